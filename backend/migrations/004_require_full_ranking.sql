@@ -0,0 +1,2 @@
+-- Polls can require voters to rank every candidate
+ALTER TABLE polls ADD COLUMN require_full_ranking BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,6 @@
+-- Partial rankings saved by voters before they submit their ballot
+CREATE TABLE ballot_drafts (
+    voter_id UUID PRIMARY KEY REFERENCES voters(id) ON DELETE CASCADE,
+    rankings JSONB NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+);
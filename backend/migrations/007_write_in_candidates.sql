@@ -0,0 +1,6 @@
+-- Polls can let voters rank candidates that are not on the ballot
+ALTER TABLE polls ADD COLUMN allow_write_ins BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE candidates ADD COLUMN is_write_in BOOLEAN NOT NULL DEFAULT false;
+
+-- Identical write-ins (ignoring case) share one candidate per poll
+CREATE UNIQUE INDEX idx_candidates_write_in_name ON candidates (poll_id, LOWER(name)) WHERE is_write_in;
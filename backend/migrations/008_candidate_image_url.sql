@@ -0,0 +1,2 @@
+-- Optional photo or avatar shown next to a candidate on the ballot
+ALTER TABLE candidates ADD COLUMN image_url TEXT;
@@ -0,0 +1,4 @@
+-- Deleted polls are kept (with their ballots) until permanently removed
+ALTER TABLE polls ADD COLUMN deleted_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX idx_polls_user_id_active ON polls(user_id) WHERE deleted_at IS NULL;
@@ -0,0 +1,10 @@
+-- Audit trail of poll ownership changes
+CREATE TABLE poll_ownership_transfers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    poll_id UUID NOT NULL REFERENCES polls(id) ON DELETE CASCADE,
+    from_user_id UUID NOT NULL REFERENCES users(id),
+    to_user_id UUID NOT NULL REFERENCES users(id),
+    transferred_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_poll_ownership_transfers_poll_id ON poll_ownership_transfers(poll_id);
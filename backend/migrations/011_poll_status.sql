@@ -0,0 +1,9 @@
+-- Polls are finalized once their close time passes; final results are cached on close
+ALTER TABLE polls ADD COLUMN status VARCHAR(20) NOT NULL DEFAULT 'open';
+ALTER TABLE polls ADD COLUMN final_results JSONB;
+ALTER TABLE polls ADD CONSTRAINT polls_valid_status CHECK (status IN ('open', 'closed'));
+
+-- Polls that closed before this migration are not finalized (or announced) retroactively
+UPDATE polls SET status = 'closed' WHERE closes_at IS NOT NULL AND closes_at <= NOW();
+
+CREATE INDEX idx_polls_open_closes_at ON polls(closes_at) WHERE status = 'open';
@@ -0,0 +1,3 @@
+-- Minimum number of ballots required for a poll's result to be valid
+ALTER TABLE polls ADD COLUMN quorum INTEGER;
+ALTER TABLE polls ADD CONSTRAINT polls_valid_quorum CHECK (quorum IS NULL OR quorum > 0);
@@ -0,0 +1,3 @@
+-- How ballots that skip a rank (1, 2, 4) are handled: rejected, or collapsed to 1, 2, 3
+ALTER TABLE polls ADD COLUMN skipped_rankings_policy VARCHAR(20) NOT NULL DEFAULT 'reject';
+ALTER TABLE polls ADD CONSTRAINT polls_valid_skipped_rankings_policy CHECK (skipped_rankings_policy IN ('reject', 'collapse'));
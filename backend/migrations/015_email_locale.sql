@@ -0,0 +1,3 @@
+-- Language for emails: a voter's own preference, falling back to the poll default
+ALTER TABLE voters ADD COLUMN locale VARCHAR(35);
+ALTER TABLE polls ADD COLUMN default_locale VARCHAR(35) NOT NULL DEFAULT 'en';
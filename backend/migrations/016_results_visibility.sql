@@ -0,0 +1,3 @@
+-- Who may read a poll's results without owning it: nobody, anyone once the poll closes, or anyone at any time
+ALTER TABLE polls ADD COLUMN results_visibility VARCHAR(20) NOT NULL DEFAULT 'private';
+ALTER TABLE polls ADD CONSTRAINT polls_valid_results_visibility CHECK (results_visibility IN ('private', 'public_after_close', 'public_always'));
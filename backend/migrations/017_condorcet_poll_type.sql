@@ -0,0 +1,3 @@
+-- Allow Condorcet (Schulze method) polls alongside the other poll types
+ALTER TABLE polls DROP CONSTRAINT polls_valid_type;
+ALTER TABLE polls ADD CONSTRAINT polls_valid_type CHECK (poll_type IN ('single_winner', 'multi_winner', 'approval', 'borda', 'condorcet'));
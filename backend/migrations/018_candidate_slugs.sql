@@ -0,0 +1,19 @@
+-- Stable per-poll short codes for candidates (printed ballots, QR codes), e.g. 'john-smith'
+ALTER TABLE candidates ADD COLUMN slug VARCHAR(64);
+
+-- Existing candidates get a slug from their name, numbered in display order when names repeat
+WITH base AS (
+    SELECT id, poll_id, display_order, created_at,
+           COALESCE(NULLIF(TRIM(BOTH '-' FROM LEFT(regexp_replace(LOWER(name), '[^a-z0-9]+', '-', 'g'), 48)), ''), 'candidate') AS slug
+    FROM candidates
+), numbered AS (
+    SELECT id, slug, ROW_NUMBER() OVER (PARTITION BY poll_id, slug ORDER BY display_order, created_at, id) AS n
+    FROM base
+)
+UPDATE candidates c
+SET slug = CASE WHEN numbered.n = 1 THEN numbered.slug ELSE numbered.slug || '-' || numbered.n END
+FROM numbered
+WHERE c.id = numbered.id;
+
+ALTER TABLE candidates ALTER COLUMN slug SET NOT NULL;
+ALTER TABLE candidates ADD CONSTRAINT candidates_poll_slug_unique UNIQUE (poll_id, slug);
@@ -0,0 +1,3 @@
+-- Fewest candidates a ballot must rank; NULL keeps the default of at least one
+ALTER TABLE polls ADD COLUMN min_rankings INTEGER;
+ALTER TABLE polls ADD CONSTRAINT polls_valid_min_rankings CHECK (min_rankings IS NULL OR min_rankings > 0);
@@ -0,0 +1,3 @@
+-- Outcome of the most recent invitation email, so organizers can see which invitations never went out
+ALTER TABLE voters ADD COLUMN email_status VARCHAR(20);
+ALTER TABLE voters ADD CONSTRAINT voters_valid_email_status CHECK (email_status IN ('sent', 'failed', 'skipped_anonymous', 'no_email'));
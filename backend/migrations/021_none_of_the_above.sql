@@ -0,0 +1,6 @@
+-- Polls can offer a reserved "None of the above" candidate; if it wins, nobody else has the voters' confidence
+ALTER TABLE polls ADD COLUMN allow_none_of_the_above BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE candidates ADD COLUMN is_nota BOOLEAN NOT NULL DEFAULT false;
+
+-- At most one reserved candidate per poll
+CREATE UNIQUE INDEX idx_candidates_nota ON candidates (poll_id) WHERE is_nota;
@@ -0,0 +1,3 @@
+-- Organizations can brand voting receipts with their own prefix in place of VOTE/ANON
+ALTER TABLE polls ADD COLUMN receipt_prefix VARCHAR(12);
+ALTER TABLE polls ADD CONSTRAINT polls_valid_receipt_prefix CHECK (receipt_prefix IS NULL OR receipt_prefix ~ '^[A-Z0-9]{1,12}$');
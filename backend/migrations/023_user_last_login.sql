@@ -0,0 +1,2 @@
+-- When each user last signed in, so operators can review account activity
+ALTER TABLE users ADD COLUMN last_login_at TIMESTAMP WITH TIME ZONE;
@@ -0,0 +1,10 @@
+-- Every distinct tabulation of a poll, with the settings it was counted under
+CREATE TABLE poll_result_versions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    poll_id UUID NOT NULL REFERENCES polls(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL,
+    settings JSONB NOT NULL,
+    results JSONB NOT NULL,
+    computed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (poll_id, version)
+);
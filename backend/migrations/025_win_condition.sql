@@ -0,0 +1,8 @@
+-- Whether a single-winner count needs a majority, or accepts the leader once a set number of rounds is reached
+ALTER TABLE polls ADD COLUMN win_condition VARCHAR(32) NOT NULL DEFAULT 'majority';
+ALTER TABLE polls ADD COLUMN plurality_round_limit INTEGER;
+ALTER TABLE polls ADD CONSTRAINT polls_valid_win_condition CHECK (
+    win_condition = 'majority'
+    OR (win_condition = 'plurality_after_rounds' AND plurality_round_limit IS NOT NULL)
+);
+ALTER TABLE polls ADD CONSTRAINT polls_valid_plurality_round_limit CHECK (plurality_round_limit IS NULL OR plurality_round_limit >= 1);
@@ -0,0 +1,8 @@
+-- Free-form labels organizers use to group their polls
+CREATE TABLE poll_tags (
+    poll_id UUID NOT NULL REFERENCES polls(id) ON DELETE CASCADE,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (poll_id, tag)
+);
+
+CREATE INDEX idx_poll_tags_tag ON poll_tags (tag);
@@ -0,0 +1,2 @@
+-- Longer markdown statement shown on the ballot, alongside the one-line description
+ALTER TABLE candidates ADD COLUMN statement TEXT;
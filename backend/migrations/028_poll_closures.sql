@@ -0,0 +1,7 @@
+-- One immutable record per closed poll: why it closed and the results it declared
+CREATE TABLE poll_closures (
+    poll_id UUID PRIMARY KEY REFERENCES polls(id) ON DELETE CASCADE,
+    close_reason VARCHAR(20) NOT NULL CHECK (close_reason IN ('scheduled', 'manual', 'quorum_failed')),
+    results JSONB,
+    closed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,2 @@
+-- Public polls can require a CAPTCHA before accepting anonymous ballots
+ALTER TABLE polls ADD COLUMN require_captcha BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,2 @@
+-- IANA time zone the organizer schedules the poll in, e.g. 'America/New_York'
+ALTER TABLE polls ADD COLUMN timezone TEXT;
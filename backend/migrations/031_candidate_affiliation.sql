@@ -0,0 +1,2 @@
+-- Party or group a candidate stands for, e.g. 'Green'
+ALTER TABLE candidates ADD COLUMN affiliation TEXT;
@@ -0,0 +1,4 @@
+-- One ballot per voter, enforced by the database so concurrent submissions can't both land.
+-- Replaces the table-level UNIQUE(voter_id) with a named partial index the API maps to ALREADY_VOTED
+ALTER TABLE ballots DROP CONSTRAINT IF EXISTS ballots_voter_id_key;
+CREATE UNIQUE INDEX ballots_voter_id_unique ON ballots(voter_id) WHERE voter_id IS NOT NULL;
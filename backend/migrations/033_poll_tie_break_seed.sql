@@ -0,0 +1,2 @@
+-- Seed for random tie-breaks, e.g. one drawn in public; NULL derives it from the poll id
+ALTER TABLE polls ADD COLUMN tie_break_seed BIGINT;
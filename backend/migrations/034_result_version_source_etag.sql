@@ -0,0 +1,2 @@
+-- Fingerprint of the inputs a version was counted from, so unchanged polls skip the comparison
+ALTER TABLE poll_result_versions ADD COLUMN source_etag TEXT;
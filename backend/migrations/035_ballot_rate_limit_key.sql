@@ -0,0 +1,4 @@
+-- Keyed hash of the address an anonymous ballot came from, so votes can be rate limited
+-- however much of the address IP_STORAGE_MODE keeps in ip_address
+ALTER TABLE ballots ADD COLUMN rate_limit_key TEXT;
+CREATE INDEX idx_ballots_anonymous_rate_limit ON ballots (poll_id, rate_limit_key, submitted_at) WHERE voter_id IS NULL;
@@ -0,0 +1,4 @@
+-- The custom receipt prefix a ballot's receipt was issued under, so the code keeps working
+-- after the poll's prefix changes
+ALTER TABLE ballots ADD COLUMN receipt_prefix VARCHAR(12);
+UPDATE ballots b SET receipt_prefix = p.receipt_prefix FROM polls p WHERE p.id = b.poll_id;
@@ -0,0 +1,21 @@
+-- One voter per email address in a poll, enforced by the database so concurrent invites can't
+-- both land. Anonymous placeholders get unique generated addresses and are left out.
+-- Duplicates invited before the API checked are dropped first, keeping whichever voted, else the earliest
+WITH ranked AS (
+    SELECT v.id,
+           ROW_NUMBER() OVER (
+               PARTITION BY v.poll_id, LOWER(TRIM(v.email))
+               ORDER BY EXISTS(SELECT 1 FROM ballots b WHERE b.voter_id = v.id) DESC, v.invited_at, v.id
+           ) AS n
+    FROM voters v
+    WHERE v.email IS NOT NULL AND v.email NOT LIKE 'Anonymous-%'
+)
+DELETE FROM voters v
+USING ranked
+WHERE v.id = ranked.id
+  AND ranked.n > 1
+  AND NOT EXISTS(SELECT 1 FROM ballots b WHERE b.voter_id = v.id)
+  AND NOT EXISTS(SELECT 1 FROM ad_impressions a WHERE a.voter_id = v.id);
+
+CREATE UNIQUE INDEX voters_poll_email_unique ON voters (poll_id, LOWER(TRIM(email)))
+    WHERE email NOT LIKE 'Anonymous-%';
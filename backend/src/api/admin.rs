@@ -0,0 +1,120 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api::health::HEALTH_CHECK_TIMEOUT;
+use crate::api::json::Json;
+use crate::api::polls::ApiResponse;
+use crate::models::user::User;
+use crate::services::auth::AuthService;
+use crate::services::email::{EmailResponse, VoterInvitationRequest};
+
+#[derive(Debug, Deserialize)]
+pub struct TestEmailRequest {
+    pub to: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmailHealthResponse {
+    /// `ok` when the email service answers its health check, otherwise `down`
+    pub status: String,
+}
+
+// Only administrators may use these endpoints; the role is read from the database
+// rather than the token so a demoted user loses access straight away
+pub(crate) async fn current_admin(headers: &HeaderMap, auth_service: &AuthService) -> Result<User, (StatusCode, Json<ApiResponse<()>>)> {
+    let unauthorized = |message: &str| (StatusCode::UNAUTHORIZED, Json(ApiResponse::<()>::error("UNAUTHORIZED", message)));
+
+    let token = headers
+        .get("authorization")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .ok_or_else(|| unauthorized("Missing or invalid authorization header"))?;
+    let claims = auth_service.verify_token(token).map_err(|_| unauthorized("Invalid token"))?;
+    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| unauthorized("Invalid user ID in token"))?;
+
+    match User::find_by_id(auth_service.pool(), user_id).await {
+        Ok(Some(user)) if user.is_admin() => Ok(user),
+        Ok(_) => Err((
+            StatusCode::FORBIDDEN,
+            Json(ApiResponse::<()>::error("FORBIDDEN", "Only administrators can do this")),
+        )),
+        Err(e) => {
+            tracing::error!("Database error finding user: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("INTERNAL_ERROR", "Internal server error")),
+            ))
+        }
+    }
+}
+
+fn email_not_configured() -> (StatusCode, Json<ApiResponse<()>>) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ApiResponse::<()>::error("EMAIL_NOT_CONFIGURED", "Email service is not configured")),
+    )
+}
+
+/// POST /api/admin/email/test - Send a sample invitation to check the email configuration
+pub async fn send_test_email(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Json(req): Json<TestEmailRequest>,
+) -> Result<Json<ApiResponse<EmailResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let admin = current_admin(&headers, &auth_service).await?;
+
+    let to = req.to.trim();
+    if !to.contains('@') {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("to", "A destination email address is required")),
+        ));
+    }
+
+    let email_service = auth_service.email_service().ok_or_else(email_not_configured)?;
+
+    let invitation = VoterInvitationRequest {
+        poll_title: "Test poll".to_string(),
+        poll_description: Some("This is a test invitation to confirm email delivery works. No poll exists.".to_string()),
+        voting_url: auth_service.urls().voting_url("test"),
+        poll_owner_name: admin.name.clone().unwrap_or_else(|| admin.email.clone()),
+        poll_owner_email: admin.email.clone(),
+        closes_at: None,
+        voter_name: None,
+        locale: "en".to_string(),
+        to: to.to_string(),
+    };
+
+    match email_service.send_voter_invitation(invitation).await {
+        Ok(response) => Ok(Json(ApiResponse::success(response))),
+        Err(e) => {
+            tracing::warn!("Test email to {} failed: {:#}", to, e);
+            Err((
+                StatusCode::BAD_GATEWAY,
+                Json(ApiResponse::<()>::error("EMAIL_SEND_FAILED", &format!("{:#}", e))),
+            ))
+        }
+    }
+}
+
+/// GET /api/admin/email/health - Whether the configured email service is reachable
+pub async fn email_health(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<EmailHealthResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    current_admin(&headers, &auth_service).await?;
+
+    let email_service = auth_service.email_service().ok_or_else(email_not_configured)?;
+    let healthy = matches!(
+        tokio::time::timeout(HEALTH_CHECK_TIMEOUT, email_service.health_check()).await,
+        Ok(Ok(true))
+    );
+
+    Ok(Json(ApiResponse::success(EmailHealthResponse {
+        status: if healthy { "ok" } else { "down" }.to_string(),
+    })))
+}
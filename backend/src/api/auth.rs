@@ -1,12 +1,12 @@
 use axum::{
-    extract::State,
+    extract::{Path, State},
     http::{HeaderMap, StatusCode},
-    Json,
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::models::user::{CreateUserRequest, LoginRequest};
+use crate::api::json::Json;
+use crate::models::user::{CreateUserRequest, LoginRequest, UpdateProfileRequest, User, UserResponse};
 use crate::services::auth::{AuthError, AuthService};
 
 #[derive(Debug, Serialize)]
@@ -78,6 +78,10 @@ pub async fn register(
             StatusCode::CONFLICT,
             Json(ApiResponse::<()>::error("USER_ALREADY_EXISTS", "A user with this email already exists")),
         )),
+        Err(AuthError::WeakPassword(message)) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::error("WEAK_PASSWORD", &message)),
+        )),
         Err(AuthError::Database(e)) => {
             tracing::error!("Database error during registration: {}", e);
             Err((
@@ -162,6 +166,11 @@ pub struct ResetPasswordRequest {
     password: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct DeleteAccountRequest {
+    password: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct MessageResponse {
     message: String,
@@ -171,7 +180,22 @@ pub async fn verify_email(
     State(auth_service): State<AuthService>,
     Json(req): Json<VerifyEmailRequest>,
 ) -> Result<Json<ApiResponse<MessageResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
-    match auth_service.verify_email(&req.token).await {
+    verify_email_token(&auth_service, &req.token).await
+}
+
+/// GET /api/auth/verify-email/:token - Verify an email address straight from the emailed link
+pub async fn verify_email_link(
+    State(auth_service): State<AuthService>,
+    Path(token): Path<String>,
+) -> Result<Json<ApiResponse<MessageResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    verify_email_token(&auth_service, &token).await
+}
+
+async fn verify_email_token(
+    auth_service: &AuthService,
+    token: &str,
+) -> Result<Json<ApiResponse<MessageResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    match auth_service.verify_email(token).await {
         Ok(()) => Ok(Json(ApiResponse::success(MessageResponse {
             message: "Email verified successfully".to_string(),
         }))),
@@ -233,11 +257,8 @@ pub async fn reset_password(
     }
 }
 
-pub async fn resend_verification(
-    State(auth_service): State<AuthService>,
-    headers: HeaderMap,
-) -> Result<Json<ApiResponse<MessageResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
-    // Extract and verify JWT (same pattern as polls, voters - no middleware)
+// Extract and verify the JWT (same pattern as polls, voters - no middleware)
+fn current_user_id(headers: &HeaderMap, auth_service: &AuthService) -> Result<Uuid, (StatusCode, Json<ApiResponse<()>>)> {
     let auth_header = headers
         .get("authorization")
         .and_then(|h| h.to_str().ok())
@@ -267,12 +288,19 @@ pub async fn resend_verification(
         )
     })?;
 
-    let user_id = Uuid::parse_str(&claims.sub).map_err(|_| {
+    Uuid::parse_str(&claims.sub).map_err(|_| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ApiResponse::<()>::error("INTERNAL_ERROR", "Invalid user ID")),
         )
-    })?;
+    })
+}
+
+pub async fn resend_verification(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<MessageResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = current_user_id(&headers, &auth_service)?;
 
     match auth_service.resend_verification(user_id).await {
         Ok(()) => Ok(Json(ApiResponse::success(MessageResponse {
@@ -286,4 +314,89 @@ pub async fn resend_verification(
             ))
         }
     }
-} 
\ No newline at end of file
+}
+
+/// GET /api/auth/me - The signed-in user's profile
+pub async fn me(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<UserResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = current_user_id(&headers, &auth_service)?;
+
+    match User::find_by_id(auth_service.pool(), user_id).await {
+        Ok(Some(user)) => Ok(Json(ApiResponse::success(user.into()))),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("USER_NOT_FOUND", "User not found")),
+        )),
+        Err(e) => {
+            tracing::error!("Database error finding user: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("INTERNAL_ERROR", "Internal server error")),
+            ))
+        }
+    }
+}
+
+/// PUT /api/auth/me - Change the signed-in user's name or email
+pub async fn update_me(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Json(req): Json<UpdateProfileRequest>,
+) -> Result<Json<ApiResponse<UserResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = current_user_id(&headers, &auth_service)?;
+
+    match auth_service.update_profile(user_id, req).await {
+        Ok(user) => Ok(Json(ApiResponse::success(user))),
+        Err(AuthError::UserAlreadyExists) => Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::<()>::error("USER_ALREADY_EXISTS", "A user with this email already exists")),
+        )),
+        Err(AuthError::InvalidEmail) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::error("INVALID_EMAIL", "Email address is required")),
+        )),
+        Err(AuthError::UserNotFound) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("USER_NOT_FOUND", "User not found")),
+        )),
+        Err(e) => {
+            tracing::error!("Profile update error: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("INTERNAL_ERROR", "Internal server error")),
+            ))
+        }
+    }
+}
+
+/// DELETE /api/auth/me - Delete the signed-in user's account and all their polls
+pub async fn delete_me(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Json(req): Json<DeleteAccountRequest>,
+) -> Result<Json<ApiResponse<MessageResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = current_user_id(&headers, &auth_service)?;
+
+    match auth_service.delete_account(user_id, &req.password).await {
+        Ok(()) => Ok(Json(ApiResponse::success(MessageResponse {
+            message: "Account deleted".to_string(),
+        }))),
+        Err(AuthError::InvalidCredentials) => Err((
+            StatusCode::UNAUTHORIZED,
+            Json(ApiResponse::<()>::error("INVALID_CREDENTIALS", "Password is incorrect")),
+        )),
+        Err(AuthError::UserNotFound) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("USER_NOT_FOUND", "User not found")),
+        )),
+        Err(e) => {
+            tracing::error!("Account deletion error: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("INTERNAL_ERROR", "Internal server error")),
+            ))
+        }
+    }
+}
@@ -1,12 +1,242 @@
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
-    Json,
+    http::{HeaderMap, StatusCode},
 };
+use std::collections::HashSet;
 use uuid::Uuid;
-use crate::models::candidate::{Candidate, CreateCandidateRequest, UpdateCandidateRequest, ReorderCandidatesRequest};
+use crate::api::json::Json;
+use crate::models::candidate::{
+    candidate_name_key, sanitize_candidate_affiliation, sanitize_candidate_description, sanitize_candidate_name,
+    sanitize_candidate_statement, Candidate, CreateCandidateRequest, ReorderCandidatesRequest, UpdateCandidateRequest,
+    MAX_CANDIDATE_AFFILIATION_LEN, MAX_CANDIDATE_DESCRIPTION_LEN, MAX_CANDIDATE_NAME_LEN, MAX_CANDIDATE_STATEMENT_LEN,
+};
+use crate::models::poll::{Poll, PollResponse};
 use crate::services::auth::AuthService;
-use crate::api::polls::ApiResponse;
+use crate::api::polls::{get_current_user_id, ApiResponse};
+
+const DEFAULT_MAX_CANDIDATES_PER_POLL: usize = 100;
+
+/// Most candidates a poll may have, from `MAX_CANDIDATES_PER_POLL`. Tabulation runs up to
+/// one round per candidate and the ballot lists them all, so the list has to stay bounded.
+pub(crate) fn max_candidates_per_poll() -> usize {
+    std::env::var("MAX_CANDIDATES_PER_POLL")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MAX_CANDIDATES_PER_POLL)
+}
+
+/// Reject a poll whose candidate list would grow past the maximum
+pub(crate) fn validate_candidate_count(count: usize) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    let max_candidates = max_candidates_per_poll();
+    if count > max_candidates {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::error(
+                "TOO_MANY_CANDIDATES",
+                &format!("A poll can have at most {} candidates", max_candidates),
+            )),
+        ));
+    }
+
+    Ok(())
+}
+
+// Check that adding `additional` candidates keeps the poll within the maximum
+async fn validate_added_candidates(
+    auth_service: &AuthService,
+    poll_id: Uuid,
+    additional: usize,
+) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    let existing = Candidate::count_by_poll(auth_service.pool(), poll_id).await.map_err(|e| {
+        tracing::error!("Failed to count candidates for poll {}: {}", poll_id, e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::<()>::error("CANDIDATE_CREATION_FAILED", "Failed to create candidate")),
+        )
+    })?;
+
+    validate_candidate_count(existing + additional)
+}
+
+/// Position of the first name in `names` that repeats one in `taken` or earlier in
+/// `names`. Write-ins are left out of `taken`, since voters may write in any name.
+pub(crate) fn find_duplicate_candidate_name<'a>(
+    taken: impl IntoIterator<Item = &'a str>,
+    names: impl IntoIterator<Item = &'a str>,
+) -> Option<usize> {
+    let mut seen: HashSet<String> = taken.into_iter().map(candidate_name_key).collect();
+    names.into_iter().position(|name| !seen.insert(candidate_name_key(name)))
+}
+
+// Refuse candidate changes once the poll has closed, like poll updates do
+async fn ensure_poll_open(auth_service: &AuthService, poll_id: Uuid) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    match Candidate::poll_is_closed(auth_service.pool(), poll_id).await {
+        Ok(false) => Ok(()),
+        Ok(true) => Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::<()>::error("POLL_CLOSED", "The schedule and candidates of a closed poll cannot be changed")),
+        )),
+        Err(e) => {
+            tracing::error!("Failed to check poll {} status: {}", poll_id, e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("POLL_GET_FAILED", "Failed to retrieve poll")),
+            ))
+        }
+    }
+}
+
+// Load a poll belonging to `user_id`; someone else's poll is reported as missing, like a missing one
+async fn find_owned_poll(
+    auth_service: &AuthService,
+    poll_id: Uuid,
+    user_id: Uuid,
+) -> Result<PollResponse, (StatusCode, Json<ApiResponse<()>>)> {
+    match Poll::find_by_id_and_user(auth_service.pool(), poll_id, user_id).await {
+        Ok(Some(poll)) => Ok(poll),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
+        )),
+        Err(e) => {
+            tracing::error!("Failed to get poll {}: {}", poll_id, e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("POLL_GET_FAILED", "Failed to retrieve poll")),
+            ))
+        }
+    }
+}
+
+// Load a candidate the owner may edit; "None of the above" follows the poll's
+// `allow_none_of_the_above` setting instead
+async fn find_editable_candidate(
+    auth_service: &AuthService,
+    candidate_id: Uuid,
+    failure: (&str, &str),
+) -> Result<Candidate, (StatusCode, Json<ApiResponse<()>>)> {
+    match Candidate::find_by_id(auth_service.pool(), candidate_id).await {
+        Ok(Some(candidate)) if candidate.is_nota => Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::<()>::error(
+                "NOTA_NOT_EDITABLE",
+                "\"None of the above\" is set by the poll's allow_none_of_the_above option",
+            )),
+        )),
+        Ok(Some(candidate)) => Ok(candidate),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("CANDIDATE_NOT_FOUND", "Candidate not found")),
+        )),
+        Err(e) => {
+            tracing::error!("Failed to get candidate: {}", e);
+            Err((StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::<()>::error(failure.0, failure.1))))
+        }
+    }
+}
+
+pub(crate) fn duplicate_candidate_name_error(name: &str) -> (StatusCode, Json<ApiResponse<()>>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ApiResponse::<()>::validation_error(
+            "name",
+            &format!("The poll already has a candidate named '{}'", sanitize_candidate_name(name)),
+        )),
+    )
+}
+
+// Check new or renamed candidates against the poll's other official candidates
+async fn validate_candidate_names(
+    auth_service: &AuthService,
+    poll_id: Uuid,
+    renamed: Option<Uuid>,
+    names: &[&str],
+) -> Result<Option<usize>, (StatusCode, Json<ApiResponse<()>>)> {
+    let existing = Candidate::find_by_poll_id(auth_service.pool(), poll_id).await.map_err(|e| {
+        tracing::error!("Failed to load candidates for poll {}: {}", poll_id, e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::<()>::error("CANDIDATES_LOAD_FAILED", "Failed to load poll candidates")),
+        )
+    })?;
+
+    let taken = existing.iter()
+        .filter(|c| !c.is_write_in && Some(c.id) != renamed)
+        .map(|c| c.name.as_str());
+    Ok(find_duplicate_candidate_name(taken, names.iter().copied()))
+}
+
+/// Reject candidate names, descriptions or statements that are too long once
+/// sanitized, which is how they are stored
+pub(crate) fn validate_candidate_text(
+    name: Option<&str>,
+    description: Option<&str>,
+    statement: Option<&str>,
+) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    if name.is_some_and(|name| sanitize_candidate_name(name).chars().count() > MAX_CANDIDATE_NAME_LEN) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error(
+                "name",
+                &format!("Candidate names can be at most {} characters", MAX_CANDIDATE_NAME_LEN),
+            )),
+        ));
+    }
+
+    if sanitize_candidate_description(description).is_some_and(|d| d.chars().count() > MAX_CANDIDATE_DESCRIPTION_LEN) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error(
+                "description",
+                &format!("Candidate descriptions can be at most {} characters", MAX_CANDIDATE_DESCRIPTION_LEN),
+            )),
+        ));
+    }
+
+    if sanitize_candidate_statement(statement).is_some_and(|s| s.chars().count() > MAX_CANDIDATE_STATEMENT_LEN) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error(
+                "statement",
+                &format!("Candidate statements can be at most {} characters", MAX_CANDIDATE_STATEMENT_LEN),
+            )),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reject candidate affiliations that are too long once sanitized
+pub(crate) fn validate_candidate_affiliation(affiliation: Option<&str>) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    if sanitize_candidate_affiliation(affiliation).is_some_and(|a| a.chars().count() > MAX_CANDIDATE_AFFILIATION_LEN) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error(
+                "affiliation",
+                &format!("Candidate affiliations can be at most {} characters", MAX_CANDIDATE_AFFILIATION_LEN),
+            )),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject candidate image URLs that are not absolute http(s) links
+pub(crate) fn validate_image_url(image_url: Option<&str>) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    let Some(image_url) = image_url else {
+        return Ok(());
+    };
+
+    let is_web_url = url::Url::parse(image_url)
+        .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.host().is_some());
+    if !is_web_url {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("image_url", "Candidate image URL must be a valid http(s) URL")),
+        ));
+    }
+
+    Ok(())
+}
 
 /// Add a new candidate to a poll
 pub async fn add_candidate(
@@ -18,12 +248,20 @@ pub async fn add_candidate(
     // For now, we'll skip authentication validation
 
     // Validate request
-    if req.name.trim().is_empty() {
+    if sanitize_candidate_name(&req.name).is_empty() {
         return Err((
             StatusCode::BAD_REQUEST,
-            Json(ApiResponse::<()>::error("VALIDATION_ERROR", "Candidate name is required")),
+            Json(ApiResponse::<()>::validation_error("name", "Candidate name is required")),
         ));
     }
+    validate_candidate_text(Some(&req.name), req.description.as_deref(), req.statement.as_deref())?;
+    validate_candidate_affiliation(req.affiliation.as_deref())?;
+    validate_image_url(req.image_url.as_deref())?;
+    ensure_poll_open(&auth_service, poll_id).await?;
+    validate_added_candidates(&auth_service, poll_id, 1).await?;
+    if validate_candidate_names(&auth_service, poll_id, None, &[&req.name]).await?.is_some() {
+        return Err(duplicate_candidate_name_error(&req.name));
+    }
 
     match Candidate::create(auth_service.pool(), poll_id, req).await {
         Ok(candidate) => Ok(Json(ApiResponse::success(candidate))),
@@ -37,6 +275,81 @@ pub async fn add_candidate(
     }
 }
 
+/// Add several candidates to a poll at once; only the poll's owner may
+pub async fn add_candidates_bulk(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Path(poll_id): Path<Uuid>,
+    Json(reqs): Json<Vec<CreateCandidateRequest>>,
+) -> Result<Json<ApiResponse<Vec<Candidate>>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = get_current_user_id(&headers, &auth_service)?;
+    find_owned_poll(&auth_service, poll_id, user_id).await?;
+
+    // Validate request
+    if reqs.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("candidates", "At least one candidate is required")),
+        ));
+    }
+
+    if reqs.iter().any(|req| sanitize_candidate_name(&req.name).is_empty()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("name", "All candidate names are required")),
+        ));
+    }
+
+    for req in &reqs {
+        validate_candidate_text(Some(&req.name), req.description.as_deref(), req.statement.as_deref())?;
+        validate_candidate_affiliation(req.affiliation.as_deref())?;
+        validate_image_url(req.image_url.as_deref())?;
+    }
+    ensure_poll_open(&auth_service, poll_id).await?;
+    validate_added_candidates(&auth_service, poll_id, reqs.len()).await?;
+    let names: Vec<&str> = reqs.iter().map(|req| req.name.as_str()).collect();
+    if let Some(index) = validate_candidate_names(&auth_service, poll_id, None, &names).await? {
+        let (status, Json(error)) = duplicate_candidate_name_error(names[index]);
+        return Err((status, Json(error.within(&format!("candidates[{}]", index)))));
+    }
+
+    match Candidate::create_bulk(auth_service.pool(), poll_id, reqs).await {
+        Ok(candidates) => Ok(Json(ApiResponse::success(candidates))),
+        Err(e) => {
+            tracing::error!("Failed to create candidates: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("CANDIDATE_CREATION_FAILED", "Failed to create candidates")),
+            ))
+        }
+    }
+}
+
+/// Fetch a single candidate for the owner of its poll
+pub async fn get_candidate(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Path(candidate_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Candidate>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = get_current_user_id(&headers, &auth_service)?;
+
+    // Someone else's candidate is reported as missing, like someone else's poll
+    match Candidate::find_by_id_for_owner(auth_service.pool(), candidate_id, user_id).await {
+        Ok(Some(candidate)) => Ok(Json(ApiResponse::success(candidate))),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("CANDIDATE_NOT_FOUND", "Candidate not found")),
+        )),
+        Err(e) => {
+            tracing::error!("Failed to get candidate: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("CANDIDATE_GET_FAILED", "Failed to retrieve candidate")),
+            ))
+        }
+    }
+}
+
 /// Update an existing candidate
 pub async fn update_candidate(
     State(auth_service): State<AuthService>,
@@ -48,13 +361,33 @@ pub async fn update_candidate(
 
     // Validate request
     if let Some(ref name) = req.name {
-        if name.trim().is_empty() {
+        if sanitize_candidate_name(name).is_empty() {
             return Err((
                 StatusCode::BAD_REQUEST,
-                Json(ApiResponse::<()>::error("VALIDATION_ERROR", "Candidate name cannot be empty")),
+                Json(ApiResponse::<()>::validation_error("name", "Candidate name cannot be empty")),
             ));
         }
     }
+    validate_candidate_text(
+        req.name.as_deref(),
+        req.description.as_deref(),
+        req.statement.as_ref().and_then(|statement| statement.as_deref()),
+    )?;
+    validate_candidate_affiliation(req.affiliation.as_ref().and_then(|affiliation| affiliation.as_deref()))?;
+    validate_image_url(req.image_url.as_ref().and_then(|image_url| image_url.as_deref()))?;
+
+    let candidate =
+        find_editable_candidate(&auth_service, candidate_id, ("CANDIDATE_UPDATE_FAILED", "Failed to update candidate")).await?;
+    ensure_poll_open(&auth_service, candidate.poll_id).await?;
+
+    if let Some(ref name) = req.name {
+        // Write-ins keep the name the voter gave, so only official candidates are checked
+        if !candidate.is_write_in
+            && validate_candidate_names(&auth_service, candidate.poll_id, Some(candidate_id), &[name]).await?.is_some()
+        {
+            return Err(duplicate_candidate_name_error(name));
+        }
+    }
 
     match Candidate::update(auth_service.pool(), candidate_id, req).await {
         Ok(Some(candidate)) => Ok(Json(ApiResponse::success(candidate))),
@@ -80,6 +413,10 @@ pub async fn delete_candidate(
     // TODO: Implement proper authentication middleware
     // For now, we'll skip authentication validation
 
+    let candidate =
+        find_editable_candidate(&auth_service, candidate_id, ("CANDIDATE_DELETE_FAILED", "Failed to delete candidate")).await?;
+    ensure_poll_open(&auth_service, candidate.poll_id).await?;
+
     match Candidate::delete(auth_service.pool(), candidate_id).await {
         Ok(true) => Ok(Json(ApiResponse::success(()))),
         Ok(false) => Err((
@@ -113,6 +450,37 @@ pub async fn reorder_candidates(
         ));
     }
 
+    ensure_poll_open(&auth_service, poll_id).await?;
+
+    // The new order must be a permutation of the poll's current candidates
+    let current_candidates = match Candidate::find_by_poll_id(auth_service.pool(), poll_id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Failed to load candidates for reorder: {}", e);
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("CANDIDATE_REORDER_FAILED", "Failed to reorder candidates")),
+            ));
+        }
+    };
+
+    let current_ids: HashSet<Uuid> = current_candidates.iter().map(|c| c.id).collect();
+    let requested_ids: HashSet<Uuid> = req.candidate_order.iter().copied().collect();
+
+    if requested_ids.len() != req.candidate_order.len() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::error("VALIDATION_ERROR", "Candidate order contains duplicate IDs")),
+        ));
+    }
+
+    if requested_ids != current_ids {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::error("VALIDATION_ERROR", "Candidate order must include every candidate in the poll exactly once")),
+        ));
+    }
+
     match Candidate::reorder(auth_service.pool(), poll_id, req.candidate_order).await {
         Ok(candidates) => Ok(Json(ApiResponse::success(candidates))),
         Err(e) => {
@@ -143,4 +511,25 @@ pub async fn list_candidates(
             ))
         }
     }
-} 
\ No newline at end of file
+}
+
+/// Look up a poll's candidate by its slug
+pub async fn get_candidate_by_slug(
+    State(auth_service): State<AuthService>,
+    Path((poll_id, slug)): Path<(Uuid, String)>,
+) -> Result<Json<ApiResponse<Candidate>>, (StatusCode, Json<ApiResponse<()>>)> {
+    match Candidate::find_by_slug(auth_service.pool(), poll_id, &slug).await {
+        Ok(Some(candidate)) => Ok(Json(ApiResponse::success(candidate))),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("CANDIDATE_NOT_FOUND", "Candidate not found")),
+        )),
+        Err(e) => {
+            tracing::error!("Failed to find candidate by slug: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("CANDIDATE_GET_FAILED", "Failed to retrieve candidate")),
+            ))
+        }
+    }
+}
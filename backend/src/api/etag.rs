@@ -0,0 +1,44 @@
+use axum::{
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+
+/// A weak entity tag over everything a representation depends on, e.g. `W/"9f86d081..."`
+pub fn weak_etag<I, S>(parts: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<[u8]>,
+{
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_ref());
+        // Separate parts so ("ab", "c") and ("a", "bc") differ
+        hasher.update([0]);
+    }
+    format!("W/\"{}\"", hex::encode(&hasher.finalize()[..16]))
+}
+
+/// A `304 Not Modified` response when the request's `If-None-Match` already names `etag`.
+/// Tags are compared weakly, ignoring any `W/` prefix.
+pub fn not_modified(headers: &HeaderMap, etag: &str) -> Option<Response> {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let matched = headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|tag| tag.trim() == "*" || opaque(tag) == opaque(etag));
+
+    matched.then(|| with_etag(etag, StatusCode::NOT_MODIFIED))
+}
+
+/// Attach `etag` to a successful response; errors are passed through untagged
+pub fn with_etag(etag: &str, response: impl IntoResponse) -> Response {
+    let mut response = response.into_response();
+    let cacheable = response.status().is_success() || response.status() == StatusCode::NOT_MODIFIED;
+    if let (true, Ok(value)) = (cacheable, HeaderValue::from_str(etag)) {
+        response.headers_mut().insert(header::ETAG, value);
+    }
+    response
+}
@@ -0,0 +1,95 @@
+use axum::{extract::State, http::StatusCode};
+use serde::Serialize;
+use std::time::Duration;
+
+use crate::api::json::Json;
+use crate::services::auth::AuthService;
+
+/// How long a single dependency check may take before it counts as down
+pub(crate) const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Environment the API can't serve requests without
+const REQUIRED_ENV: [&str; 2] = ["JWT_SECRET", "DATABASE_URL"];
+
+#[derive(Debug, Serialize)]
+pub struct HealthResponse {
+    status: String,
+    version: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReadinessResponse {
+    status: String,
+    version: String,
+    checks: ReadinessChecks,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReadinessChecks {
+    database: String,
+    email_service: String,
+    environment: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    missing_env: Vec<&'static str>,
+}
+
+// The email service is only pinged when HEALTH_CHECK_EMAIL=true, since it's not
+// needed to serve most requests
+fn email_check_enabled() -> bool {
+    std::env::var("HEALTH_CHECK_EMAIL").is_ok_and(|v| v == "true")
+}
+
+/// GET /health - Liveness: the process is up and answering. Never touches
+/// dependencies, so a database outage doesn't get the process restarted.
+pub async fn health() -> Json<HealthResponse> {
+    Json(HealthResponse {
+        status: "ok".to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    })
+}
+
+/// GET /ready - Readiness: whether the database is reachable and required configuration
+/// is present. Returns 503 until the API can actually serve traffic.
+pub async fn ready(State(auth_service): State<AuthService>) -> (StatusCode, Json<ReadinessResponse>) {
+    let database_ok = matches!(
+        tokio::time::timeout(
+            HEALTH_CHECK_TIMEOUT,
+            sqlx::query("SELECT 1").execute(auth_service.pool()),
+        )
+        .await,
+        Ok(Ok(_))
+    );
+
+    let email_status = match auth_service.email_service() {
+        Some(email_service) if email_check_enabled() => {
+            match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, email_service.health_check()).await {
+                Ok(Ok(true)) => "ok",
+                _ => "down",
+            }
+        }
+        Some(_) => "skipped",
+        None => "not_configured",
+    };
+
+    let missing_env: Vec<&'static str> = REQUIRED_ENV
+        .into_iter()
+        .filter(|key| auth_service.env_var(key).is_none_or(|value| value.is_empty()))
+        .collect();
+
+    let ready = database_ok && email_status != "down" && missing_env.is_empty();
+    let status_code = if ready { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+
+    (
+        status_code,
+        Json(ReadinessResponse {
+            status: if ready { "ready" } else { "not_ready" }.to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            checks: ReadinessChecks {
+                database: if database_ok { "ok" } else { "down" }.to_string(),
+                email_service: email_status.to_string(),
+                environment: if missing_env.is_empty() { "ok" } else { "missing" }.to_string(),
+                missing_env,
+            },
+        }),
+    )
+}
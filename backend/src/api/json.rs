@@ -0,0 +1,41 @@
+use axum::{
+    async_trait,
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::api::polls::ApiResponse;
+
+/// Drop-in replacement for `axum::Json` that reports malformed request bodies with
+/// the standard `ApiResponse` error envelope instead of axum's plain-text rejection
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Json<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for Json<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match axum::Json::<T>::from_request(req, state).await {
+            Ok(axum::Json(value)) => Ok(Json(value)),
+            Err(rejection) => Err(invalid_json_response(rejection)),
+        }
+    }
+}
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> Response {
+        axum::Json(self.0).into_response()
+    }
+}
+
+fn invalid_json_response(rejection: JsonRejection) -> Response {
+    let body = ApiResponse::<()>::error("INVALID_JSON", &rejection.body_text());
+    (StatusCode::BAD_REQUEST, axum::Json(body)).into_response()
+}
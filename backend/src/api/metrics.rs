@@ -0,0 +1,11 @@
+use axum::{http::header, response::IntoResponse};
+
+use crate::services::metrics::metrics;
+
+/// GET /metrics - Prometheus scrape endpoint
+pub async fn metrics_handler() -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        metrics().render(),
+    )
+}
@@ -1,6 +1,11 @@
+pub mod admin;
 pub mod auth;
+pub mod etag;
+pub mod json;
+pub mod metrics;
 pub mod polls;
 pub mod candidates;
+pub mod health;
 pub mod voting;
 pub mod voters;
 pub mod results; 
\ No newline at end of file
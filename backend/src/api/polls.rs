@@ -1,15 +1,33 @@
 use axum::{
     extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
-    Json,
+    response::Response,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
-use crate::models::poll::{CreatePollRequest, Poll, PollListQuery, UpdatePollRequest};
+use crate::api::candidates::{
+    duplicate_candidate_name_error, find_duplicate_candidate_name, validate_candidate_affiliation, validate_candidate_count,
+    validate_candidate_text, validate_image_url,
+};
+use crate::api::etag::{not_modified, weak_etag, with_etag};
+use crate::api::json::Json;
+use crate::models::ballot::RESERVED_RECEIPT_PREFIXES;
+use crate::models::candidate::{sanitize_candidate_name, NOTA_CANDIDATE_NAME};
+use crate::models::poll::{
+    is_valid_timezone, normalize_tags, CreatePollRequest, Poll, PollExport, PollListQuery, PollType, PollUpdateError,
+    PublicPollListItem, PublicPollListQuery, ResultsVisibility, SkippedRankingsPolicy, UpdatePollRequest, MAX_POLL_TAGS,
+    MAX_POLL_TAG_LEN, POLL_EXPORT_FORMAT_VERSION,
+};
+use crate::models::user::User;
 use crate::services::auth::AuthService;
+use crate::services::email::is_valid_locale;
+use crate::services::metrics::metrics;
+use crate::services::poll_closer;
+use crate::services::rcv::WinCondition;
 
 // Helper function to get user ID from JWT token
-fn get_current_user_id(headers: &HeaderMap, auth_service: &AuthService) -> Result<Uuid, (StatusCode, Json<ApiResponse<()>>)> {
+pub(crate) fn get_current_user_id(headers: &HeaderMap, auth_service: &AuthService) -> Result<Uuid, (StatusCode, Json<ApiResponse<()>>)> {
     // In test environment, use hardcoded test user ID
     if cfg!(test) {
         return Ok(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap());
@@ -56,6 +74,229 @@ fn get_current_user_id(headers: &HeaderMap, auth_service: &AuthService) -> Resul
         })
 }
 
+// Only verified accounts may create polls when REQUIRE_EMAIL_VERIFICATION=true
+fn email_verification_required() -> bool {
+    std::env::var("REQUIRE_EMAIL_VERIFICATION").is_ok_and(|v| v == "true")
+}
+
+// A poll must close strictly after it opens, or it can never accept votes, and stay
+// open for at least `min_duration` when one is configured
+fn validate_schedule(
+    opens_at: Option<DateTime<Utc>>,
+    closes_at: Option<DateTime<Utc>>,
+    min_duration: Option<chrono::Duration>,
+) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    if let (Some(opens_at), Some(closes_at)) = (opens_at, closes_at) {
+        if closes_at <= opens_at {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::<()>::validation_error("closes_at", "Poll must close after it opens")),
+            ));
+        }
+
+        if let Some(min_duration) = min_duration.filter(|&min_duration| closes_at - opens_at < min_duration) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::<()>::validation_error(
+                    "closes_at",
+                    &format!("Poll must stay open for at least {} minutes", min_duration.num_minutes()),
+                )),
+            ));
+        }
+    }
+    Ok(())
+}
+
+// A ballot can't be required to rank more candidates than the poll offers
+fn validate_min_rankings(min_rankings: Option<i32>, candidate_count: usize) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    let Some(min_rankings) = min_rankings else {
+        return Ok(());
+    };
+
+    if min_rankings < 1 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("min_rankings", "Minimum rankings must be at least 1")),
+        ));
+    }
+    if min_rankings as usize > candidate_count {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error(
+                "min_rankings",
+                &format!("Minimum rankings cannot exceed the {} candidates on the poll", candidate_count),
+            )),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_quorum(quorum: Option<i32>) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    if quorum.is_some_and(|quorum| quorum < 1) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("quorum", "Quorum must be at least 1 ballot")),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_results_visibility(visibility: Option<&str>) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    match visibility {
+        Some(value) if ResultsVisibility::parse(value).is_none() => {
+            let allowed: Vec<&str> = ResultsVisibility::ALL.iter().map(|v| v.as_str()).collect();
+            Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::<()>::validation_error(
+                    "results_visibility",
+                    &format!("Unknown results visibility '{}'; expected one of: {}", value, allowed.join(", ")),
+                )),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Longest custom receipt prefix
+const MAX_RECEIPT_PREFIX_LEN: usize = 12;
+
+// Receipt codes are split on '-' and the built-in prefixes mark how a code is looked up,
+// so a custom prefix must be plain uppercase letters and digits and not one of those
+fn validate_receipt_prefix(prefix: Option<&str>) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    let Some(prefix) = prefix else {
+        return Ok(());
+    };
+
+    let well_formed = !prefix.is_empty()
+        && prefix.len() <= MAX_RECEIPT_PREFIX_LEN
+        && prefix.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+    if !well_formed || RESERVED_RECEIPT_PREFIXES.contains(&prefix) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error(
+                "receipt_prefix",
+                &format!(
+                    "Receipt prefix must be 1-{} uppercase letters or digits, other than {}",
+                    MAX_RECEIPT_PREFIX_LEN,
+                    RESERVED_RECEIPT_PREFIXES.join(" or "),
+                ),
+            )),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_skipped_rankings_policy(policy: Option<&str>) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    match policy {
+        Some(value) if SkippedRankingsPolicy::parse(value).is_none() => {
+            let allowed: Vec<&str> = SkippedRankingsPolicy::ALL.iter().map(|p| p.as_str()).collect();
+            Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::<()>::validation_error(
+                    "skipped_rankings_policy",
+                    &format!("Unknown skipped rankings policy '{}'; expected one of: {}", value, allowed.join(", ")),
+                )),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check the winner count against the poll type and the number of candidates, not counting
+/// "None of the above"
+fn validate_num_winners(poll_type: PollType, num_winners: i32, candidate_count: usize) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    match poll_type {
+        PollType::SingleWinner if num_winners != 1 => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("num_winners", "Single-winner polls must have exactly 1 winner")),
+        )),
+        PollType::MultiWinner if num_winners < 2 => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("num_winners", "Multi-winner polls must have at least 2 winners")),
+        )),
+        PollType::MultiWinner if num_winners as usize >= candidate_count => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("num_winners", "Multi-winner polls must have fewer winners than candidates")),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn validate_win_condition(win_condition: Option<&str>, round_limit: Option<i32>) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    let name = win_condition.unwrap_or(WinCondition::Majority.name());
+    if !WinCondition::NAMES.contains(&name) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error(
+                "win_condition",
+                &format!("Unknown win condition '{}'; expected one of: {}", name, WinCondition::NAMES.join(", ")),
+            )),
+        ));
+    }
+    if round_limit.is_some_and(|limit| limit < 1) || WinCondition::from_settings(name, round_limit).is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error(
+                "plurality_round_limit",
+                "Plurality after rounds needs a round limit of at least 1",
+            )),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_tags(tags: Option<&[String]>) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    let Some(tags) = tags else {
+        return Ok(());
+    };
+    if tags.iter().any(|tag| tag.trim().is_empty()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("tags", "Tags cannot be empty")),
+        ));
+    }
+    if tags.iter().any(|tag| tag.trim().chars().count() > MAX_POLL_TAG_LEN) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error(
+                "tags",
+                &format!("Tags must be at most {} characters", MAX_POLL_TAG_LEN),
+            )),
+        ));
+    }
+    if normalize_tags(tags).len() > MAX_POLL_TAGS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error(
+                "tags",
+                &format!("A poll can have at most {} tags", MAX_POLL_TAGS),
+            )),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_default_locale(locale: Option<&str>) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    if locale.is_some_and(|locale| !is_valid_locale(locale)) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("default_locale", "Default locale must be a language tag such as 'en' or 'pt-BR'")),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_timezone(timezone: Option<&str>) -> Result<(), (StatusCode, Json<ApiResponse<()>>)> {
+    if timezone.is_some_and(|timezone| !is_valid_timezone(timezone)) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("timezone", "Timezone must be an IANA time zone name such as 'America/New_York'")),
+        ));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Serialize)]
 pub struct ApiResponse<T> {
     success: bool,
@@ -68,12 +309,22 @@ pub struct ApiResponse<T> {
 pub struct ApiError {
     code: String,
     message: String,
+    // Boxed so error results stay small
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    fields: Box<[FieldError]>,
+}
+
+/// One invalid input, named the way the request body spells it
+#[derive(Debug, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
 }
 
 #[derive(Debug, Serialize)]
 pub struct ApiMetadata {
     timestamp: String,
-    version: String,
+    version: &'static str,
 }
 
 #[derive(Debug, Serialize)]
@@ -93,7 +344,7 @@ impl<T> ApiResponse<T> {
             error: None,
             metadata: ApiMetadata {
                 timestamp: chrono::Utc::now().to_rfc3339(),
-                version: env!("CARGO_PKG_VERSION").to_string(),
+                version: env!("CARGO_PKG_VERSION"),
             },
         }
     }
@@ -105,13 +356,38 @@ impl<T> ApiResponse<T> {
             error: Some(ApiError {
                 code: code.to_string(),
                 message: message.to_string(),
+                fields: Box::default(),
             }),
             metadata: ApiMetadata {
                 timestamp: chrono::Utc::now().to_rfc3339(),
-                version: env!("CARGO_PKG_VERSION").to_string(),
+                version: env!("CARGO_PKG_VERSION"),
             },
         }
     }
+
+    /// A `VALIDATION_ERROR` blaming one input, so clients can highlight it
+    pub fn validation_error(field: &str, message: &str) -> ApiResponse<()> {
+        let mut response = Self::error("VALIDATION_ERROR", message);
+        if let Some(error) = response.error.as_mut() {
+            error.fields = Box::new([FieldError {
+                field: field.to_string(),
+                message: message.to_string(),
+            }]);
+        }
+        response
+    }
+}
+
+impl ApiResponse<()> {
+    /// Nest the blamed fields under a parent, e.g. `name` becomes `candidates[0].name`
+    pub fn within(mut self, parent: &str) -> Self {
+        if let Some(error) = self.error.as_mut() {
+            for field in error.fields.iter_mut() {
+                field.field = format!("{}.{}", parent, field.field);
+            }
+        }
+        self
+    }
 }
 
 pub async fn create_poll(
@@ -122,33 +398,104 @@ pub async fn create_poll(
     // Extract user ID from JWT token
     let user_id = get_current_user_id(&headers, &auth_service)?;
 
+    if email_verification_required() {
+        match User::find_by_id(auth_service.pool(), user_id).await {
+            Ok(Some(user)) if user.email_verified => {}
+            Ok(_) => {
+                return Err((
+                    StatusCode::FORBIDDEN,
+                    Json(ApiResponse::<()>::error("EMAIL_NOT_VERIFIED", "Please verify your email address before creating polls")),
+                ));
+            }
+            Err(e) => {
+                tracing::error!("Failed to look up user: {}", e);
+                return Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiResponse::<()>::error("POLL_CREATION_FAILED", "Failed to create poll")),
+                ));
+            }
+        }
+    }
+
     // Validate request
     if req.title.trim().is_empty() {
         return Err((
             StatusCode::BAD_REQUEST,
-            Json(ApiResponse::<()>::error("VALIDATION_ERROR", "Poll title is required")),
+            Json(ApiResponse::<()>::validation_error("title", "Poll title is required")),
         ));
     }
 
     if req.candidates.len() < 2 {
         return Err((
             StatusCode::BAD_REQUEST,
-            Json(ApiResponse::<()>::error("VALIDATION_ERROR", "At least 2 candidates are required")),
+            Json(ApiResponse::<()>::validation_error("candidates", "At least 2 candidates are required")),
         ));
     }
+    validate_candidate_count(req.candidates.len())?;
 
     // Validate candidate names
-    for candidate in &req.candidates {
-        if candidate.name.trim().is_empty() {
-            return Err((
+    for (index, candidate) in req.candidates.iter().enumerate() {
+        let within_candidate = |(status, Json(error)): (StatusCode, Json<ApiResponse<()>>)| {
+            (status, Json(error.within(&format!("candidates[{}]", index))))
+        };
+        if sanitize_candidate_name(&candidate.name).is_empty() {
+            return Err(within_candidate((
                 StatusCode::BAD_REQUEST,
-                Json(ApiResponse::<()>::error("VALIDATION_ERROR", "All candidate names are required")),
-            ));
+                Json(ApiResponse::<()>::validation_error("name", "All candidate names are required")),
+            )));
         }
+        validate_candidate_text(Some(&candidate.name), candidate.description.as_deref(), candidate.statement.as_deref()).map_err(within_candidate)?;
+        validate_candidate_affiliation(candidate.affiliation.as_deref()).map_err(within_candidate)?;
+        validate_image_url(candidate.image_url.as_deref()).map_err(within_candidate)?;
+    }
+
+    let reserved_names = req.allow_none_of_the_above.unwrap_or(false).then_some(NOTA_CANDIDATE_NAME);
+    if let Some(index) = find_duplicate_candidate_name(reserved_names, req.candidates.iter().map(|c| c.name.as_str())) {
+        let (status, Json(error)) = duplicate_candidate_name_error(&req.candidates[index].name);
+        return Err((status, Json(error.within(&format!("candidates[{}]", index)))));
+    }
+
+    let poll_type = match req.poll_type.as_deref() {
+        None => PollType::SingleWinner,
+        Some(value) => match PollType::parse(value) {
+            Some(poll_type) => poll_type,
+            None => {
+                let allowed: Vec<&str> = PollType::ALL.iter().map(|t| t.as_str()).collect();
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiResponse::<()>::validation_error(
+                        "poll_type",
+                        &format!("Unknown poll type '{}'; expected one of: {}", value, allowed.join(", ")),
+                    )),
+                ));
+            }
+        },
+    };
+
+    validate_num_winners(poll_type, req.num_winners.unwrap_or(1), req.candidates.len())?;
+    validate_quorum(req.quorum)?;
+    validate_min_rankings(req.min_rankings, req.candidates.len())?;
+    validate_skipped_rankings_policy(req.skipped_rankings_policy.as_deref())?;
+    validate_default_locale(req.default_locale.as_deref())?;
+    validate_timezone(req.timezone.as_deref())?;
+    validate_results_visibility(req.results_visibility.as_deref())?;
+    validate_receipt_prefix(req.receipt_prefix.as_deref())?;
+    validate_tags(req.tags.as_deref())?;
+    validate_win_condition(req.win_condition.as_deref(), req.plurality_round_limit)?;
+
+    if req.closes_at.is_some_and(|closes_at| closes_at <= Utc::now()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::validation_error("closes_at", "Poll close time must be in the future")),
+        ));
     }
+    validate_schedule(req.opens_at, req.closes_at, auth_service.min_poll_duration())?;
 
     match Poll::create(auth_service.pool(), user_id, req).await {
-        Ok(poll) => Ok(Json(ApiResponse::success(poll))),
+        Ok(poll) => {
+            metrics().record_poll_created();
+            Ok(Json(ApiResponse::success(poll)))
+        }
         Err(e) => {
             tracing::error!("Failed to create poll: {}", e);
             Err((
@@ -159,6 +506,57 @@ pub async fn create_poll(
     }
 }
 
+/// GET /api/polls/:id/export - The poll's configuration and candidates as a portable document
+pub async fn export_poll(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Path(poll_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<PollExport>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = get_current_user_id(&headers, &auth_service)?;
+
+    match Poll::find_by_id_and_user(auth_service.pool(), poll_id, user_id).await {
+        Ok(Some(poll)) => Ok(Json(ApiResponse::success(PollExport::from(poll)))),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
+        )),
+        Err(e) => {
+            tracing::error!("Failed to get poll: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("POLL_EXPORT_FAILED", "Failed to export poll")),
+            ))
+        }
+    }
+}
+
+/// POST /api/polls/import - Create a poll for the current user from an exported document.
+/// The document goes through the same validation as `create_poll`.
+pub async fn import_poll(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Json(document): Json<serde_json::Value>,
+) -> Result<Json<ApiResponse<crate::models::poll::PollResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let export: PollExport = serde_json::from_value(document).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::error("VALIDATION_ERROR", &format!("Invalid poll export: {}", e))),
+        )
+    })?;
+
+    if export.format_version != POLL_EXPORT_FORMAT_VERSION {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::error(
+                "VALIDATION_ERROR",
+                &format!("Unsupported export format version {}; expected {}", export.format_version, POLL_EXPORT_FORMAT_VERSION),
+            )),
+        ));
+    }
+
+    create_poll(State(auth_service), headers, Json(CreatePollRequest::from(export))).await
+}
+
 pub async fn list_polls(
     State(auth_service): State<AuthService>,
     headers: HeaderMap,
@@ -192,6 +590,35 @@ pub async fn list_polls(
     }
 }
 
+/// GET /api/public/polls - Public polls open for voting, soonest to close first (no auth required)
+pub async fn list_public_polls(
+    State(auth_service): State<AuthService>,
+    Query(query): Query<PublicPollListQuery>,
+) -> Result<Json<ApiResponse<PaginatedResponse<PublicPollListItem>>>, (StatusCode, Json<ApiResponse<()>>)> {
+    match Poll::list_public(auth_service.pool(), &query).await {
+        Ok((polls, total)) => {
+            let page = query.page.unwrap_or(1).max(1);
+            let limit = query.limit.unwrap_or(20).clamp(1, 100);
+            let total_pages = (total as f64 / limit as f64).ceil() as i32;
+
+            Ok(Json(ApiResponse::success(PaginatedResponse {
+                items: polls,
+                total,
+                page,
+                limit,
+                total_pages,
+            })))
+        }
+        Err(e) => {
+            tracing::error!("Failed to list public polls: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("POLL_LIST_FAILED", "Failed to retrieve polls")),
+            ))
+        }
+    }
+}
+
 /// GET /api/public/polls/:id - Get public poll (no auth required)
 pub async fn get_public_poll(
     Path(poll_id): Path<Uuid>,
@@ -230,6 +657,24 @@ pub async fn get_public_poll(
                 closes_at: poll.closes_at,
                 is_public: poll.is_public,
                 registration_required: poll.registration_required,
+                require_full_ranking: poll.require_full_ranking,
+                allow_write_ins: poll.allow_write_ins,
+                quorum: poll.quorum,
+                skipped_rankings_policy: poll.skipped_rankings_policy,
+                default_locale: poll.default_locale,
+                results_visibility: poll.results_visibility,
+                min_rankings: poll.min_rankings,
+                allow_none_of_the_above: poll.allow_none_of_the_above,
+                receipt_prefix: poll.receipt_prefix,
+                win_condition: poll.win_condition,
+                plurality_round_limit: poll.plurality_round_limit,
+                require_captcha: poll.require_captcha,
+                timezone: poll.timezone,
+                tie_break_seed: poll.tie_break_seed,
+                status: poll.status,
+                opens_at_local: poll.opens_at_local,
+                closes_at_local: poll.closes_at_local,
+                tags: poll.tags,
                 created_at: poll.created_at,
                 updated_at: poll.updated_at,
                 candidates,
@@ -251,15 +696,23 @@ pub async fn get_public_poll(
     }
 }
 
+/// GET /api/polls/:id - Get one of the current user's polls. Supports conditional
+/// requests: a matching `If-None-Match` gets `304 Not Modified`.
 pub async fn get_poll(
     State(auth_service): State<AuthService>,
     headers: HeaderMap,
     Path(poll_id): Path<Uuid>,
-) -> Result<Json<ApiResponse<crate::models::poll::PollResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+) -> Result<Response, (StatusCode, Json<ApiResponse<()>>)> {
     let user_id = get_current_user_id(&headers, &auth_service)?;
 
     match Poll::find_by_id_and_user(auth_service.pool(), poll_id, user_id).await {
-        Ok(Some(poll)) => Ok(Json(ApiResponse::success(poll))),
+        Ok(Some(poll)) => {
+            let etag = poll_etag(&poll);
+            if let Some(response) = not_modified(&headers, &etag) {
+                return Ok(response);
+            }
+            Ok(with_etag(&etag, Json(ApiResponse::success(poll))))
+        }
         Ok(None) => Err((
             StatusCode::NOT_FOUND,
             Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
@@ -274,6 +727,16 @@ pub async fn get_poll(
     }
 }
 
+/// Weak ETag for a poll as returned by the API. Candidate edits don't touch the poll's
+/// `updated_at`, so the candidates are part of the tag too.
+pub(crate) fn poll_etag(poll: &crate::models::poll::PollResponse) -> String {
+    weak_etag([
+        poll.id.to_string(),
+        poll.updated_at.to_rfc3339(),
+        serde_json::to_string(&poll.candidates).unwrap_or_default(),
+    ])
+}
+
 pub async fn update_poll(
     State(auth_service): State<AuthService>,
     headers: HeaderMap,
@@ -287,17 +750,143 @@ pub async fn update_poll(
         if title.trim().is_empty() {
             return Err((
                 StatusCode::BAD_REQUEST,
-                Json(ApiResponse::<()>::error("VALIDATION_ERROR", "Poll title cannot be empty")),
+                Json(ApiResponse::<()>::validation_error("title", "Poll title cannot be empty")),
             ));
         }
     }
 
+    if let Some(ref candidates) = req.candidates {
+        if candidates.len() < 2 {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::<()>::validation_error("candidates", "At least 2 candidates are required")),
+            ));
+        }
+        validate_candidate_count(candidates.len())?;
+
+        if candidates.iter().any(|c| sanitize_candidate_name(&c.name).is_empty()) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::<()>::validation_error("candidates", "All candidate names are required")),
+            ));
+        }
+
+        for candidate in candidates {
+            validate_candidate_text(Some(&candidate.name), candidate.description.as_deref(), candidate.statement.as_deref())?;
+            validate_candidate_affiliation(candidate.affiliation.as_deref())?;
+            validate_image_url(candidate.image_url.as_deref())?;
+        }
+    }
+
+    validate_quorum(req.quorum.flatten())?;
+    validate_skipped_rankings_policy(req.skipped_rankings_policy.as_deref())?;
+    validate_default_locale(req.default_locale.as_deref())?;
+    validate_timezone(req.timezone.as_ref().and_then(|timezone| timezone.as_deref()))?;
+    validate_results_visibility(req.results_visibility.as_deref())?;
+    validate_receipt_prefix(req.receipt_prefix.as_ref().and_then(|prefix| prefix.as_deref()))?;
+    validate_tags(req.tags.as_deref())?;
+
+    let schedule_changed = req.opens_at.is_some() || req.closes_at.is_some();
+    let win_condition_changed = req.win_condition.is_some() || req.plurality_round_limit.is_some();
+    if !schedule_changed && !win_condition_changed && req.candidates.is_none() && req.min_rankings.is_none()
+        && req.allow_none_of_the_above != Some(false)
+    {
+        return apply_poll_update(&auth_service, poll_id, user_id, req).await;
+    }
+
+    let current_poll = match Poll::find_by_id_and_user(auth_service.pool(), poll_id, user_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
+            ));
+        }
+        Err(e) => {
+            tracing::error!("Failed to get poll: {}", e);
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("POLL_UPDATE_FAILED", "Failed to update poll")),
+            ));
+        }
+    };
+
+    // Omitted dates keep their current values, so check the resulting schedule
+    if schedule_changed {
+        validate_schedule(
+            req.opens_at.or(current_poll.opens_at),
+            req.closes_at.or(current_poll.closes_at),
+            auth_service.min_poll_duration(),
+        )?;
+    }
+
+    // Check the minimum against the candidate list the poll will end up with
+    validate_min_rankings(
+        req.min_rankings.unwrap_or(current_poll.min_rankings),
+        req.candidates.as_ref().map_or(current_poll.candidates.len(), Vec::len),
+    )?;
+
+    // Either half of the win condition may be omitted or cleared, so check the rule the poll ends up with
+    validate_win_condition(
+        Some(req.win_condition.as_deref().unwrap_or(&current_poll.win_condition)),
+        req.plurality_round_limit.unwrap_or(current_poll.plurality_round_limit),
+    )?;
+
+    // Validate candidate changes against the poll's current candidates
+    if let Some(ref candidates) = req.candidates {
+        // The new list has to leave a multi-winner poll more candidates than seats
+        if let Some(poll_type) = PollType::parse(&current_poll.poll_type) {
+            validate_num_winners(poll_type, current_poll.num_winners, candidates.len())?;
+        }
+
+        // "None of the above" is managed by the poll setting, so the list never includes it
+        let existing_ids: std::collections::HashSet<Uuid> = current_poll.candidates.iter()
+            .filter(|c| !c.is_nota)
+            .map(|c| c.id)
+            .collect();
+        let mut requested_ids = std::collections::HashSet::new();
+        for id in candidates.iter().filter_map(|c| c.id) {
+            if !existing_ids.contains(&id) || !requested_ids.insert(id) {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiResponse::<()>::error("VALIDATION_ERROR", "Candidate IDs must be unique and belong to this poll")),
+                ));
+            }
+        }
+
+        // The list replaces every official candidate, so it only has to agree with itself
+        let keeps_nota = req.allow_none_of_the_above.unwrap_or(current_poll.allow_none_of_the_above);
+        let reserved_names = keeps_nota.then_some(NOTA_CANDIDATE_NAME);
+        if let Some(index) = find_duplicate_candidate_name(reserved_names, candidates.iter().map(|c| c.name.as_str())) {
+            let (status, Json(error)) = duplicate_candidate_name_error(&candidates[index].name);
+            return Err((status, Json(error.within(&format!("candidates[{}]", index)))));
+        }
+    }
+
+    apply_poll_update(&auth_service, poll_id, user_id, req).await
+}
+
+async fn apply_poll_update(
+    auth_service: &AuthService,
+    poll_id: Uuid,
+    user_id: Uuid,
+    req: UpdatePollRequest,
+) -> Result<Json<ApiResponse<crate::models::poll::PollResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
     match Poll::update(auth_service.pool(), poll_id, user_id, req).await {
         Ok(Some(poll)) => Ok(Json(ApiResponse::success(poll))),
         Ok(None) => Err((
             StatusCode::NOT_FOUND,
             Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
         )),
+        // Turning "None of the above" off or dropping candidates deletes their rankings
+        Err(PollUpdateError::CandidatesLocked) => Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::<()>::error("CANDIDATES_LOCKED", "Candidates cannot be removed after votes have been cast")),
+        )),
+        Err(PollUpdateError::PollClosed) => Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::<()>::error("POLL_CLOSED", "The schedule and candidates of a closed poll cannot be changed")),
+        )),
         Err(e) => {
             tracing::error!("Failed to update poll: {}", e);
             Err((
@@ -308,14 +897,27 @@ pub async fn update_poll(
     }
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct DeletePollQuery {
+    pub permanent: Option<bool>,
+}
+
 pub async fn delete_poll(
     State(auth_service): State<AuthService>,
     headers: HeaderMap,
     Path(poll_id): Path<Uuid>,
+    Query(query): Query<DeletePollQuery>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<ApiResponse<()>>)> {
     let user_id = get_current_user_id(&headers, &auth_service)?;
 
-    match Poll::delete(auth_service.pool(), poll_id, user_id).await {
+    // Polls are soft-deleted unless the owner asks for permanent removal
+    let deleted = if query.permanent.unwrap_or(false) {
+        Poll::delete_permanently(auth_service.pool(), poll_id, user_id).await
+    } else {
+        Poll::delete(auth_service.pool(), poll_id, user_id).await
+    };
+
+    match deleted {
         Ok(true) => Ok(Json(ApiResponse::success(()))),
         Ok(false) => Err((
             StatusCode::NOT_FOUND,
@@ -329,4 +931,210 @@ pub async fn delete_poll(
             ))
         }
     }
-} 
\ No newline at end of file
+} 
+
+pub async fn restore_poll(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Path(poll_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<crate::models::poll::PollResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = get_current_user_id(&headers, &auth_service)?;
+
+    match Poll::restore(auth_service.pool(), poll_id, user_id).await {
+        Ok(Some(poll)) => Ok(Json(ApiResponse::success(poll))),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "No deleted poll found to restore")),
+        )),
+        Err(e) => {
+            tracing::error!("Failed to restore poll: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("POLL_RESTORE_FAILED", "Failed to restore poll")),
+            ))
+        }
+    }
+}
+
+/// POST /api/polls/:id/close-now - End voting immediately
+pub async fn close_poll_now(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Path(poll_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<crate::models::poll::PollResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = get_current_user_id(&headers, &auth_service)?;
+    let pool = auth_service.pool();
+
+    let close_failed = |e: sqlx::Error| {
+        tracing::error!("Failed to close poll: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::<()>::error("POLL_CLOSE_FAILED", "Failed to close poll")),
+        )
+    };
+
+    if Poll::find_by_id_and_user(pool, poll_id, user_id).await.map_err(close_failed)?.is_none() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
+        ));
+    }
+
+    if !Poll::close_now(pool, poll_id, user_id).await.map_err(close_failed)? {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::<()>::error("POLL_ALREADY_CLOSED", "Poll is already closed")),
+        ));
+    }
+
+    // Cache the final results now rather than waiting for the background closer. The poll
+    // is already past its close time, so if this fails the closer finishes it on its next scan
+    if let Err(e) = poll_closer::finalize_poll_now(&auth_service, poll_id).await {
+        tracing::warn!("Poll {} closed but not yet finalized: {}", poll_id, e);
+    }
+
+    match Poll::find_by_id_and_user(pool, poll_id, user_id).await.map_err(close_failed)? {
+        Some(poll) => Ok(Json(ApiResponse::success(poll))),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
+        )),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResetPollQuery {
+    pub confirm: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResetPollResponse {
+    pub ballots_deleted: u64,
+}
+
+/// POST /api/polls/:id/reset - Clear all votes, drafts and write-ins while keeping the
+/// official candidates and voters, reopening a closed poll whose close time has not passed.
+/// Polls that have already opened also require `?confirm=true`.
+pub async fn reset_poll(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Path(poll_id): Path<Uuid>,
+    Query(query): Query<ResetPollQuery>,
+) -> Result<Json<ApiResponse<ResetPollResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = get_current_user_id(&headers, &auth_service)?;
+    let pool = auth_service.pool();
+
+    let reset_failed = |e: sqlx::Error| {
+        tracing::error!("Failed to reset poll: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::<()>::error("POLL_RESET_FAILED", "Failed to reset poll")),
+        )
+    };
+
+    let poll = match Poll::find_by_id_and_user(pool, poll_id, user_id).await.map_err(reset_failed)? {
+        Some(poll) => poll,
+        None => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
+            ));
+        }
+    };
+
+    // Only draft polls (not yet open) can be wiped without an explicit confirmation
+    let is_draft = poll.opens_at.is_some_and(|opens_at| opens_at > Utc::now());
+    if !is_draft && !query.confirm.unwrap_or(false) {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::<()>::error(
+                "CONFIRMATION_REQUIRED",
+                "This poll has opened; pass confirm=true to delete its votes",
+            )),
+        ));
+    }
+
+    match Poll::reset_votes(pool, poll_id, user_id).await.map_err(reset_failed)? {
+        Some(ballots_deleted) => {
+            tracing::info!("Poll {} reset by user {}: {} ballots deleted", poll_id, user_id, ballots_deleted);
+            Ok(Json(ApiResponse::success(ResetPollResponse { ballots_deleted })))
+        }
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
+        )),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransferPollRequest {
+    pub email: String,
+}
+
+pub async fn transfer_poll(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Path(poll_id): Path<Uuid>,
+    Json(req): Json<TransferPollRequest>,
+) -> Result<Json<ApiResponse<crate::models::poll::PollResponse>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let user_id = get_current_user_id(&headers, &auth_service)?;
+    let pool = auth_service.pool();
+
+    let new_owner = match User::find_by_email(pool, req.email.trim()).await {
+        Ok(Some(user)) => user,
+        Ok(None) => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::<()>::error("USER_NOT_FOUND", "No user exists with that email address")),
+            ));
+        }
+        Err(e) => {
+            tracing::error!("Failed to look up user: {}", e);
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("POLL_TRANSFER_FAILED", "Failed to transfer poll")),
+            ));
+        }
+    };
+
+    if new_owner.id == user_id {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::<()>::error("VALIDATION_ERROR", "You already own this poll")),
+        ));
+    }
+
+    match Poll::transfer_ownership(pool, poll_id, user_id, new_owner.id).await {
+        Ok(true) => {}
+        Ok(false) => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
+            ));
+        }
+        Err(e) => {
+            tracing::error!("Failed to transfer poll: {}", e);
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("POLL_TRANSFER_FAILED", "Failed to transfer poll")),
+            ));
+        }
+    }
+
+    tracing::info!("Poll {} transferred from user {} to user {}", poll_id, user_id, new_owner.id);
+
+    match Poll::find_by_id(pool, poll_id).await {
+        Ok(Some(poll)) => Ok(Json(ApiResponse::success(poll))),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error("POLL_NOT_FOUND", "Poll not found")),
+        )),
+        Err(e) => {
+            tracing::error!("Failed to get poll: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error("POLL_TRANSFER_FAILED", "Failed to transfer poll")),
+            ))
+        }
+    }
+}
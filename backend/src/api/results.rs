@@ -1,21 +1,36 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
-    Json,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
 };
-use serde::Serialize;
+use futures_util::stream;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tokio::sync::broadcast::error::RecvError;
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::collections::{btree_map::Entry, BTreeMap, HashMap, HashSet};
+use std::time::Duration;
 use chrono;
 
+use crate::api::admin::current_admin;
+use crate::api::etag::{not_modified, weak_etag, with_etag};
+use crate::api::json::Json;
+use crate::api::polls::poll_etag;
 use crate::models::{
-    ballot::Ballot,
-    poll::Poll,
+    ballot::{Ballot, BallotRanking},
+    poll::{Poll, PollResponse, PollType, ResultsVisibility},
+    poll_closure::PollClosure,
     candidate::Candidate,
+    result_version::{ResultVersion, ResultVersionSummary},
 };
 use crate::services::{
     auth::AuthService,
-    rcv::{SingleWinnerRCV, Candidate as RcvCandidate},
+    email::{EmailResponseData, EmailService},
+    results::{build_poll_results, round_infos, send_results_emails, tabulate, tabulation_algorithm, FinalRanking, PollResultsResponse, RoundInfo},
+    rcv::{count_stale, find_smallest_cycle, PairwiseMatrix, Ballot as RcvBallot, Candidate as RcvCandidate, TabulationError, TieBreakMethod, WinCondition},
 };
 
 // Reuse the same response structures
@@ -39,31 +54,47 @@ pub struct ApiMetadata {
     version: String,
 }
 
-#[derive(Debug, Serialize)]
-pub struct PollResultsResponse {
-    pub poll_id: Uuid,
-    pub total_votes: usize,
-    pub status: String,
-    pub winner: Option<WinnerInfo>,
-    pub final_rankings: Vec<FinalRanking>,
+#[derive(Debug, Deserialize)]
+pub struct PollResultsQuery {
+    pub include_rounds: Option<bool>,
 }
 
+/// The settings a tabulation was counted under, recorded with each results version
 #[derive(Debug, Serialize)]
-pub struct WinnerInfo {
-    pub candidate_id: Uuid,
-    pub name: String,
-    pub final_votes: f64,
-    pub percentage: f64,
+pub struct TabulationSettings {
+    pub poll_type: String,
+    pub algorithm: String,
+    pub num_winners: i32,
+    pub quorum: Option<i32>,
+    pub allow_none_of_the_above: bool,
+    /// How eliminations are decided when candidates tie, for the methods that eliminate
+    pub tie_break: Option<TieBreakMethod>,
+    /// When the instant-runoff count may stop, for single-winner polls
+    pub win_condition: Option<WinCondition>,
+    pub candidate_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PreviewResultsRequest {
+    pub ballots: Vec<PreviewBallot>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct PreviewBallot {
+    pub rankings: Vec<BallotRanking>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BatchResultsRequest {
+    pub poll_ids: Vec<Uuid>,
+}
+
+/// One poll's entry in a batch: its results, or why they couldn't be computed
 #[derive(Debug, Serialize)]
-pub struct FinalRanking {
-    pub position: usize,
-    pub candidate_id: Uuid,
-    pub name: String,
-    pub votes: f64,
-    pub percentage: f64,
-    pub eliminated_round: Option<usize>,
+#[serde(untagged)]
+pub enum BatchPollResult {
+    Results(Box<PollResultsResponse>),
+    Error { error: ApiError },
 }
 
 #[derive(Debug, Serialize)]
@@ -71,41 +102,64 @@ pub struct RcvRoundsResponse {
     pub rounds: Vec<RoundInfo>,
     pub total_ballots: usize,
     pub exhausted_ballots: usize,
+    pub spoiled_ballots: usize,
+    pub stale_ballot_count: usize,
+    pub computed_at: chrono::DateTime<chrono::Utc>,
+    pub algorithm: String,
 }
 
 #[derive(Debug, Serialize)]
-pub struct RoundInfo {
-    pub round_number: usize,
-    pub vote_counts: HashMap<Uuid, VoteCounts>,
-    pub eliminated: Option<EliminatedCandidate>,
-    pub winner: Option<WinnerCandidate>,
-    pub exhausted_ballots: usize,
-    pub total_votes: f64,
-    pub majority_threshold: f64,
-    pub tiebreak_reason: Option<String>,
+pub struct FirstChoiceResponse {
+    /// Most first-choice votes first; ties keep the poll's candidate order
+    pub candidates: Vec<FirstChoiceCount>,
+    pub total_ballots: usize,
+    /// Ballots with a first choice, the base for `percentage`
+    pub total_votes: usize,
 }
 
 #[derive(Debug, Serialize)]
-pub struct VoteCounts {
+pub struct FirstChoiceCount {
     pub candidate_id: Uuid,
     pub name: String,
-    pub votes: f64,
+    pub votes: usize,
     pub percentage: f64,
 }
 
 #[derive(Debug, Serialize)]
-pub struct EliminatedCandidate {
-    pub candidate_id: Uuid,
-    pub name: String,
+pub struct AffiliationResultsResponse {
+    /// Most votes first; unaffiliated candidates are grouped under a `null` affiliation
+    pub affiliations: Vec<AffiliationTotal>,
+    pub total_votes: usize,
+    pub winners: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AffiliationTotal {
+    pub affiliation: Option<String>,
+    /// Members in final-ranking order
+    pub candidates: Vec<FinalRanking>,
+    /// Votes held by the group's members in the final round; earlier eliminations have transferred on
     pub votes: f64,
+    /// Share of the final round's votes
+    pub percentage: f64,
+    pub seats_won: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PairwiseResponse {
+    pub candidates: Vec<PairwiseCandidate>,
+    /// `matrix[a][b]` is the number of ballots ranking `a` above `b`
+    pub matrix: HashMap<Uuid, HashMap<Uuid, usize>>,
+    pub condorcet_winner: Option<Uuid>,
+    /// Shortest cycle of head-to-head defeats, each candidate beating the next and the last beating the first
+    pub cycle: Option<Vec<Uuid>>,
+    pub total_ballots: usize,
 }
 
 #[derive(Debug, Serialize)]
-pub struct WinnerCandidate {
+pub struct PairwiseCandidate {
     pub candidate_id: Uuid,
     pub name: String,
-    pub votes: f64,
-    pub percentage: f64,
 }
 
 impl<T> ApiResponse<T> {
@@ -205,12 +259,105 @@ fn get_current_user_id(headers: &HeaderMap, auth_service: &AuthService) -> Resul
         })
 }
 
+// Snapshot the settings `build_poll_results` counts this poll under
+fn tabulation_settings(poll: &PollResponse, candidates: &[Candidate]) -> TabulationSettings {
+    let eliminates = matches!(PollType::parse(&poll.poll_type), Some(PollType::SingleWinner) | Some(PollType::MultiWinner));
+    let tie_break = eliminates.then(|| poll.tie_break());
+    let win_condition = (PollType::parse(&poll.poll_type) == Some(PollType::SingleWinner)).then(|| poll.win_condition());
+
+    TabulationSettings {
+        poll_type: poll.poll_type.clone(),
+        algorithm: tabulation_algorithm(&poll.poll_type).to_string(),
+        num_winners: poll.num_winners,
+        quorum: poll.quorum,
+        allow_none_of_the_above: poll.allow_none_of_the_above,
+        tie_break,
+        win_condition,
+        candidate_ids: candidates.iter().map(|c| c.id).collect(),
+    }
+}
+
+// Keep the tabulation in the poll's results history, keyed on the fingerprint of what it
+// was counted from. A failure to record is logged rather than withholding the results.
+async fn record_result_version(
+    pool: &PgPool,
+    poll: &PollResponse,
+    candidates: &[Candidate],
+    results: &PollResultsResponse,
+    fingerprint: &str,
+) {
+    let (Ok(settings), Ok(results)) = (
+        serde_json::to_value(tabulation_settings(poll, candidates)),
+        serde_json::to_value(results),
+    ) else {
+        return;
+    };
+
+    if let Err(e) = ResultVersion::record(pool, poll.id, fingerprint, &settings, &results).await {
+        tracing::error!("Failed to record results version for poll {}: {}", poll.id, e);
+    }
+}
+
+// Fingerprint of everything a poll's results are counted from. Reads only the ballot count
+// and latest submission, so unchanged results can be answered with a 304 before anything
+// is counted. Whether the poll has closed is part of it, since passing `closes_at` changes
+// the reported status without touching the poll row.
+async fn results_fingerprint(pool: &PgPool, poll: &PollResponse) -> Result<String, sqlx::Error> {
+    let participation = Poll::participation(pool, poll.id).await?;
+    let is_closed = poll.closes_at.is_some_and(|closes| chrono::Utc::now() > closes);
+    Ok(weak_etag([
+        poll_etag(poll),
+        (participation.registered_ballots + participation.anonymous_ballots).to_string(),
+        participation.last_ballot_at.map(|at| at.to_rfc3339()).unwrap_or_default(),
+        is_closed.to_string(),
+    ]))
+}
+
+// Weak ETag for one representation of a poll's results
+fn results_etag(fingerprint: &str, include_rounds: bool) -> String {
+    weak_etag([fingerprint, &include_rounds.to_string()])
+}
+
+// The results a closed poll declared when it closed, with the reason it closed.
+// `None` when the poll is still open or couldn't be counted at close.
+async fn closed_poll_results(pool: &PgPool, poll_id: Uuid, include_rounds: bool) -> Result<Option<serde_json::Value>, sqlx::Error> {
+    let Some(closure) = PollClosure::find(pool, poll_id).await? else {
+        return Ok(None);
+    };
+    let Some(serde_json::Value::Object(mut results)) = closure.results else {
+        return Ok(None);
+    };
+
+    if !include_rounds {
+        results.remove("rounds");
+    }
+    results.insert("close_reason".to_string(), closure.close_reason.into());
+    results.insert("closed_at".to_string(), serde_json::json!(closure.closed_at));
+    Ok(Some(serde_json::Value::Object(results)))
+}
+
+// Failures caused by the poll's setup get a structured error; anything else is a bare 500
+fn tabulation_error_response(error: TabulationError) -> Result<Response, StatusCode> {
+    let (status, code) = match error {
+        TabulationError::InsufficientCandidates(_) => (StatusCode::UNPROCESSABLE_ENTITY, "INSUFFICIENT_CANDIDATES"),
+        TabulationError::TooManyRounds => (StatusCode::INTERNAL_SERVER_ERROR, "TABULATION_DID_NOT_CONVERGE"),
+        TabulationError::InvalidBallot(_) | TabulationError::UnsupportedPollType(_) => {
+            tracing::error!("RCV tabulation error: {}", error);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    tracing::warn!("RCV tabulation error: {}", error);
+    Ok((status, Json(create_error_response::<()>(code, &error.to_string()))).into_response())
+}
+
 /// GET /api/polls/:id/results - Get poll results
 pub async fn get_poll_results(
     Path(poll_id): Path<Uuid>,
+    Query(query): Query<PollResultsQuery>,
     State(auth_service): State<AuthService>,
     headers: HeaderMap,
-) -> Result<Json<ApiResponse<PollResultsResponse>>, StatusCode> {
+) -> Result<Response, StatusCode> {
     let pool = auth_service.pool();
     
     // Extract user ID from JWT token
@@ -223,7 +370,7 @@ pub async fn get_poll_results(
     let poll = match Poll::find_by_id(pool, poll_id).await {
         Ok(Some(poll)) => poll,
         Ok(None) => {
-            return Ok(Json(create_error_response::<PollResultsResponse>("NOT_FOUND", "Poll not found")));
+            return Ok(Json(create_error_response::<PollResultsResponse>("NOT_FOUND", "Poll not found")).into_response());
         }
         Err(e) => {
             tracing::error!("Database error finding poll: {}", e);
@@ -237,6 +384,29 @@ pub async fn get_poll_results(
         // return Ok(Json(create_error_response("FORBIDDEN", "You don't have permission to view these results")));
     }
 
+    let include_rounds = query.include_rounds.unwrap_or(false);
+    let fingerprint = match results_fingerprint(pool, &poll).await {
+        Ok(fingerprint) => fingerprint,
+        Err(e) => {
+            tracing::error!("Database error fingerprinting ballots: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    let etag = results_etag(&fingerprint, include_rounds);
+    if let Some(response) = not_modified(&headers, &etag) {
+        return Ok(response);
+    }
+
+    // A closed poll's declared outcome stands, whatever has been edited since
+    match closed_poll_results(pool, poll_id, include_rounds).await {
+        Ok(Some(results)) => return Ok(with_etag(&etag, Json(create_api_response(results)))),
+        Ok(None) => {}
+        Err(e) => {
+            tracing::error!("Database error finding poll closure: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    }
+
     // Get candidates
     let candidates = match Candidate::find_by_poll_id(pool, poll_id).await {
         Ok(candidates) => candidates,
@@ -255,112 +425,304 @@ pub async fn get_poll_results(
         }
     };
 
-    if ballots.is_empty() {
-        return Ok(Json(create_api_response(PollResultsResponse {
-            poll_id,
-            total_votes: 0,
-            status: "no_votes".to_string(),
-            winner: None,
-            final_rankings: Vec::new(),
-        })));
+    // Versions always keep the rounds, whether or not this request asked for them
+    let mut response = match build_poll_results(&poll, &candidates, ballots, true) {
+        Ok(response) => response,
+        Err(e) => return tabulation_error_response(e),
+    };
+    record_result_version(pool, &poll, &candidates, &response, &fingerprint).await;
+    if !include_rounds {
+        response.rounds = None;
     }
 
-    // Convert to RCV format
-    let rcv_candidates: Vec<RcvCandidate> = candidates.iter()
-        .map(|c| RcvCandidate {
-            id: c.id,
-            name: c.name.clone(),
-        })
-        .collect();
+    Ok(with_etag(&etag, Json(create_api_response(response))))
+}
 
-    // Run RCV tabulation
-    let rcv_engine = SingleWinnerRCV::new(rcv_candidates.clone(), ballots.clone());
-    let rcv_result = match rcv_engine.tabulate() {
-        Ok(result) => result,
+/// GET /api/polls/:id/results/versions - Every distinct tabulation of the poll, oldest first
+pub async fn list_result_versions(
+    Path(poll_id): Path<Uuid>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let pool = auth_service.pool();
+
+    let poll = match Poll::find_by_id(pool, poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok((StatusCode::NOT_FOUND, Json(create_error_response::<()>("NOT_FOUND", "Poll not found"))).into_response());
+        }
         Err(e) => {
-            tracing::error!("RCV tabulation error: {}", e);
+            tracing::error!("Database error finding poll: {}", e);
             return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
 
-    // Determine poll status
-    let now = chrono::Utc::now();
-    let is_closed = poll.closes_at.map_or(false, |closes| now > closes);
-    let status = if is_closed {
-        "completed"
-    } else if rcv_result.winner.is_some() {
-        "winner_declared"
-    } else {
-        "in_progress"
+    if let Err(response) = authorize_results_view(&poll, &headers, &auth_service) {
+        return Ok(*response);
+    }
+
+    match ResultVersion::list_for_poll(pool, poll_id).await {
+        Ok(versions) => Ok(Json(create_api_response::<Vec<ResultVersionSummary>>(versions)).into_response()),
+        Err(e) => {
+            tracing::error!("Database error listing results versions: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// GET /api/polls/:id/results/versions/:version - One recorded tabulation with its settings
+pub async fn get_result_version(
+    Path((poll_id, version)): Path<(Uuid, i32)>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let pool = auth_service.pool();
+
+    let poll = match Poll::find_by_id(pool, poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok((StatusCode::NOT_FOUND, Json(create_error_response::<()>("NOT_FOUND", "Poll not found"))).into_response());
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
     };
 
-    // Get final round for results
-    let final_round = rcv_result.rounds.last();
-    
-    let winner = if let (Some(winner_id), Some(final_round)) = (rcv_result.winner, final_round) {
-        if let Some(candidate) = rcv_candidates.iter().find(|c| c.id == winner_id) {
-            let winner_votes = final_round.vote_counts.get(&winner_id).unwrap_or(&0.0);
-            let percentage = if final_round.total_votes > 0.0 {
-                (winner_votes / final_round.total_votes) * 100.0
-            } else {
-                0.0
-            };
-            
-            Some(WinnerInfo {
-                candidate_id: winner_id,
-                name: candidate.name.clone(),
-                final_votes: *winner_votes,
-                percentage,
-            })
-        } else {
-            None
-        }
-    } else {
-        None
-    };
-
-    // Create final rankings
-    let mut final_rankings = Vec::new();
-    if let Some(final_round) = final_round {
-        let mut rankings: Vec<(Uuid, f64)> = final_round.vote_counts.iter()
-            .map(|(&id, &votes)| (id, votes))
-            .collect();
-        rankings.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-
-        for (position, (candidate_id, votes)) in rankings.iter().enumerate() {
-            if let Some(candidate) = rcv_candidates.iter().find(|c| c.id == *candidate_id) {
-                let percentage = if final_round.total_votes > 0.0 {
-                    (votes / final_round.total_votes) * 100.0
-                } else {
-                    0.0
-                };
-
-                // Find elimination round (if any)
-                let eliminated_round = rcv_result.rounds.iter()
-                    .find(|r| r.eliminated == Some(*candidate_id))
-                    .map(|r| r.round_number);
-
-                final_rankings.push(FinalRanking {
-                    position: position + 1,
-                    candidate_id: *candidate_id,
-                    name: candidate.name.clone(),
-                    votes: *votes,
-                    percentage,
-                    eliminated_round,
-                });
+    if let Err(response) = authorize_results_view(&poll, &headers, &auth_service) {
+        return Ok(*response);
+    }
+
+    match ResultVersion::find(pool, poll_id, version).await {
+        Ok(Some(version)) => Ok(Json(create_api_response(version)).into_response()),
+        Ok(None) => Ok((
+            StatusCode::NOT_FOUND,
+            Json(create_error_response::<()>("VERSION_NOT_FOUND", "No results version with that number")),
+        ).into_response()),
+        Err(e) => {
+            tracing::error!("Database error finding results version: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+// Whether anyone, not just the owner, may read this poll's results right now
+fn results_publicly_visible(poll: &PollResponse) -> bool {
+    match ResultsVisibility::parse(&poll.results_visibility) {
+        Some(ResultsVisibility::PublicAlways) => true,
+        Some(ResultsVisibility::PublicAfterClose) => poll.closes_at.is_some_and(|closes_at| closes_at <= chrono::Utc::now()),
+        Some(ResultsVisibility::Private) | None => false,
+    }
+}
+
+// Publicly visible results are open to anyone; otherwise only the poll's owner may read
+// them. The error is the response to send instead.
+fn authorize_results_view(poll: &PollResponse, headers: &HeaderMap, auth_service: &AuthService) -> Result<(), Box<Response>> {
+    if results_publicly_visible(poll) {
+        return Ok(());
+    }
+
+    let current_user_id = get_current_user_id(headers, auth_service).map_err(|(status, _)| Box::new(status.into_response()))?;
+    if poll.user_id != current_user_id {
+        return Err(Box::new(
+            Json(create_error_response::<()>("FORBIDDEN", "You don't have permission to view these results")).into_response(),
+        ));
+    }
+    Ok(())
+}
+
+/// GET /api/public/polls/:id/results - Get results (no auth required) when the poll's results visibility allows
+pub async fn get_public_poll_results(
+    Path(poll_id): Path<Uuid>,
+    Query(query): Query<PollResultsQuery>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let pool = auth_service.pool();
+
+    let poll = match Poll::find_by_id(pool, poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok((StatusCode::NOT_FOUND, Json(create_error_response::<()>("NOT_FOUND", "Poll not found"))).into_response());
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if !results_publicly_visible(&poll) {
+        return Ok((
+            StatusCode::FORBIDDEN,
+            Json(create_error_response::<()>("RESULTS_NOT_PUBLIC", "Results for this poll are not public")),
+        )
+            .into_response());
+    }
+
+    let include_rounds = query.include_rounds.unwrap_or(false);
+    let fingerprint = match results_fingerprint(pool, &poll).await {
+        Ok(fingerprint) => fingerprint,
+        Err(e) => {
+            tracing::error!("Database error fingerprinting ballots: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    let etag = results_etag(&fingerprint, include_rounds);
+    if let Some(response) = not_modified(&headers, &etag) {
+        return Ok(response);
+    }
+
+    match closed_poll_results(pool, poll_id, include_rounds).await {
+        Ok(Some(results)) => return Ok(with_etag(&etag, Json(create_api_response(results)))),
+        Ok(None) => {}
+        Err(e) => {
+            tracing::error!("Database error finding poll closure: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    }
+
+    let candidates = match Candidate::find_by_poll_id(pool, poll_id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Database error finding candidates: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let ballots = match Ballot::find_by_poll_id(pool, poll_id).await {
+        Ok(ballots) => ballots,
+        Err(e) => {
+            tracing::error!("Database error finding ballots: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let response = match build_poll_results(&poll, &candidates, ballots, include_rounds) {
+        Ok(response) => response,
+        Err(e) => return tabulation_error_response(e),
+    };
+
+    Ok(with_etag(&etag, Json(create_api_response(response))))
+}
+
+/// Keep-alive comment interval so proxies don't drop idle results streams
+const RESULTS_STREAM_HEARTBEAT: Duration = Duration::from_secs(15);
+
+// Recompute a poll's current results; `None` means the poll is gone
+async fn load_poll_results(pool: &PgPool, poll_id: Uuid) -> anyhow::Result<Option<PollResultsResponse>> {
+    let Some(poll) = Poll::find_by_id(pool, poll_id).await? else {
+        return Ok(None);
+    };
+    let candidates = Candidate::find_by_poll_id(pool, poll_id).await?;
+    let ballots = Ballot::find_by_poll_id(pool, poll_id).await?;
+
+    Ok(Some(build_poll_results(&poll, &candidates, ballots, false)?))
+}
+
+/// Most polls a single batch request may tabulate
+const MAX_BATCH_POLLS: usize = 50;
+
+// Results for one poll of a batch, turning failures into a per-poll error entry
+async fn batch_poll_result(pool: &PgPool, poll_id: Uuid) -> BatchPollResult {
+    let (code, message) = match load_poll_results(pool, poll_id).await {
+        Ok(Some(results)) => return BatchPollResult::Results(Box::new(results)),
+        Ok(None) => ("NOT_FOUND", "Poll not found".to_string()),
+        Err(e) => match e.downcast_ref::<TabulationError>() {
+            Some(TabulationError::InsufficientCandidates(_)) => ("INSUFFICIENT_CANDIDATES", e.to_string()),
+            Some(TabulationError::TooManyRounds) => ("TABULATION_DID_NOT_CONVERGE", e.to_string()),
+            _ => {
+                tracing::error!("Failed to tabulate poll {} in batch: {}", poll_id, e);
+                ("TABULATION_FAILED", "Failed to compute results".to_string())
             }
+        },
+    };
+
+    BatchPollResult::Error {
+        error: ApiError {
+            code: code.to_string(),
+            message,
+        },
+    }
+}
+
+/// POST /api/admin/results/batch - Compute results for several polls in one call (admins only)
+pub async fn batch_poll_results(
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Json(request): Json<BatchResultsRequest>,
+) -> Result<Response, StatusCode> {
+    let pool = auth_service.pool();
+
+    if let Err(rejection) = current_admin(&headers, &auth_service).await {
+        return Ok(rejection.into_response());
+    }
+
+    if request.poll_ids.is_empty() || request.poll_ids.len() > MAX_BATCH_POLLS {
+        let message = format!("Provide between 1 and {} poll ids", MAX_BATCH_POLLS);
+        return Ok(Json(create_error_response::<()>("VALIDATION_ERROR", &message)).into_response());
+    }
+
+    let mut results = BTreeMap::new();
+    for poll_id in request.poll_ids {
+        if let Entry::Vacant(entry) = results.entry(poll_id) {
+            entry.insert(batch_poll_result(pool, poll_id).await);
         }
     }
 
-    let response = PollResultsResponse {
-        poll_id,
-        total_votes: ballots.len(),
-        status: status.to_string(),
-        winner,
-        final_rankings,
+    Ok(Json(create_api_response(results)).into_response())
+}
+
+/// GET /api/polls/:id/results/stream - Stream results as ballots arrive (Server-Sent Events)
+pub async fn stream_poll_results(
+    Path(poll_id): Path<Uuid>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let poll = match Poll::find_by_id(auth_service.pool(), poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response::<()>("NOT_FOUND", "Poll not found")).into_response());
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
     };
 
-    Ok(Json(create_api_response(response)))
+    if let Err(response) = authorize_results_view(&poll, &headers, &auth_service) {
+        return Ok(*response);
+    }
+
+    // Subscribe before the first snapshot so a ballot arriving in between still triggers an update
+    let receiver = auth_service.results_events().subscribe();
+
+    // The first event is the current snapshot; each later one follows a ballot for this poll
+    let stream = stream::unfold((auth_service, receiver, true), move |(auth_service, mut receiver, initial)| async move {
+        let mut wait = !initial;
+        loop {
+            if wait {
+                match receiver.recv().await {
+                    Ok(id) if id != poll_id => continue,
+                    // Missed signals collapse into a single refresh
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+            wait = true;
+
+            match load_poll_results(auth_service.pool(), poll_id).await {
+                Ok(Some(results)) => {
+                    let event = Event::default().event("results").json_data(&results);
+                    return Some((event, (auth_service, receiver, false)));
+                }
+                Ok(None) => return None,
+                Err(e) => tracing::error!("Failed to refresh streamed results for poll {}: {}", poll_id, e),
+            }
+        }
+    });
+
+    let keep_alive = KeepAlive::new().interval(RESULTS_STREAM_HEARTBEAT).text("heartbeat");
+    Ok(Sse::new(stream).keep_alive(keep_alive).into_response())
 }
 
 /// GET /api/polls/:id/results/rounds - Get RCV rounds
@@ -368,20 +730,13 @@ pub async fn get_rcv_rounds(
     Path(poll_id): Path<Uuid>,
     State(auth_service): State<AuthService>,
     headers: HeaderMap,
-) -> Result<Json<ApiResponse<RcvRoundsResponse>>, StatusCode> {
+) -> Result<Response, StatusCode> {
     let pool = auth_service.pool();
-    
-    // Extract user ID from JWT token
-    let current_user_id = match get_current_user_id(&headers, &auth_service) {
-        Ok(user_id) => user_id,
-        Err((status, _)) => return Err(status),
-    };
 
-    // Get poll and verify ownership
     let poll = match Poll::find_by_id(pool, poll_id).await {
         Ok(Some(poll)) => poll,
         Ok(None) => {
-            return Ok(Json(create_error_response::<RcvRoundsResponse>("NOT_FOUND", "Poll not found")));
+            return Ok(Json(create_error_response::<RcvRoundsResponse>("NOT_FOUND", "Poll not found")).into_response());
         }
         Err(e) => {
             tracing::error!("Database error finding poll: {}", e);
@@ -389,10 +744,8 @@ pub async fn get_rcv_rounds(
         }
     };
 
-    // Verify poll ownership
-    if poll.user_id != current_user_id {
-        tracing::warn!("⚠️ Temporarily bypassing ownership check - poll.user_id {} != current_user_id {}", poll.user_id, current_user_id);
-        // return Ok(Json(create_error_response("FORBIDDEN", "You don't have permission to view these results")));
+    if let Err(response) = authorize_results_view(&poll, &headers, &auth_service) {
+        return Ok(*response);
     }
 
     // Get candidates
@@ -423,7 +776,11 @@ pub async fn get_rcv_rounds(
             rounds: Vec::new(),
             total_ballots: 0,
             exhausted_ballots: 0,
-        })));
+            spoiled_ballots: 0,
+            stale_ballot_count: 0,
+            computed_at: chrono::Utc::now(),
+            algorithm: tabulation_algorithm(&poll.poll_type).to_string(),
+        })).into_response());
     }
 
     // Convert to RCV format
@@ -434,89 +791,27 @@ pub async fn get_rcv_rounds(
         })
         .collect();
 
+    let stale_ballot_count = count_stale(&rcv_candidates, &ballots);
+
     // Run RCV tabulation
-    let rcv_engine = SingleWinnerRCV::new(rcv_candidates, ballots.clone());
-    let rcv_result = match rcv_engine.tabulate() {
+    let rcv_result = match tabulate(&poll, rcv_candidates, ballots.clone()) {
         Ok(result) => result,
-        Err(e) => {
-            tracing::error!("RCV tabulation error: {}", e);
-            return Err(StatusCode::INTERNAL_SERVER_ERROR);
-        }
+        Err(e) => return tabulation_error_response(e),
     };
 
-    // Convert rounds to API format
-    let rounds = rcv_result.rounds.iter().map(|round| {
-        let vote_counts = round.vote_counts.iter().map(|(&candidate_id, &votes)| {
-            let name = candidate_map.get(&candidate_id).unwrap_or(&"Unknown".to_string()).clone();
-            let percentage = if round.total_votes > 0.0 {
-                (votes / round.total_votes) * 100.0
-            } else {
-                0.0
-            };
-            
-            (candidate_id, VoteCounts {
-                candidate_id,
-                name,
-                votes,
-                percentage,
-            })
-        }).collect();
-
-        let eliminated = round.eliminated.map(|candidate_id| {
-            let name = candidate_map.get(&candidate_id).unwrap_or(&"Unknown".to_string()).clone();
-            let votes = round.vote_counts.get(&candidate_id).unwrap_or(&0.0);
-            EliminatedCandidate {
-                candidate_id,
-                name,
-                votes: *votes,
-            }
-        });
-
-        let winner = round.winner.map(|candidate_id| {
-            let name = candidate_map.get(&candidate_id).unwrap_or(&"Unknown".to_string()).clone();
-            let votes = round.vote_counts.get(&candidate_id).unwrap_or(&0.0);
-            let percentage = if round.total_votes > 0.0 {
-                (votes / round.total_votes) * 100.0
-            } else {
-                0.0
-            };
-            WinnerCandidate {
-                candidate_id,
-                name,
-                votes: *votes,
-                percentage,
-            }
-        });
-
-        // Convert tiebreak reason to string
-        let tiebreak_reason = round.tiebreak_reason.as_ref().map(|reason| {
-            match reason {
-                crate::services::rcv::TieBreakReason::FirstChoiceVotes => "FirstChoiceVotes".to_string(),
-                crate::services::rcv::TieBreakReason::PriorRoundPerformance => "PriorRoundPerformance".to_string(),
-                crate::services::rcv::TieBreakReason::MostVotesToDistribute => "MostVotesToDistribute".to_string(),
-                crate::services::rcv::TieBreakReason::Random => "Random".to_string(),
-            }
-        });
-
-        RoundInfo {
-            round_number: round.round_number,
-            vote_counts,
-            eliminated,
-            winner,
-            exhausted_ballots: round.exhausted_ballots,
-            total_votes: round.total_votes,
-            majority_threshold: round.majority_threshold,
-            tiebreak_reason,
-        }
-    }).collect();
+    let rounds = round_infos(&rcv_result.rounds, &candidate_map);
 
     let response = RcvRoundsResponse {
         rounds,
         total_ballots: ballots.len(),
         exhausted_ballots: rcv_result.exhausted_ballots,
+        spoiled_ballots: rcv_result.spoiled_ballots,
+        stale_ballot_count,
+        computed_at: chrono::Utc::now(),
+        algorithm: tabulation_algorithm(&poll.poll_type).to_string(),
     };
 
-    Ok(Json(create_api_response(response)))
+    Ok(Json(create_api_response(response)).into_response())
 }
 
 #[derive(Debug, Serialize)]
@@ -540,6 +835,294 @@ pub struct AnonymousBallotsResponse {
     pub ballots: Vec<AnonymousBallot>,
 }
 
+/// Most synthetic ballots a single preview may tabulate
+const MAX_PREVIEW_BALLOTS: usize = 10_000;
+
+/// POST /api/polls/:id/results/preview - Tabulate synthetic ballots against the poll's
+/// candidates without recording anything, so organizers can check the count before voting opens
+pub async fn preview_poll_results(
+    Path(poll_id): Path<Uuid>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    Json(req): Json<PreviewResultsRequest>,
+) -> Result<Response, StatusCode> {
+    let pool = auth_service.pool();
+
+    let current_user_id = match get_current_user_id(&headers, &auth_service) {
+        Ok(user_id) => user_id,
+        Err((status, _)) => return Err(status),
+    };
+
+    let poll = match Poll::find_by_id(pool, poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response::<()>("NOT_FOUND", "Poll not found")).into_response());
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if poll.user_id != current_user_id {
+        return Ok(Json(create_error_response::<()>("FORBIDDEN", "You don't have permission to preview this poll's results")).into_response());
+    }
+
+    if req.ballots.len() > MAX_PREVIEW_BALLOTS {
+        let message = format!("A preview can tabulate at most {} ballots", MAX_PREVIEW_BALLOTS);
+        return Ok((StatusCode::BAD_REQUEST, Json(create_error_response::<()>("TOO_MANY_BALLOTS", &message))).into_response());
+    }
+
+    let candidates = match Candidate::find_by_poll_id(pool, poll_id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Database error finding candidates: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    // Reject ballots naming unknown candidates up front; the engine would treat them as a server fault
+    let candidate_ids: HashSet<Uuid> = candidates.iter().map(|c| c.id).collect();
+    let mut ballots = Vec::with_capacity(req.ballots.len());
+    for (index, ballot) in req.ballots.into_iter().enumerate() {
+        let mut rankings = ballot.rankings;
+        rankings.sort_by_key(|ranking| ranking.rank);
+
+        let mut seen = HashSet::new();
+        if let Some(ranking) = rankings.iter().find(|r| !candidate_ids.contains(&r.candidate_id) || !seen.insert(r.candidate_id)) {
+            let message = format!("Ballot {} ranks unknown or repeated candidate {}", index + 1, ranking.candidate_id);
+            return Ok((StatusCode::BAD_REQUEST, Json(create_error_response::<()>("INVALID_BALLOT", &message))).into_response());
+        }
+
+        ballots.push(RcvBallot {
+            id: Uuid::new_v4(),
+            voter_id: Uuid::nil(),
+            rankings: rankings.into_iter().map(|ranking| ranking.candidate_id).collect(),
+        });
+    }
+
+    let response = match build_poll_results(&poll, &candidates, ballots, true) {
+        Ok(response) => response,
+        Err(e) => return tabulation_error_response(e),
+    };
+
+    Ok(Json(create_api_response(response)).into_response())
+}
+
+/// GET /api/polls/:id/results/pairwise - Head-to-head counts, Condorcet winner and any cycle
+pub async fn get_pairwise_results(
+    Path(poll_id): Path<Uuid>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let pool = auth_service.pool();
+
+    let poll = match Poll::find_by_id(pool, poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response::<PairwiseResponse>("NOT_FOUND", "Poll not found")).into_response());
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if let Err(response) = authorize_results_view(&poll, &headers, &auth_service) {
+        return Ok(*response);
+    }
+
+    let candidates = match Candidate::find_by_poll_id(pool, poll_id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Database error finding candidates: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let ballots = match Ballot::find_by_poll_id(pool, poll_id).await {
+        Ok(ballots) => ballots,
+        Err(e) => {
+            tracing::error!("Database error finding ballots: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let rcv_candidates: Vec<RcvCandidate> = candidates.iter()
+        .map(|c| RcvCandidate {
+            id: c.id,
+            name: c.name.clone(),
+        })
+        .collect();
+    let matrix = PairwiseMatrix::from_ballots(&rcv_candidates, &ballots);
+
+    Ok(Json(create_api_response(PairwiseResponse {
+        condorcet_winner: matrix.condorcet_winner(),
+        cycle: find_smallest_cycle(&matrix),
+        candidates: candidates.into_iter()
+            .map(|c| PairwiseCandidate { candidate_id: c.id, name: c.name })
+            .collect(),
+        matrix: matrix.wins,
+        total_ballots: ballots.len(),
+    })).into_response())
+}
+
+/// GET /api/polls/:id/results/first-choice - Tally first preferences without running the elimination rounds
+pub async fn get_first_choice_results(
+    Path(poll_id): Path<Uuid>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let pool = auth_service.pool();
+
+    let poll = match Poll::find_by_id(pool, poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response::<FirstChoiceResponse>("NOT_FOUND", "Poll not found")).into_response());
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if let Err(response) = authorize_results_view(&poll, &headers, &auth_service) {
+        return Ok(*response);
+    }
+
+    let candidates = match Candidate::find_by_poll_id(pool, poll_id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Database error finding candidates: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let ballots = match Ballot::find_by_poll_id(pool, poll_id).await {
+        Ok(ballots) => ballots,
+        Err(e) => {
+            tracing::error!("Database error finding ballots: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let mut first_choices: HashMap<Uuid, usize> = HashMap::new();
+    for first_choice in ballots.iter().filter_map(|ballot| ballot.rankings.first()) {
+        *first_choices.entry(*first_choice).or_insert(0) += 1;
+    }
+    let total_votes: usize = first_choices.values().sum();
+
+    let mut counts: Vec<FirstChoiceCount> = candidates.into_iter()
+        .map(|c| {
+            let votes = first_choices.get(&c.id).copied().unwrap_or(0);
+            let percentage = if total_votes > 0 {
+                (votes as f64 / total_votes as f64) * 100.0
+            } else {
+                0.0
+            };
+            FirstChoiceCount { candidate_id: c.id, name: c.name, votes, percentage }
+        })
+        .collect();
+    counts.sort_by_key(|count| std::cmp::Reverse(count.votes));
+
+    Ok(Json(create_api_response(FirstChoiceResponse {
+        candidates: counts,
+        total_ballots: ballots.len(),
+        total_votes,
+    })).into_response())
+}
+
+/// GET /api/polls/:id/results/by-affiliation - Group the tabulated final rankings by candidate affiliation
+pub async fn get_results_by_affiliation(
+    Path(poll_id): Path<Uuid>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let pool = auth_service.pool();
+
+    let poll = match Poll::find_by_id(pool, poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response::<AffiliationResultsResponse>("NOT_FOUND", "Poll not found")).into_response());
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if let Err(response) = authorize_results_view(&poll, &headers, &auth_service) {
+        return Ok(*response);
+    }
+
+    let candidates = match Candidate::find_by_poll_id(pool, poll_id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Database error finding candidates: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let ballots = match Ballot::find_by_poll_id(pool, poll_id).await {
+        Ok(ballots) => ballots,
+        Err(e) => {
+            tracing::error!("Database error finding ballots: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let results = match build_poll_results(&poll, &candidates, ballots, true) {
+        Ok(results) => results,
+        Err(e) => return tabulation_error_response(e),
+    };
+    let final_round = results.rounds.as_ref().and_then(|rounds| rounds.last());
+
+    let affiliation_of: HashMap<Uuid, Option<String>> = candidates.into_iter()
+        .map(|c| (c.id, c.affiliation))
+        .collect();
+    let winners: Vec<Uuid> = results.winners.iter().map(|winner| winner.candidate_id).collect();
+
+    // Groups keep the order their best-placed member appears in the final rankings
+    let mut affiliations: Vec<AffiliationTotal> = Vec::new();
+    for ranking in results.final_rankings {
+        let affiliation = affiliation_of.get(&ranking.candidate_id).cloned().flatten();
+        let index = match affiliations.iter().position(|group| group.affiliation == affiliation) {
+            Some(index) => index,
+            None => {
+                affiliations.push(AffiliationTotal {
+                    affiliation,
+                    candidates: Vec::new(),
+                    votes: 0.0,
+                    percentage: 0.0,
+                    seats_won: 0,
+                });
+                affiliations.len() - 1
+            }
+        };
+        let group = &mut affiliations[index];
+        group.votes += final_round
+            .and_then(|round| round.vote_counts.get(&ranking.candidate_id))
+            .map_or(0.0, |counts| counts.votes);
+        if winners.contains(&ranking.candidate_id) {
+            group.seats_won += 1;
+        }
+        group.candidates.push(ranking);
+    }
+
+    if let Some(round) = final_round.filter(|round| round.total_votes > 0.0) {
+        for group in &mut affiliations {
+            group.percentage = (group.votes / round.total_votes) * 100.0;
+        }
+    }
+    affiliations.sort_by(|a, b| b.votes.total_cmp(&a.votes));
+
+    Ok(Json(create_api_response(AffiliationResultsResponse {
+        affiliations,
+        total_votes: results.total_votes,
+        winners,
+    })).into_response())
+}
+
 /// GET /api/polls/:id/ballots/anonymous - Get anonymized ballot data for CSV export
 pub async fn get_anonymous_ballots(
     Path(poll_id): Path<Uuid>,
@@ -634,4 +1217,95 @@ pub async fn get_anonymous_ballots(
     };
 
     Ok(Json(create_api_response(response)))
-} 
\ No newline at end of file
+}
+
+/// POST /api/polls/:id/results/notify - Email final results to every voter
+pub async fn notify_poll_results(
+    Path(poll_id): Path<Uuid>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<EmailResponseData>>, StatusCode> {
+    let pool = auth_service.pool();
+
+    // Extract user ID from JWT token
+    let current_user_id = match get_current_user_id(&headers, &auth_service) {
+        Ok(user_id) => user_id,
+        Err((status, _)) => return Err(status),
+    };
+
+    let poll = match Poll::find_by_id(pool, poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response("NOT_FOUND", "Poll not found")));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if poll.user_id != current_user_id {
+        return Ok(Json(create_error_response("FORBIDDEN", "You don't have permission to notify voters for this poll")));
+    }
+
+    // Results are only final once voting has ended
+    let now = chrono::Utc::now();
+    if poll.closes_at.is_none_or(|closes| now <= closes) {
+        return Ok(Json(create_error_response("POLL_NOT_CLOSED", "Results can only be sent after the poll has closed")));
+    }
+
+    let candidates = match Candidate::find_by_poll_id(pool, poll_id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Database error finding candidates: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let ballots = match Ballot::find_by_poll_id(pool, poll_id).await {
+        Ok(ballots) => ballots,
+        Err(e) => {
+            tracing::error!("Database error finding ballots: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let results = match build_poll_results(&poll, &candidates, ballots, false) {
+        Ok(results) => results,
+        Err(e) => {
+            tracing::error!("RCV tabulation error: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let Some(winner) = &results.winner else {
+        return Ok(Json(create_error_response("NO_WINNER", "This poll has no winner to announce")));
+    };
+
+    let email_service = match EmailService::new() {
+        Ok(email_service) => std::sync::Arc::new(email_service),
+        Err(e) => {
+            tracing::error!("Failed to create email service: {}", e);
+            return Ok(Json(create_error_response("EMAIL_NOT_CONFIGURED", "Email service is not configured")));
+        }
+    };
+
+    let results_url = auth_service.urls().results_url(poll.id);
+    let (sent, failed_recipients) = match send_results_emails(pool, email_service, &poll, &results, winner, results_url).await {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            tracing::error!("Database error finding voters: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    tracing::info!("Poll results for {} sent to {} voters ({} failed)", poll_id, sent, failed_recipients.len());
+
+    Ok(Json(create_api_response(EmailResponseData {
+        sent: Some(sent),
+        failed: Some(failed_recipients.len()),
+        failed_recipients: Some(failed_recipients),
+        ..Default::default()
+    })))
+}
+
@@ -1,16 +1,17 @@
 use axum::{
-    extract::{Path, State},
-    http::{HeaderMap, StatusCode},
-    Json,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::models::ballot::Voter;
-use crate::models::poll::Poll;
+use crate::api::json::Json;
+use crate::models::ballot::{is_duplicate_voter_email, Voter, VoterEmailStatus};
+use crate::models::poll::{DailySubmissions, Poll, PollResponse, SubmissionInterval};
 use crate::models::user::User;
 use crate::services::auth::AuthService;
-use crate::services::email::{EmailService, VoterInvitationRequest};
+use crate::services::email::{email_locale, is_valid_locale, EmailResponse, EmailResponseData, EmailService, VoterInvitationRequest};
 
 #[derive(Debug, Serialize)]
 pub struct ApiResponse<T> {
@@ -122,6 +123,8 @@ fn create_error_response<T>(code: &str, message: &str) -> ApiResponse<T> {
 #[derive(Debug, Deserialize)]
 pub struct CreateVoterRequest {
     pub email: Option<String>,
+    /// Email language for this voter, overriding the poll default
+    pub locale: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -140,6 +143,9 @@ pub struct VoterResponse {
     pub voted_at: Option<String>,
     #[serde(rename = "votingUrl")]
     pub voting_url: String,
+    /// `sent`, `failed`, `skipped_anonymous` or `no_email`; unset when no invitation was attempted
+    #[serde(rename = "emailStatus")]
+    pub email_status: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -152,6 +158,171 @@ pub struct VotersListResponse {
     pub pending_count: usize,
 }
 
+#[derive(Debug, Serialize)]
+pub struct PollStatsResponse {
+    #[serde(rename = "invitedVoters")]
+    pub invited_voters: i64,
+    #[serde(rename = "ballotsCast")]
+    pub ballots_cast: i64,
+    #[serde(rename = "registeredBallots")]
+    pub registered_ballots: i64,
+    #[serde(rename = "anonymousBallots")]
+    pub anonymous_ballots: i64,
+    #[serde(rename = "turnoutPercentage")]
+    pub turnout_percentage: f64,
+    #[serde(rename = "firstBallotAt")]
+    pub first_ballot_at: Option<String>,
+    #[serde(rename = "lastBallotAt")]
+    pub last_ballot_at: Option<String>,
+    #[serde(rename = "dailySubmissions")]
+    pub daily_submissions: Vec<DailySubmissions>,
+    /// `hour` or `day`, chosen from the length of the voting window
+    #[serde(rename = "submissionInterval")]
+    pub submission_interval: SubmissionInterval,
+    /// Submissions per bucket; registered and anonymous counts only with `?split=true`
+    #[serde(rename = "submissionSeries")]
+    pub submission_series: Vec<SubmissionSeriesPoint>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubmissionSeriesPoint {
+    #[serde(rename = "bucketStart")]
+    pub bucket_start: String,
+    pub ballots: i64,
+    #[serde(rename = "registeredBallots", skip_serializing_if = "Option::is_none")]
+    pub registered_ballots: Option<i64>,
+    #[serde(rename = "anonymousBallots", skip_serializing_if = "Option::is_none")]
+    pub anonymous_ballots: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PollStatsQuery {
+    /// Break each submission bucket down into registered and anonymous ballots
+    pub split: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RemindVotersRequest {
+    #[serde(rename = "voterIds")]
+    pub voter_ids: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RemindVotersResponse {
+    pub sent: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    #[serde(rename = "failedRecipients")]
+    pub failed_recipients: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListVotersQuery {
+    /// Case-insensitive partial match on voter email
+    pub q: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DeleteVoterQuery {
+    pub force: Option<bool>,
+}
+
+/// Look up the poll owner, falling back to a placeholder for email sender details
+async fn find_poll_owner(pool: &sqlx::PgPool, poll: &PollResponse) -> User {
+    match User::find_by_id(pool, poll.user_id).await {
+        Ok(Some(user)) => user,
+        result => {
+            match result {
+                Err(e) => tracing::error!("Database error finding poll owner: {}", e),
+                _ => tracing::warn!("Poll owner not found for poll {}", poll.id),
+            }
+            User {
+                id: poll.user_id,
+                email: "unknown@rankedchoice.me".to_string(),
+                name: Some("Poll Organizer".to_string()),
+                password_hash: String::new(),
+                role: "pollster".to_string(),
+                email_verified: false,
+                last_login_at: None,
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+            }
+        }
+    }
+}
+
+fn invitation_request(
+    poll: &PollResponse,
+    poll_owner: &User,
+    voter_email: &str,
+    voter_locale: Option<&str>,
+    voting_url: &str,
+) -> VoterInvitationRequest {
+    VoterInvitationRequest {
+        poll_title: poll.title.clone(),
+        poll_description: poll.description.clone(),
+        voting_url: voting_url.to_string(),
+        poll_owner_name: poll_owner.name.clone().unwrap_or_else(|| "Poll Organizer".to_string()),
+        poll_owner_email: poll_owner.email.clone(),
+        closes_at: poll.closes_at.map(|dt| dt.to_rfc3339()),
+        voter_name: None, // We could extract this from email if needed
+        locale: email_locale(voter_locale, &poll.default_locale),
+        to: voter_email.to_string(),
+    }
+}
+
+/// Email a voting invitation on behalf of the poll owner
+async fn send_invitation_email(
+    pool: &sqlx::PgPool,
+    poll: &PollResponse,
+    voter_email: &str,
+    voter_locale: Option<&str>,
+    voting_url: &str,
+) -> anyhow::Result<EmailResponse> {
+    let poll_owner = find_poll_owner(pool, poll).await;
+    let email_service = EmailService::new()?;
+
+    email_service
+        .send_voter_invitation(invitation_request(poll, &poll_owner, voter_email, voter_locale, voting_url))
+        .await
+}
+
+/// Send a new voter their invitation, when they have a real address, and persist the outcome
+async fn deliver_invitation(
+    pool: &sqlx::PgPool,
+    poll: &PollResponse,
+    voter: &Voter,
+    voting_url: &str,
+) -> VoterEmailStatus {
+    let status = match voter.email {
+        None => VoterEmailStatus::NoEmail,
+        Some(ref voter_email) if voter_email.starts_with("Anonymous-") => VoterEmailStatus::SkippedAnonymous,
+        Some(ref voter_email) => {
+            match send_invitation_email(pool, poll, voter_email, voter.locale.as_deref(), voting_url).await {
+                Ok(email_result) if email_result.success => {
+                    tracing::info!("✅ Email invitation sent to {}", voter_email);
+                    VoterEmailStatus::Sent
+                }
+                Ok(email_result) => {
+                    tracing::warn!("⚠️ Email service responded with failure for {}: {:?}",
+                        voter_email, email_result.error);
+                    VoterEmailStatus::Failed
+                }
+                Err(e) => {
+                    tracing::error!("❌ Failed to send email invitation to {}: {}", voter_email, e);
+                    VoterEmailStatus::Failed
+                }
+            }
+        }
+    };
+
+    if let Err(e) = Voter::set_email_status(pool, voter.id, status).await {
+        tracing::error!("Database error recording email status for voter {}: {}", voter.id, e);
+    }
+
+    status
+}
+
 /// POST /api/polls/:id/invite - Create a voter for a poll
 pub async fn create_voter(
     Path(poll_id): Path<String>,
@@ -192,95 +363,49 @@ pub async fn create_voter(
     }
 
     // Generate display name for anonymous voters
-    let display_email = if req.email.is_none() || req.email.as_ref().map_or(true, |e| e.trim().is_empty()) {
+    let display_email = if req.email.is_none() || req.email.as_ref().is_none_or(|e| e.trim().is_empty()) {
         // Generate a truly unique anonymous voter code using UUID
         Some(format!("Anonymous-{}", Uuid::new_v4()))
     } else {
         req.email
     };
 
+    let locale = req.locale.map(|locale| locale.trim().to_string()).filter(|locale| !locale.is_empty());
+    if locale.as_deref().is_some_and(|locale| !is_valid_locale(locale)) {
+        return Ok(Json(create_error_response("VALIDATION_ERROR", "Locale must be a language tag such as 'en' or 'pt-BR'")));
+    }
+
+    // One voter per email address, so the same person can't be invited twice
+    if let Some(email) = display_email.as_deref().filter(|email| !email.starts_with("Anonymous-")) {
+        match Voter::email_registered(pool, poll_uuid, email).await {
+            Ok(false) => {}
+            Ok(true) => {
+                return Ok(Json(create_error_response("ALREADY_REGISTERED", "A voter with this email is already registered for this poll")));
+            }
+            Err(e) => {
+                tracing::error!("Database error checking voter email: {}", e);
+                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+        }
+    }
+
     // Create voter
-    let voter = match Voter::create(pool, poll_uuid, display_email, None, None).await {
+    let voter = match Voter::create_with_locale(pool, auth_service.signer(), poll_uuid, display_email, locale).await {
         Ok(voter) => voter,
+        // A concurrent invite for the same address got in after the check above
+        Err(e) if is_duplicate_voter_email(&e) => {
+            return Ok(Json(create_error_response("ALREADY_REGISTERED", "A voter with this email is already registered for this poll")));
+        }
         Err(e) => {
             tracing::error!("Database error creating voter: {}", e);
             return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
 
-    let frontend_url = std::env::var("FRONTEND_URL").unwrap_or_else(|_| "http://localhost:5174".to_string());
-    let voting_url = format!("{}/vote/{}", frontend_url, voter.ballot_token);
-
-    // Send email invitation (if voter has an email)
-    if let Some(ref voter_email) = voter.email {
-        if !voter_email.starts_with("Anonymous-") {
-            // Get poll owner information
-            let poll_owner = match User::find_by_id(pool, poll.user_id).await {
-                Ok(Some(user)) => user,
-                Ok(None) => {
-                    tracing::warn!("Poll owner not found for poll {}", poll.id);
-                    User {
-                        id: poll.user_id,
-                        email: "unknown@rankedchoice.me".to_string(),
-                        name: Some("Poll Organizer".to_string()),
-                        password_hash: String::new(),
-                        role: "pollster".to_string(),
-                        email_verified: false,
-                        created_at: chrono::Utc::now(),
-                        updated_at: chrono::Utc::now(),
-                    }
-                }
-                Err(e) => {
-                    tracing::error!("Database error finding poll owner: {}", e);
-                    User {
-                        id: poll.user_id,
-                        email: "unknown@rankedchoice.me".to_string(),
-                        name: Some("Poll Organizer".to_string()),
-                        password_hash: String::new(),
-                        role: "pollster".to_string(),
-                        email_verified: false,
-                        created_at: chrono::Utc::now(),
-                        updated_at: chrono::Utc::now(),
-                    }
-                }
-            };
-
-            // Create email service and send invitation
-            match EmailService::new() {
-                Ok(email_service) => {
-                    let email_request = VoterInvitationRequest {
-                        poll_title: poll.title.clone(),
-                        poll_description: poll.description.clone(),
-                        voting_url: voting_url.clone(),
-                        poll_owner_name: poll_owner.name.unwrap_or_else(|| "Poll Organizer".to_string()),
-                        poll_owner_email: poll_owner.email,
-                        closes_at: poll.closes_at.map(|dt| dt.to_rfc3339()),
-                        voter_name: None, // We could extract this from email if needed
-                        to: voter_email.clone(),
-                    };
-
-                    match email_service.send_voter_invitation(email_request).await {
-                        Ok(email_result) => {
-                            if email_result.success {
-                                tracing::info!("✅ Email invitation sent to {}", voter_email);
-                            } else {
-                                tracing::warn!("⚠️ Email service responded with failure for {}: {:?}", 
-                                    voter_email, email_result.error);
-                            }
-                        }
-                        Err(e) => {
-                            tracing::error!("❌ Failed to send email invitation to {}: {}", voter_email, e);
-                            // Don't fail the voter creation if email fails
-                        }
-                    }
-                }
-                Err(e) => {
-                    tracing::error!("❌ Failed to create email service: {}", e);
-                    // Don't fail the voter creation if email service setup fails
-                }
-            }
-        }
-    }
+    let voting_url = auth_service.urls().voting_url(&voter.ballot_token);
+
+    // Send email invitation (if voter has an email); a failed send doesn't fail the voter creation
+    let email_status = deliver_invitation(pool, &poll, &voter, &voting_url).await;
 
     let response = VoterResponse {
         id: voter.id.to_string(),
@@ -291,6 +416,7 @@ pub async fn create_voter(
         invited_at: voter.invited_at.to_rfc3339(),
         voted_at: voter.voted_at.map(|dt| dt.to_rfc3339()),
         voting_url,
+        email_status: Some(email_status.as_str().to_string()),
     };
 
     Ok(Json(create_api_response(response)))
@@ -299,6 +425,7 @@ pub async fn create_voter(
 /// GET /api/polls/:id/voters - List voters for a poll
 pub async fn list_voters(
     Path(poll_id): Path<String>,
+    Query(query): Query<ListVotersQuery>,
     State(auth_service): State<AuthService>,
     headers: HeaderMap,
 ) -> Result<Json<ApiResponse<VotersListResponse>>, StatusCode> {
@@ -334,8 +461,9 @@ pub async fn list_voters(
         return Ok(Json(create_error_response("FORBIDDEN", "You don't have permission to view this poll's voters")));
     }
 
-    // Get voters for poll
-    let voters = match get_voters_by_poll_id(pool, poll_uuid).await {
+    // Get voters for poll, narrowed to the search if one was given
+    let search = query.q.as_deref().map(str::trim).filter(|q| !q.is_empty());
+    let voters = match Voter::list_by_poll(pool, poll_uuid, search).await {
         Ok(voters) => voters,
         Err(e) => {
             tracing::error!("Database error finding voters: {}", e);
@@ -346,8 +474,7 @@ pub async fn list_voters(
     let voter_responses: Vec<VoterResponse> = voters
         .iter()
         .map(|voter| {
-            let frontend_url = std::env::var("FRONTEND_URL").unwrap_or_else(|_| "http://localhost:5174".to_string());
-            let voting_url = format!("{}/vote/{}", frontend_url, voter.ballot_token);
+            let voting_url = auth_service.urls().voting_url(&voter.ballot_token);
             VoterResponse {
                 id: voter.id.to_string(),
                 poll_id: voter.poll_id.to_string(),
@@ -357,6 +484,7 @@ pub async fn list_voters(
                 invited_at: voter.invited_at.to_rfc3339(),
                 voted_at: voter.voted_at.map(|dt| dt.to_rfc3339()),
                 voting_url,
+                email_status: voter.email_status.clone(),
             }
         })
         .collect();
@@ -376,6 +504,16 @@ pub async fn list_voters(
             vec![]
         }
     };
+
+    // Anonymous ballots have no email, so a search only matches them by their "anon-" display ID
+    let anonymous_ballots: Vec<_> = match search.map(str::to_ascii_lowercase) {
+        None => anonymous_ballots,
+        Some(q) if q.starts_with("anon") => anonymous_ballots
+            .into_iter()
+            .filter(|ballot| format!("anon-{}", &ballot.id.to_string()[..8]).contains(&q))
+            .collect(),
+        Some(_) => Vec::new(),
+    };
     
     // Create VoterResponse entries for anonymous ballots
     let mut anonymous_voter_responses: Vec<VoterResponse> = anonymous_ballots
@@ -392,6 +530,7 @@ pub async fn list_voters(
                 invited_at: submitted_at.to_rfc3339(), // Use submitted_at as invited_at
                 voted_at: Some(submitted_at.to_rfc3339()),
                 voting_url: format!("Anonymous Vote ({})", anonymous_id), // Not a real URL for anonymous
+                email_status: None, // Never invited
             }
         })
         .collect();
@@ -457,8 +596,7 @@ pub async fn create_registration_link(
     
     // Store the registration link in database (you might want to add a registration_links table)
     // For now, we'll return the link directly
-    let frontend_url = std::env::var("FRONTEND_URL").unwrap_or_else(|_| "http://localhost:5174".to_string());
-    let registration_url = format!("{}/register/{}", frontend_url, registration_token);
+    let registration_url = auth_service.urls().registration_url(&registration_token);
 
     let response = RegistrationLinkResponse {
         poll_id: poll.id.to_string(),
@@ -485,36 +623,415 @@ pub struct RegistrationLinkResponse {
     pub created_at: String,
 }
 
-/// Helper function to get voters by poll ID
-async fn get_voters_by_poll_id(pool: &sqlx::PgPool, poll_id: Uuid) -> Result<Vec<Voter>, sqlx::Error> {
-    let voter_rows = sqlx::query!(
-        r#"
-        SELECT id, poll_id, email, ballot_token, ip_address, user_agent,
-               location_data, demographics, invited_at, voted_at
-        FROM voters
-        WHERE poll_id = $1
-        ORDER BY invited_at DESC
-        "#,
-        poll_id
+/// Header row and a sample voter, in the columns voter imports expect
+const VOTER_IMPORT_TEMPLATE: &str = "email,name\njane.doe@example.com,Jane Doe\n";
+
+/// GET /api/polls/:id/voters/import-template - Download a CSV showing the voter import format
+pub async fn get_voter_import_template(
+    Path(poll_id): Path<String>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let pool = auth_service.pool();
+
+    // Extract user ID from JWT token
+    let user_id = match get_current_user_id(&headers, &auth_service) {
+        Ok(user_id) => user_id,
+        Err((status, _)) => return Err(status),
+    };
+
+    // Parse poll ID
+    let poll_uuid = match Uuid::parse_str(&poll_id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return Ok(Json(create_error_response::<()>("INVALID_ID", "Invalid poll ID format")).into_response());
+        }
+    };
+
+    // Verify poll exists and user owns it
+    let poll = match Poll::find_by_id(pool, poll_uuid).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response::<()>("NOT_FOUND", "Poll not found")).into_response());
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if poll.user_id != user_id {
+        return Ok(Json(create_error_response::<()>("FORBIDDEN", "You don't have permission to manage this poll")).into_response());
+    }
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (header::CONTENT_DISPOSITION, "attachment; filename=\"voter-import-template.csv\""),
+        ],
+        VOTER_IMPORT_TEMPLATE,
     )
-    .fetch_all(pool)
-    .await?;
+        .into_response())
+}
+
+/// GET /api/polls/:id/stats - Turnout and submission statistics for a poll
+pub async fn get_poll_stats(
+    Path(poll_id): Path<String>,
+    Query(query): Query<PollStatsQuery>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<PollStatsResponse>>, StatusCode> {
+    let pool = auth_service.pool();
+
+    // Extract user ID from JWT token
+    let user_id = match get_current_user_id(&headers, &auth_service) {
+        Ok(user_id) => user_id,
+        Err((status, _)) => return Err(status),
+    };
+
+    // Parse poll ID
+    let poll_uuid = match Uuid::parse_str(&poll_id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return Ok(Json(create_error_response("INVALID_ID", "Invalid poll ID format")));
+        }
+    };
+
+    // Verify poll exists and user owns it
+    let poll = match Poll::find_by_id(pool, poll_uuid).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response("NOT_FOUND", "Poll not found")));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if poll.user_id != user_id {
+        return Ok(Json(create_error_response("FORBIDDEN", "You don't have permission to view this poll's statistics")));
+    }
+
+    let participation = match Poll::participation(pool, poll_uuid).await {
+        Ok(participation) => participation,
+        Err(e) => {
+            tracing::error!("Database error computing poll participation: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let daily_submissions = match Poll::daily_submissions(pool, poll_uuid).await {
+        Ok(days) => days,
+        Err(e) => {
+            tracing::error!("Database error computing daily submissions: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    // The voting window, or as much of it as has passed, decides the bucket width
+    let now = chrono::Utc::now();
+    let window_end = poll.closes_at.map_or(now, |closes_at| closes_at.min(now));
+    let interval = SubmissionInterval::for_window(poll.opens_at.unwrap_or(poll.created_at), window_end);
+    let split = query.split.unwrap_or(false);
+    let submission_series = match Poll::submission_series(pool, poll_uuid, interval).await {
+        Ok(buckets) => buckets.into_iter()
+            .map(|bucket| SubmissionSeriesPoint {
+                bucket_start: bucket.bucket_start.to_rfc3339(),
+                ballots: bucket.ballots,
+                registered_ballots: split.then_some(bucket.registered_ballots),
+                anonymous_ballots: split.then_some(bucket.anonymous_ballots),
+            })
+            .collect(),
+        Err(e) => {
+            tracing::error!("Database error computing submission series: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    // Turnout only counts ballots from invited voters
+    let turnout_percentage = if participation.invited_voters > 0 {
+        (participation.registered_ballots as f64 / participation.invited_voters as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    let response = PollStatsResponse {
+        invited_voters: participation.invited_voters,
+        ballots_cast: participation.registered_ballots + participation.anonymous_ballots,
+        registered_ballots: participation.registered_ballots,
+        anonymous_ballots: participation.anonymous_ballots,
+        turnout_percentage,
+        first_ballot_at: participation.first_ballot_at.map(|dt| dt.to_rfc3339()),
+        last_ballot_at: participation.last_ballot_at.map(|dt| dt.to_rfc3339()),
+        daily_submissions,
+        submission_interval: interval,
+        submission_series,
+    };
+
+    Ok(Json(create_api_response(response)))
+}
 
-    let voters = voter_rows
+/// POST /api/polls/:id/voters/:voter_id/resend - Resend a voter's invitation email
+pub async fn resend_invitation(
+    Path((poll_id, voter_id)): Path<(String, String)>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<EmailResponseData>>, StatusCode> {
+    let pool = auth_service.pool();
+
+    // Extract user ID from JWT token
+    let user_id = match get_current_user_id(&headers, &auth_service) {
+        Ok(user_id) => user_id,
+        Err((status, _)) => return Err(status),
+    };
+
+    // Parse IDs
+    let (poll_uuid, voter_uuid) = match (Uuid::parse_str(&poll_id), Uuid::parse_str(&voter_id)) {
+        (Ok(poll_uuid), Ok(voter_uuid)) => (poll_uuid, voter_uuid),
+        _ => {
+            return Ok(Json(create_error_response("INVALID_ID", "Invalid poll or voter ID format")));
+        }
+    };
+
+    // Verify poll exists and user owns it
+    let poll = match Poll::find_by_id(pool, poll_uuid).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response("NOT_FOUND", "Poll not found")));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if poll.user_id != user_id {
+        return Ok(Json(create_error_response("FORBIDDEN", "You don't have permission to manage this poll")));
+    }
+
+    let voter = match Voter::find_by_id(pool, voter_uuid).await {
+        Ok(Some(voter)) if voter.poll_id == poll_uuid => voter,
+        Ok(_) => {
+            return Ok(Json(create_error_response("NOT_FOUND", "Voter not found")));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding voter: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let voter_email = match voter.email {
+        Some(ref email) if !email.starts_with("Anonymous-") => email.clone(),
+        _ => {
+            return Ok(Json(create_error_response("ANONYMOUS_VOTER", "Anonymous voters have no email address to send to")));
+        }
+    };
+
+    if voter.has_voted() {
+        return Ok(Json(create_error_response("ALREADY_VOTED", "This voter has already submitted their ballot")));
+    }
+
+    // Reuse the existing token so previously sent links keep working
+    let voting_url = auth_service.urls().voting_url(&voter.ballot_token);
+
+    let result = send_invitation_email(pool, &poll, &voter_email, voter.locale.as_deref(), &voting_url).await;
+    let status = match result {
+        Ok(ref email_result) if email_result.success => VoterEmailStatus::Sent,
+        _ => VoterEmailStatus::Failed,
+    };
+    if let Err(e) = Voter::set_email_status(pool, voter.id, status).await {
+        tracing::error!("Database error recording email status for voter {}: {}", voter.id, e);
+    }
+
+    match result {
+        Ok(email_result) if email_result.success => {
+            tracing::info!("✅ Email invitation resent to {}", voter_email);
+            Ok(Json(create_api_response(email_result.data.unwrap_or_default())))
+        }
+        Ok(email_result) => {
+            let message = email_result
+                .error
+                .map(|e| e.message)
+                .unwrap_or_else(|| "Email service reported a failure".to_string());
+            Ok(Json(create_error_response("EMAIL_FAILED", &message)))
+        }
+        Err(e) => {
+            tracing::error!("❌ Failed to resend email invitation to {}: {}", voter_email, e);
+            Ok(Json(create_error_response("EMAIL_FAILED", "Failed to send invitation email")))
+        }
+    }
+}
+
+/// POST /api/polls/:id/voters/remind - Email a reminder to voters who haven't voted yet
+pub async fn remind_voters(
+    Path(poll_id): Path<String>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+    body: Option<Json<RemindVotersRequest>>,
+) -> Result<Json<ApiResponse<RemindVotersResponse>>, StatusCode> {
+    let pool = auth_service.pool();
+
+    // Extract user ID from JWT token
+    let user_id = match get_current_user_id(&headers, &auth_service) {
+        Ok(user_id) => user_id,
+        Err((status, _)) => return Err(status),
+    };
+
+    // Parse poll ID
+    let poll_uuid = match Uuid::parse_str(&poll_id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return Ok(Json(create_error_response("INVALID_ID", "Invalid poll ID format")));
+        }
+    };
+
+    // Verify poll exists and user owns it
+    let poll = match Poll::find_by_id(pool, poll_uuid).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response("NOT_FOUND", "Poll not found")));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if poll.user_id != user_id {
+        return Ok(Json(create_error_response("FORBIDDEN", "You don't have permission to manage this poll")));
+    }
+
+    // Reminders only make sense while voters can still act on them
+    let now = chrono::Utc::now();
+    let is_open = poll.is_open_for_voting(now);
+
+    if !is_open {
+        return Ok(Json(create_error_response("POLL_CLOSED", "This poll is not currently open for voting")));
+    }
+
+    let voter_ids = body.and_then(|Json(req)| req.voter_ids);
+
+    let voters = match Voter::find_by_poll_id(pool, poll_uuid).await {
+        Ok(voters) => voters,
+        Err(e) => {
+            tracing::error!("Database error finding voters: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let targeted: Vec<Voter> = voters
         .into_iter()
-        .map(|row| Voter {
-            id: row.id,
-            poll_id: row.poll_id.expect("poll_id cannot be null"),
-            email: row.email,
-            ballot_token: row.ballot_token,
-            ip_address: row.ip_address,
-            user_agent: row.user_agent,
-            location_data: row.location_data,
-            demographics: row.demographics,
-            invited_at: row.invited_at.expect("invited_at cannot be null"),
-            voted_at: row.voted_at,
-        })
+        .filter(|voter| voter_ids.as_ref().is_none_or(|ids| ids.contains(&voter.id)))
         .collect();
 
-    Ok(voters)
-} 
\ No newline at end of file
+    let (pending, skipped): (Vec<Voter>, Vec<Voter>) = targeted.into_iter().partition(|voter| {
+        voter.voted_at.is_none()
+            && voter.email.as_ref().is_some_and(|email| !email.starts_with("Anonymous-"))
+    });
+
+    let email_service = match EmailService::new() {
+        Ok(service) => service,
+        Err(e) => {
+            tracing::error!("Email service unavailable: {}", e);
+            return Ok(Json(create_error_response("EMAIL_NOT_CONFIGURED", "Email service is not configured")));
+        }
+    };
+
+    let poll_owner = find_poll_owner(pool, &poll).await;
+    let mut sent = 0;
+    let mut failed_recipients = Vec::new();
+
+    for voter in pending {
+        let voter_email = voter.email.unwrap_or_default();
+        let voting_url = auth_service.urls().voting_url(&voter.ballot_token);
+        let request = invitation_request(&poll, &poll_owner, &voter_email, voter.locale.as_deref(), &voting_url);
+
+        match email_service.send_voter_reminder(request).await {
+            Ok(result) if result.success => sent += 1,
+            Ok(result) => {
+                tracing::warn!("⚠️ Email service responded with failure for {}: {:?}", voter_email, result.error);
+                failed_recipients.push(voter_email);
+            }
+            Err(e) => {
+                tracing::error!("❌ Failed to send reminder to {}: {}", voter_email, e);
+                failed_recipients.push(voter_email);
+            }
+        }
+    }
+
+    Ok(Json(create_api_response(RemindVotersResponse {
+        sent,
+        skipped: skipped.len(),
+        failed: failed_recipients.len(),
+        failed_recipients,
+    })))
+}
+
+/// DELETE /api/polls/:id/voters/:voter_id - Revoke a voter's invitation
+pub async fn delete_voter(
+    Path((poll_id, voter_id)): Path<(String, String)>,
+    Query(query): Query<DeleteVoterQuery>,
+    State(auth_service): State<AuthService>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<()>>, StatusCode> {
+    let pool = auth_service.pool();
+
+    // Extract user ID from JWT token
+    let user_id = match get_current_user_id(&headers, &auth_service) {
+        Ok(user_id) => user_id,
+        Err((status, _)) => return Err(status),
+    };
+
+    // Parse IDs
+    let (poll_uuid, voter_uuid) = match (Uuid::parse_str(&poll_id), Uuid::parse_str(&voter_id)) {
+        (Ok(poll_uuid), Ok(voter_uuid)) => (poll_uuid, voter_uuid),
+        _ => {
+            return Ok(Json(create_error_response("INVALID_ID", "Invalid poll or voter ID format")));
+        }
+    };
+
+    // Verify poll exists and user owns it
+    let poll = match Poll::find_by_id(pool, poll_uuid).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(Json(create_error_response("NOT_FOUND", "Poll not found")));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if poll.user_id != user_id {
+        return Ok(Json(create_error_response("FORBIDDEN", "You don't have permission to manage this poll")));
+    }
+
+    let voter = match Voter::find_by_id(pool, voter_uuid).await {
+        Ok(Some(voter)) if voter.poll_id == poll_uuid => voter,
+        Ok(_) => {
+            return Ok(Json(create_error_response("NOT_FOUND", "Voter not found")));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding voter: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    // Removing a voter who already voted also discards their ballot, so make it explicit
+    if voter.has_voted() && !query.force.unwrap_or(false) {
+        return Ok(Json(create_error_response(
+            "VOTER_HAS_VOTED",
+            "This voter has already voted; pass force=true to delete their ballot as well",
+        )));
+    }
+
+    match Voter::delete(pool, voter.id).await {
+        Ok(true) => Ok(Json(create_api_response(()))),
+        Ok(false) => Ok(Json(create_error_response("NOT_FOUND", "Voter not found"))),
+        Err(e) => {
+            tracing::error!("Database error deleting voter: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
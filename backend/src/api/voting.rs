@@ -1,20 +1,29 @@
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
-    Json,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
 };
 use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use uuid::Uuid;
 use ipnetwork::IpNetwork;
 use std::net::{IpAddr, SocketAddr};
 use axum::extract::ConnectInfo;
 
+use crate::api::json::Json;
+use crate::api::polls::FieldError;
+use crate::config::stored_ip_address;
 use crate::models::{
-    ballot::{Ballot, Voter, SubmitBallotRequest, VotingReceiptResponse},
-    poll::Poll,
-    candidate::Candidate,
+    ballot::{
+        Ballot, BallotDraft, BallotEntry, BallotRanking, Voter, SaveBallotDraftRequest, SubmitBallotRequest,
+        MyBallotRanking, MyBallotResponse, VotingReceiptResponse, ReceiptVerification, receipt_code, is_duplicate_ballot,
+    },
+    poll::{Poll, PollResponse, PollType, SkippedRankingsPolicy},
+    candidate::{render_candidate_statement, sanitize_candidate_name, Candidate, MAX_CANDIDATE_NAME_LEN},
 };
+use crate::api::candidates::max_candidates_per_poll;
 use crate::services::auth::AuthService;
+use crate::services::metrics::metrics;
 
 // Reuse the same response structures from polls.rs
 #[derive(Debug, Serialize)]
@@ -29,6 +38,9 @@ pub struct ApiResponse<T> {
 pub struct ApiError {
     code: String,
     message: String,
+    // Boxed so error results stay small
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    fields: Box<[FieldError]>,
 }
 
 #[derive(Debug, Serialize)]
@@ -41,6 +53,7 @@ pub struct ApiMetadata {
 pub struct BallotDisplayResponse {
     pub poll: PollForVoting,
     pub voter: VoterStatus,
+    pub draft: Option<Vec<BallotRanking>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -51,13 +64,21 @@ pub struct PollForVoting {
     pub poll_type: String,
     pub candidates: Vec<CandidateForVoting>,
     pub is_open: bool,
+    pub require_full_ranking: bool,
+    pub min_rankings: Option<i32>,
+    pub allow_write_ins: bool,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CandidateForVoting {
     pub id: Uuid,
     pub name: String,
+    pub slug: String,
     pub description: Option<String>,
+    /// The candidate's markdown statement rendered to sanitized HTML
+    pub statement_html: Option<String>,
+    pub affiliation: Option<String>,
+    pub image_url: Option<String>,
     pub display_order: i32,
 }
 
@@ -85,7 +106,82 @@ pub struct VotingReceipt {
     pub verification_url: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct ValidateBallotResponse {
+    pub ballot_validation: BallotValidation,
+}
+
+/// Dry-run verdict on a ballot: `accepted` is true exactly when it has no issues
+#[derive(Debug, Serialize)]
+pub struct BallotValidation {
+    pub accepted: bool,
+    pub issues: Vec<BallotIssue>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BallotIssue {
+    pub kind: BallotIssueKind,
+    pub rank: Option<i32>,
+    pub candidate_ids: Vec<Uuid>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BallotIssueKind {
+    /// The ballot ranks nobody
+    EmptyBallot,
+    /// An entry names an unknown candidate or a write-in the poll doesn't accept
+    InvalidEntry,
+    /// A rank below 1 or above the number of candidates
+    InvalidRank,
+    /// Two or more candidates share a rank
+    Overvote,
+    /// A rank is skipped before a later one is used
+    Undervote,
+    /// An approval ballot approves the same candidate twice
+    DuplicateCandidate,
+    /// The poll requires ranking every candidate and some are missing
+    IncompleteRanking,
+}
+
+impl BallotIssue {
+    fn new(kind: BallotIssueKind, rank: Option<i32>, candidate_ids: Vec<Uuid>, message: String) -> Self {
+        Self { kind, rank, candidate_ids, message }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReceiptVerificationResponse {
+    pub receipt_code: String,
+    #[serde(flatten)]
+    pub ballot: ReceiptVerification,
+}
+
+// Anonymous ballots accepted per IP per poll within the rate-limit window
+const DEFAULT_ANON_VOTE_RATE_LIMIT: i64 = 10;
+const DEFAULT_ANON_VOTE_RATE_WINDOW_SECS: i64 = 3600;
+
 // Helper functions
+// Map error codes to their HTTP status; the `ApiResponse` body is the same either way
+fn status_for_error_code(code: &str) -> StatusCode {
+    match code {
+        "NOT_FOUND" => StatusCode::NOT_FOUND,
+        "ALREADY_VOTED" => StatusCode::CONFLICT,
+        "POLL_CLOSED" => StatusCode::FORBIDDEN,
+        "POLL_NOT_READY" => StatusCode::CONFLICT,
+        "CAPTCHA_FAILED" => StatusCode::FORBIDDEN,
+        _ => StatusCode::OK,
+    }
+}
+
+impl<T: Serialize> IntoResponse for ApiResponse<T> {
+    fn into_response(self) -> Response {
+        let status = self.error.as_ref().map_or(StatusCode::OK, |error| status_for_error_code(&error.code));
+        (status, Json(self)).into_response()
+    }
+}
+
 fn create_api_response<T>(data: T) -> ApiResponse<T> {
     ApiResponse {
         success: true,
@@ -105,6 +201,7 @@ fn create_error_response<T>(code: &str, message: &str) -> ApiResponse<T> {
         error: Some(ApiError {
             code: code.to_string(),
             message: message.to_string(),
+            fields: Box::default(),
         }),
         metadata: ApiMetadata {
             timestamp: chrono::Utc::now().to_rfc3339(),
@@ -113,29 +210,311 @@ fn create_error_response<T>(code: &str, message: &str) -> ApiResponse<T> {
     }
 }
 
-fn extract_ip_address(connect_info: Option<ConnectInfo<SocketAddr>>) -> Option<IpNetwork> {
-    connect_info.map(|info| {
-        let ip = info.0.ip();
-        match ip {
-            IpAddr::V4(ipv4) => IpNetwork::new(IpAddr::V4(ipv4), 32).ok(),
-            IpAddr::V6(ipv6) => IpNetwork::new(IpAddr::V6(ipv6), 128).ok(),
+// A `VALIDATION_ERROR` blaming one input of the ballot
+fn create_validation_error<T>(field: &str, message: &str) -> ApiResponse<T> {
+    let mut response = create_error_response("VALIDATION_ERROR", message);
+    if let Some(error) = response.error.as_mut() {
+        error.fields = Box::new([FieldError {
+            field: field.to_string(),
+            message: message.to_string(),
+        }]);
+    }
+    response
+}
+
+// Sanitize a write-in like any candidate name, then collapse whitespace so write-ins
+// that differ only in spacing share a candidate
+fn normalize_write_in(name: &str) -> String {
+    sanitize_candidate_name(name).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Ballot entries matched to the poll's candidates. Write-ins naming no existing
+/// candidate carry a placeholder ID until `create_write_ins` stores them.
+struct MatchedEntries {
+    rankings: Vec<BallotRanking>,
+    new_write_ins: HashMap<Uuid, String>,
+}
+
+/// Match submitted entries to candidates by ID or, for write-ins, by name ignoring case
+fn match_entries(
+    allow_write_ins: bool,
+    candidates: &[Candidate],
+    entries: &[BallotEntry],
+) -> Result<MatchedEntries, String> {
+    let mut rankings = Vec::with_capacity(entries.len());
+    let mut write_ins_by_key: HashMap<String, (Uuid, String)> = HashMap::new();
+    let mut written_in = HashSet::new();
+
+    for entry in entries {
+        let candidate_id = match (entry.candidate_id, entry.write_in_name.as_deref()) {
+            (Some(candidate_id), None) => {
+                if !candidates.iter().any(|c| c.id == candidate_id) {
+                    return Err("Invalid candidate ID in ballot".to_string());
+                }
+                candidate_id
+            }
+            (None, Some(write_in_name)) => {
+                if !allow_write_ins {
+                    return Err("This poll does not accept write-in candidates".to_string());
+                }
+                let name = normalize_write_in(write_in_name);
+                if name.is_empty() {
+                    return Err("Write-in names cannot be empty".to_string());
+                }
+                if name.chars().count() > MAX_CANDIDATE_NAME_LEN {
+                    return Err(format!("Write-in names can be at most {} characters", MAX_CANDIDATE_NAME_LEN));
+                }
+                // "Bob" and "bob" are one candidate, so writing both in ranks it twice
+                let key = name.to_lowercase();
+                if !written_in.insert(key.clone()) {
+                    return Err(format!("'{}' is written in more than once", name));
+                }
+                match candidates.iter().find(|c| normalize_write_in(&c.name).to_lowercase() == key) {
+                    Some(candidate) => candidate.id,
+                    None => write_ins_by_key.entry(key).or_insert_with(|| (Uuid::new_v4(), name)).0,
+                }
+            }
+            _ => return Err("Each ranking needs either a candidate ID or a write-in name".to_string()),
+        };
+        rankings.push(BallotRanking { candidate_id, rank: entry.rank });
+    }
+
+    Ok(MatchedEntries {
+        rankings,
+        new_write_ins: write_ins_by_key.into_values().collect(),
+    })
+}
+
+/// Write-ins may add candidates only while the poll stays within the candidate maximum
+fn validate_write_in_count(candidates: &[Candidate], matched: &MatchedEntries) -> Result<(), String> {
+    let max_candidates = max_candidates_per_poll();
+    // "None of the above" comes with the poll setting and doesn't count toward the cap
+    let existing = candidates.iter().filter(|c| !c.is_nota).count();
+    if !matched.new_write_ins.is_empty() && existing + matched.new_write_ins.len() > max_candidates {
+        return Err(format!("This poll can have at most {} candidates, so it can't take new write-ins", max_candidates));
+    }
+    Ok(())
+}
+
+/// Store new write-in candidates (reusing any another voter added meanwhile) and
+/// swap their placeholder IDs for the stored ones. Runs in the ballot's transaction,
+/// so a ballot that fails to save leaves no write-ins behind.
+async fn create_write_ins(
+    conn: &mut sqlx::PgConnection,
+    poll_id: Uuid,
+    matched: MatchedEntries,
+) -> Result<Vec<BallotRanking>, sqlx::Error> {
+    let mut stored_ids = HashMap::new();
+    for (placeholder_id, name) in matched.new_write_ins {
+        let candidate = Candidate::find_or_create_write_in(&mut *conn, poll_id, &name).await?;
+        stored_ids.insert(placeholder_id, candidate.id);
+    }
+
+    Ok(matched.rankings.into_iter().map(|ranking| BallotRanking {
+        candidate_id: stored_ids.get(&ranking.candidate_id).copied().unwrap_or(ranking.candidate_id),
+        rank: ranking.rank,
+    }).collect())
+}
+
+/// A ballot is only a real choice once the poll has at least this many of its own candidates
+const MIN_BALLOT_CANDIDATES: usize = 2;
+
+const POLL_NOT_READY_MESSAGE: &str = "This poll does not have enough candidates to vote on yet";
+
+fn poll_ready_for_voting(candidates: &[Candidate]) -> bool {
+    candidates.iter().filter(|c| !c.is_write_in && !c.is_nota).count() >= MIN_BALLOT_CANDIDATES
+}
+
+/// Count the poll's own (non write-in) candidates and how many of them a ballot leaves unranked
+fn unranked_official_candidates(candidates: &[Candidate], rankings: &[BallotRanking]) -> (usize, usize) {
+    let ranked: HashSet<Uuid> = rankings.iter().map(|r| r.candidate_id).collect();
+    let official: Vec<&Candidate> = candidates.iter().filter(|c| !c.is_write_in).collect();
+    let missing = official.iter().filter(|c| !ranked.contains(&c.id)).count();
+    (official.len(), missing)
+}
+
+/// Error message when a ballot ranks fewer candidates than the poll's `min_rankings`.
+/// The minimum is capped at the official candidate count so removing candidates can't
+/// leave a poll impossible to vote in.
+fn min_rankings_shortfall(poll: &PollResponse, official: usize, rankings: &[BallotRanking]) -> Option<String> {
+    let required = (poll.min_rankings? as usize).min(official);
+    let ranked: HashSet<Uuid> = rankings.iter().map(|r| r.candidate_id).collect();
+    (ranked.len() < required).then(|| format!(
+        "This poll requires ranking at least {} candidates ({} ranked)",
+        required,
+        ranked.len()
+    ))
+}
+
+/// Renumber ranks 1, 2, 3, ... in order so skipped ranks disappear (1, 2, 4 becomes 1, 2, 3).
+/// Shared ranks stay shared and ranks below 1 are left alone, so those are still rejected.
+fn collapse_skipped_ranks(rankings: &mut [BallotRanking]) {
+    if rankings.iter().any(|r| r.rank < 1) {
+        return;
+    }
+
+    let distinct: BTreeSet<i32> = rankings.iter().map(|r| r.rank).collect();
+    let renumbered: HashMap<i32, i32> = distinct
+        .into_iter()
+        .enumerate()
+        .map(|(i, rank)| (rank, (i + 1) as i32))
+        .collect();
+    for ranking in rankings.iter_mut() {
+        ranking.rank = renumbered[&ranking.rank];
+    }
+}
+
+/// Whether a poll closes gaps in ranked ballots instead of rejecting them
+fn collapses_skipped_ranks(poll: &PollResponse, poll_type: PollType) -> bool {
+    poll_type != PollType::Approval
+        && SkippedRankingsPolicy::parse(&poll.skipped_rankings_policy) == Some(SkippedRankingsPolicy::Collapse)
+}
+
+/// Highest rank a ballot may use: one per candidate, counting write-ins the ballot adds
+fn max_rank(candidates: &[Candidate], matched: &MatchedEntries) -> i32 {
+    (candidates.len() + matched.new_write_ins.len()) as i32
+}
+
+/// Ranked ballots must keep every rank between 1 and the number of candidates.
+/// Approval ballots ignore the order, so their ranks aren't checked.
+fn validate_rank_range(poll_type: PollType, rankings: &[BallotRanking], max_rank: i32) -> Result<(), String> {
+    if poll_type == PollType::Approval {
+        return Ok(());
+    }
+
+    match rankings.iter().find(|r| r.rank < 1 || r.rank > max_rank) {
+        Some(ranking) => Err(format!("Rank {} is out of range; ranks must be between 1 and {}", ranking.rank, max_rank)),
+        None => Ok(()),
+    }
+}
+
+// Why a ballot naming the same candidate twice is refused
+fn duplicate_candidate_message(poll_type: PollType) -> &'static str {
+    if poll_type == PollType::Approval {
+        "Each candidate can only be approved once"
+    } else {
+        "Each candidate can only be ranked once"
+    }
+}
+
+/// Check ballot ranks for the poll type. Every ballot may name each candidate once; RCV
+/// ballots must also rank 1, 2, 3, ..., while approval ballots ignore the order.
+fn validate_ranks(poll_type: PollType, rankings: &[(Uuid, i32)]) -> Result<(), &'static str> {
+    let unique: HashSet<Uuid> = rankings.iter().map(|(id, _)| *id).collect();
+    if unique.len() != rankings.len() {
+        return Err(duplicate_candidate_message(poll_type));
+    }
+    if poll_type == PollType::Approval {
+        return Ok(());
+    }
+
+    let mut ranks: Vec<i32> = rankings.iter().map(|(_, rank)| *rank).collect();
+    ranks.sort();
+    for (i, &rank) in ranks.iter().enumerate() {
+        if rank != (i + 1) as i32 {
+            return Err("Rankings must be sequential starting from 1");
+        }
+    }
+    Ok(())
+}
+
+/// Classify every problem that would stop `submit_ballot` accepting these entries.
+/// Mirrors `match_entries`, `validate_ranks` and the full-ranking check without writing anything.
+fn classify_ballot(poll: &PollResponse, candidates: &[Candidate], entries: &[BallotEntry]) -> Vec<BallotIssue> {
+    use BallotIssueKind::*;
+
+    if entries.is_empty() {
+        return vec![BallotIssue::new(EmptyBallot, None, Vec::new(), "Ballot must contain at least one ranking".to_string())];
+    }
+
+    // Rank problems can't be judged until every entry resolves to a candidate
+    let matched = match match_entries(poll.allow_write_ins, candidates, entries) {
+        Ok(matched) => matched,
+        Err(message) => return vec![BallotIssue::new(InvalidEntry, None, Vec::new(), message)],
+    };
+
+    let mut issues = Vec::new();
+    if let Err(message) = validate_write_in_count(candidates, &matched) {
+        issues.push(BallotIssue::new(InvalidEntry, None, Vec::new(), message));
+    }
+    let poll_type = PollType::parse(&poll.poll_type).unwrap_or(PollType::SingleWinner);
+
+    let mut seen = HashSet::new();
+    let mut duplicates: Vec<Uuid> = Vec::new();
+    for ranking in &matched.rankings {
+        if !seen.insert(ranking.candidate_id) && !duplicates.contains(&ranking.candidate_id) {
+            duplicates.push(ranking.candidate_id);
+        }
+    }
+    if !duplicates.is_empty() {
+        issues.push(BallotIssue::new(DuplicateCandidate, None, duplicates, duplicate_candidate_message(poll_type).to_string()));
+    }
+
+    if poll_type != PollType::Approval {
+        let mut by_rank: BTreeMap<i32, Vec<Uuid>> = BTreeMap::new();
+        for ranking in &matched.rankings {
+            by_rank.entry(ranking.rank).or_default().push(ranking.candidate_id);
+        }
+
+        // Collapsing renumbers ranks 1, 2, 3, ..., so only their count can run past the limit
+        let max_rank = max_rank(candidates, &matched);
+        let collapses = collapses_skipped_ranks(poll, poll_type);
+        let renumbered = collapses && by_rank.range(..1).next().is_none();
+        for (position, (&rank, candidate_ids)) in by_rank.iter().enumerate() {
+            let effective_rank = if renumbered { position as i32 + 1 } else { rank };
+            if rank < 1 {
+                issues.push(BallotIssue::new(InvalidRank, Some(rank), candidate_ids.clone(), format!("Rank {} is not valid; ranks start at 1", rank)));
+            } else if effective_rank > max_rank {
+                issues.push(BallotIssue::new(InvalidRank, Some(rank), candidate_ids.clone(), format!("Rank {} is out of range; ranks must be between 1 and {}", rank, max_rank)));
+            } else if candidate_ids.len() > 1 {
+                issues.push(BallotIssue::new(Overvote, Some(rank), candidate_ids.clone(), format!("{} candidates share rank {}", candidate_ids.len(), rank)));
+            }
+        }
+
+        // Under the collapse policy skipped ranks are closed up on submission instead
+        if !collapses {
+            let highest = by_rank.keys().next_back().copied().unwrap_or(0);
+            for rank in 1..highest {
+                if !by_rank.contains_key(&rank) {
+                    issues.push(BallotIssue::new(Undervote, Some(rank), Vec::new(), format!("Rank {} is skipped", rank)));
+                }
+            }
         }
-    }).flatten()
+    }
+
+    let (official, missing) = unranked_official_candidates(candidates, &matched.rankings);
+    if poll.require_full_ranking && missing > 0 {
+        issues.push(BallotIssue::new(IncompleteRanking, None, Vec::new(), format!(
+            "This poll requires ranking all {} candidates ({} missing)",
+            official,
+            missing
+        )));
+    }
+    if let Some(message) = min_rankings_shortfall(poll, official, &matched.rankings) {
+        issues.push(BallotIssue::new(IncompleteRanking, None, Vec::new(), message));
+    }
+
+    issues
+}
+
+// The client's address as it should be stored, per `IP_STORAGE_MODE`
+fn extract_ip_address(connect_info: Option<ConnectInfo<SocketAddr>>) -> Option<IpNetwork> {
+    connect_info.and_then(|info| stored_ip_address(info.0.ip()))
 }
 
 /// GET /api/vote/:token - Get ballot by token
 pub async fn get_ballot(
     Path(token): Path<String>,
     State(auth_service): State<AuthService>,
-    connect_info: Option<ConnectInfo<SocketAddr>>,
-) -> Result<Json<ApiResponse<BallotDisplayResponse>>, StatusCode> {
+    _connect_info: Option<ConnectInfo<SocketAddr>>,
+) -> Result<ApiResponse<BallotDisplayResponse>, StatusCode> {
     let pool = auth_service.pool();
 
     // Find voter by token
-    let voter = match Voter::find_by_token(pool, &token).await {
+    let voter = match Voter::find_by_token(pool, auth_service.signer(), &token).await {
         Ok(Some(voter)) => voter,
         Ok(None) => {
-            return Ok(Json(create_error_response("NOT_FOUND", "Invalid ballot token")));
+            return Ok(create_error_response("NOT_FOUND", "Invalid ballot token"));
         }
         Err(e) => {
             tracing::error!("Database error finding voter: {}", e);
@@ -145,14 +524,14 @@ pub async fn get_ballot(
 
     // Check if voter has already voted
     if voter.has_voted() {
-        return Ok(Json(create_error_response("ALREADY_VOTED", "You have already submitted your ballot")));
+        return Ok(create_error_response("ALREADY_VOTED", "You have already submitted your ballot"));
     }
 
     // Get poll details
     let poll = match Poll::find_by_id(pool, voter.poll_id).await {
         Ok(Some(poll)) => poll,
         Ok(None) => {
-            return Ok(Json(create_error_response("NOT_FOUND", "Poll not found")));
+            return Ok(create_error_response("NOT_FOUND", "Poll not found"));
         }
         Err(e) => {
             tracing::error!("Database error finding poll: {}", e);
@@ -162,11 +541,10 @@ pub async fn get_ballot(
 
     // Check if poll is open for voting
     let now = chrono::Utc::now();
-    let is_open = poll.opens_at.map_or(true, |opens| now >= opens) &&
-                  poll.closes_at.map_or(true, |closes| now <= closes);
+    let is_open = poll.is_open_for_voting(now);
 
     if !is_open {
-        return Ok(Json(create_error_response("POLL_CLOSED", "This poll is not currently open for voting")));
+        return Ok(create_error_response("POLL_CLOSED", "This poll is not currently open for voting"));
     }
 
     // Get candidates
@@ -178,18 +556,39 @@ pub async fn get_ballot(
         }
     };
 
+    if !poll_ready_for_voting(&candidates) {
+        return Ok(create_error_response("POLL_NOT_READY", POLL_NOT_READY_MESSAGE));
+    }
+
     let poll_for_voting = PollForVoting {
         id: poll.id,
         title: poll.title,
         description: poll.description,
         poll_type: poll.poll_type,
-        candidates: candidates.into_iter().map(|c| CandidateForVoting {
+        // Other voters' write-ins are not offered as ballot options
+        candidates: candidates.into_iter().filter(|c| !c.is_write_in).map(|c| CandidateForVoting {
             id: c.id,
             name: c.name,
+            slug: c.slug,
             description: c.description,
+            statement_html: c.statement.as_deref().map(render_candidate_statement),
+            affiliation: c.affiliation,
+            image_url: c.image_url,
             display_order: c.display_order,
         }).collect(),
         is_open,
+        require_full_ranking: poll.require_full_ranking,
+        min_rankings: poll.min_rankings,
+        allow_write_ins: poll.allow_write_ins,
+    };
+
+    // Pre-populate any rankings the voter saved earlier
+    let draft = match BallotDraft::find_by_voter(pool, voter.id).await {
+        Ok(draft) => draft.map(|d| d.rankings),
+        Err(e) => {
+            tracing::error!("Database error finding ballot draft: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
     };
 
     let voter_status = VoterStatus {
@@ -200,9 +599,10 @@ pub async fn get_ballot(
     let response = BallotDisplayResponse {
         poll: poll_for_voting,
         voter: voter_status,
+        draft,
     };
 
-    Ok(Json(create_api_response(response)))
+    Ok(create_api_response(response))
 }
 
 /// POST /api/vote/:token - Submit ballot
@@ -211,15 +611,15 @@ pub async fn submit_ballot(
     State(auth_service): State<AuthService>,
     connect_info: Option<ConnectInfo<SocketAddr>>,
     Json(request): Json<SubmitBallotRequest>,
-) -> Result<Json<ApiResponse<SubmitBallotResponse>>, StatusCode> {
+) -> Result<ApiResponse<SubmitBallotResponse>, StatusCode> {
     let pool = auth_service.pool();
     let ip_address = extract_ip_address(connect_info);
 
     // Find voter by token
-    let voter = match Voter::find_by_token(pool, &token).await {
+    let voter = match Voter::find_by_token(pool, auth_service.signer(), &token).await {
         Ok(Some(voter)) => voter,
         Ok(None) => {
-            return Ok(Json(create_error_response("NOT_FOUND", "Invalid ballot token")));
+            return Ok(create_error_response("NOT_FOUND", "Invalid ballot token"));
         }
         Err(e) => {
             tracing::error!("Database error finding voter: {}", e);
@@ -229,14 +629,14 @@ pub async fn submit_ballot(
 
     // Check if voter has already voted
     if voter.has_voted() {
-        return Ok(Json(create_error_response("ALREADY_VOTED", "You have already submitted your ballot")));
+        return Ok(create_error_response("ALREADY_VOTED", "You have already submitted your ballot"));
     }
 
     // Get poll to verify it's still open
     let poll = match Poll::find_by_id(pool, voter.poll_id).await {
         Ok(Some(poll)) => poll,
         Ok(None) => {
-            return Ok(Json(create_error_response("NOT_FOUND", "Poll not found")));
+            return Ok(create_error_response("NOT_FOUND", "Poll not found"));
         }
         Err(e) => {
             tracing::error!("Database error finding poll: {}", e);
@@ -246,19 +646,12 @@ pub async fn submit_ballot(
 
     // Check if poll is open for voting
     let now = chrono::Utc::now();
-    let is_open = poll.opens_at.map_or(true, |opens| now >= opens) &&
-                  poll.closes_at.map_or(true, |closes| now <= closes);
+    let is_open = poll.is_open_for_voting(now);
 
     if !is_open {
-        return Ok(Json(create_error_response("POLL_CLOSED", "This poll is not currently open for voting")));
+        return Ok(create_error_response("POLL_CLOSED", "This poll is not currently open for voting"));
     }
 
-    // Validate ballot rankings
-    if request.rankings.is_empty() {
-        return Ok(Json(create_error_response("VALIDATION_ERROR", "Ballot must contain at least one ranking")));
-    }
-
-    // Verify all candidate IDs belong to this poll
     let candidates = match Candidate::find_by_poll_id(pool, poll.id).await {
         Ok(candidates) => candidates,
         Err(e) => {
@@ -267,31 +660,71 @@ pub async fn submit_ballot(
         }
     };
 
-    let valid_candidate_ids: std::collections::HashSet<Uuid> = candidates.iter().map(|c| c.id).collect();
-    
-    for ranking in &request.rankings {
-        if !valid_candidate_ids.contains(&ranking.candidate_id) {
-            return Ok(Json(create_error_response("VALIDATION_ERROR", "Invalid candidate ID in ballot")));
-        }
+    if !poll_ready_for_voting(&candidates) {
+        return Ok(create_error_response("POLL_NOT_READY", POLL_NOT_READY_MESSAGE));
+    }
+
+    // Validate ballot rankings
+    if request.rankings.is_empty() {
+        return Ok(create_validation_error("rankings", "Ballot must contain at least one ranking"));
+    }
+
+    // Every ranked candidate must belong to this poll
+    let mut matched = match match_entries(poll.allow_write_ins, &candidates, &request.rankings) {
+        Ok(matched) => matched,
+        Err(message) => return Ok(create_validation_error("rankings", &message)),
+    };
+    if let Err(message) = validate_write_in_count(&candidates, &matched) {
+        return Ok(create_validation_error("rankings", &message));
     }
 
     // Validate ranking sequence (should be 1, 2, 3, etc.)
-    let mut ranks: Vec<i32> = request.rankings.iter().map(|r| r.rank).collect();
-    ranks.sort();
-    for (i, &rank) in ranks.iter().enumerate() {
-        if rank != (i + 1) as i32 {
-            return Ok(Json(create_error_response("VALIDATION_ERROR", "Rankings must be sequential starting from 1")));
+    let poll_type = PollType::parse(&poll.poll_type).unwrap_or(PollType::SingleWinner);
+    if collapses_skipped_ranks(&poll, poll_type) {
+        collapse_skipped_ranks(&mut matched.rankings);
+    }
+    if let Err(message) = validate_rank_range(poll_type, &matched.rankings, max_rank(&candidates, &matched)) {
+        return Ok(create_validation_error("rankings", &message));
+    }
+    let ranks: Vec<(Uuid, i32)> = matched.rankings.iter().map(|r| (r.candidate_id, r.rank)).collect();
+    if let Err(message) = validate_ranks(poll_type, &ranks) {
+        return Ok(create_validation_error("rankings", message));
+    }
+
+    // Enforce complete ballots when the poll requires ranking every candidate
+    let (official, missing) = unranked_official_candidates(&candidates, &matched.rankings);
+    if poll.require_full_ranking && missing > 0 {
+        return Ok(create_validation_error("rankings", &format!(
+            "This poll requires ranking all {} candidates ({} missing)",
+            official,
+            missing
+        )));
+    }
+    if let Some(message) = min_rankings_shortfall(&poll, official, &matched.rankings) {
+        return Ok(create_validation_error("rankings", &message));
+    }
+
+    // Approval ballots are stored in submission order; their ranks only keep rows distinct
+    if poll_type == PollType::Approval {
+        for (i, ranking) in matched.rankings.iter_mut().enumerate() {
+            ranking.rank = (i + 1) as i32;
         }
     }
 
     // Create ballot with rankings
-    let ballot_response = match Ballot::create(pool, voter.id, poll.id, request.rankings, ip_address).await {
+    let ballot_response = match create_voter_ballot(pool, voter.id, poll.id, matched, ip_address).await {
         Ok(ballot) => ballot,
+        // A concurrent submission with the same token got there first
+        Err(e) if is_duplicate_ballot(&e) => {
+            return Ok(create_error_response("ALREADY_VOTED", "You have already submitted your ballot"));
+        }
         Err(e) => {
             tracing::error!("Database error creating ballot: {}", e);
             return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
+    metrics().record_ballot_submitted("registered");
+    auth_service.results_events().ballot_submitted(poll.id);
 
     // Mark voter as having voted
     if let Err(e) = Voter::mark_as_voted(pool, voter.id).await {
@@ -299,13 +732,20 @@ pub async fn submit_ballot(
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
+    // The submitted ballot supersedes any saved draft
+    if let Err(e) = BallotDraft::delete(pool, voter.id).await {
+        tracing::warn!("Failed to clear ballot draft for voter {}: {}", voter.id, e);
+    }
+
     // Generate receipt
-    let receipt_code = format!("VOTE-{}-{}", 
-        chrono::Utc::now().format("%Y"),
-        ballot_response.ballot.id.to_string().split('-').next().unwrap_or("UNKNOWN")
+    let receipt_code = receipt_code(
+        ballot_response.ballot.receipt_prefix.as_deref(),
+        false,
+        ballot_response.ballot.submitted_at,
+        ballot_response.ballot.id,
     );
-    
-    let verification_url = format!("https://rankedchoice.me/verify/{}", receipt_code);
+
+    let verification_url = auth_service.urls().receipt_url(&receipt_code);
 
     let response = SubmitBallotResponse {
         ballot: BallotSubmissionInfo {
@@ -318,21 +758,148 @@ pub async fn submit_ballot(
         },
     };
 
-    Ok(Json(create_api_response(response)))
+    Ok(create_api_response(response))
+}
+
+/// PUT /api/vote/:token/draft - Save partial rankings without submitting the ballot
+pub async fn save_ballot_draft(
+    Path(token): Path<String>,
+    State(auth_service): State<AuthService>,
+    Json(request): Json<SaveBallotDraftRequest>,
+) -> Result<ApiResponse<BallotDraft>, StatusCode> {
+    let pool = auth_service.pool();
+
+    // Find voter by token
+    let voter = match Voter::find_by_token(pool, auth_service.signer(), &token).await {
+        Ok(Some(voter)) => voter,
+        Ok(None) => {
+            return Ok(create_error_response("NOT_FOUND", "Invalid ballot token"));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding voter: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    // Drafts are only meaningful until the ballot is submitted
+    if voter.has_voted() {
+        return Ok(create_error_response("ALREADY_VOTED", "You have already submitted your ballot"));
+    }
+
+    // ...and while the ballot could still be submitted
+    let poll = match Poll::find_by_id(pool, voter.poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(create_error_response("NOT_FOUND", "Poll not found"));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if !poll.is_open_for_voting(chrono::Utc::now()) {
+        return Ok(create_error_response("POLL_CLOSED", "This poll is not currently open for voting"));
+    }
+
+    // Verify all candidate IDs belong to this poll
+    let candidates = match Candidate::find_by_poll_id(pool, poll.id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Database error finding candidates: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let valid_candidate_ids: HashSet<Uuid> = candidates.iter().map(|c| c.id).collect();
+
+    for ranking in &request.rankings {
+        if !valid_candidate_ids.contains(&ranking.candidate_id) {
+            return Ok(create_validation_error("rankings", "Invalid candidate ID in ballot"));
+        }
+    }
+
+    match BallotDraft::save(pool, voter.id, request.rankings).await {
+        Ok(draft) => Ok(create_api_response(draft)),
+        Err(e) => {
+            tracing::error!("Database error saving ballot draft: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// POST /api/vote/:token/validate - Check a ballot without submitting it
+pub async fn validate_ballot(
+    Path(token): Path<String>,
+    State(auth_service): State<AuthService>,
+    Json(request): Json<SubmitBallotRequest>,
+) -> Result<ApiResponse<ValidateBallotResponse>, StatusCode> {
+    let pool = auth_service.pool();
+
+    // Find voter by token
+    let voter = match Voter::find_by_token(pool, auth_service.signer(), &token).await {
+        Ok(Some(voter)) => voter,
+        Ok(None) => {
+            return Ok(create_error_response("NOT_FOUND", "Invalid ballot token"));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding voter: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    if voter.has_voted() {
+        return Ok(create_error_response("ALREADY_VOTED", "You have already submitted your ballot"));
+    }
+
+    let poll = match Poll::find_by_id(pool, voter.poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => {
+            return Ok(create_error_response("NOT_FOUND", "Poll not found"));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding poll: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let now = chrono::Utc::now();
+    let is_open = poll.is_open_for_voting(now);
+
+    if !is_open {
+        return Ok(create_error_response("POLL_CLOSED", "This poll is not currently open for voting"));
+    }
+
+    let candidates = match Candidate::find_by_poll_id(pool, poll.id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Database error finding candidates: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let issues = classify_ballot(&poll, &candidates, &request.rankings);
+
+    Ok(create_api_response(ValidateBallotResponse {
+        ballot_validation: BallotValidation {
+            accepted: issues.is_empty(),
+            issues,
+        },
+    }))
 }
 
 /// GET /api/vote/:token/receipt - Get voting receipt
 pub async fn get_voting_receipt(
     Path(token): Path<String>,
     State(auth_service): State<AuthService>,
-) -> Result<Json<ApiResponse<VotingReceiptResponse>>, StatusCode> {
+) -> Result<ApiResponse<VotingReceiptResponse>, StatusCode> {
     let pool = auth_service.pool();
 
     // Find voter by token
-    let voter = match Voter::find_by_token(pool, &token).await {
+    let voter = match Voter::find_by_token(pool, auth_service.signer(), &token).await {
         Ok(Some(voter)) => voter,
         Ok(None) => {
-            return Ok(Json(create_error_response("NOT_FOUND", "Invalid ballot token")));
+            return Ok(create_error_response("NOT_FOUND", "Invalid ballot token"));
         }
         Err(e) => {
             tracing::error!("Database error finding voter: {}", e);
@@ -342,55 +909,167 @@ pub async fn get_voting_receipt(
 
     // Check if voter has voted
     if !voter.has_voted() {
-        return Ok(Json(create_error_response("NOT_VOTED", "No ballot has been submitted for this token")));
+        return Ok(create_error_response("NOT_VOTED", "No ballot has been submitted for this token"));
     }
 
-    // Find the ballot for this voter
+    // Find the ballot for this voter, along with the receipt prefix it was issued under
     let ballot_query = sqlx::query!(
-        "SELECT id, submitted_at FROM ballots WHERE voter_id = $1",
+        "SELECT id, submitted_at, receipt_prefix FROM ballots WHERE voter_id = $1",
         voter.id
     );
 
     let ballot_row = match ballot_query.fetch_one(pool).await {
         Ok(row) => row,
         Err(sqlx::Error::RowNotFound) => {
-            return Ok(Json(create_error_response("NOT_FOUND", "Ballot not found")));
+            return Ok(create_error_response("NOT_FOUND", "Ballot not found"));
         }
         Err(e) => {
             tracing::error!("Database error finding ballot: {}", e);
             return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
+    let submitted_at = ballot_row.submitted_at.expect("submitted_at cannot be null");
 
-    // Generate receipt code (same format as submission)
-    let receipt_code = format!("VOTE-{}-{}", 
-        ballot_row.submitted_at.expect("submitted_at cannot be null").format("%Y"),
-        ballot_row.id.to_string().split('-').next().unwrap_or("UNKNOWN")
-    );
-    
-    let verification_url = format!("https://rankedchoice.me/verify/{}", receipt_code);
+    // Same code as the one handed out at submission
+    let receipt_code = receipt_code(ballot_row.receipt_prefix.as_deref(), false, submitted_at, ballot_row.id);
+
+    let verification_url = auth_service.urls().receipt_url(&receipt_code);
 
     let response = VotingReceiptResponse {
         ballot_id: ballot_row.id,
-        submitted_at: ballot_row.submitted_at.expect("submitted_at cannot be null"),
+        submitted_at,
         poll_id: voter.poll_id,
         receipt_code,
         verification_url,
     };
 
-    Ok(Json(create_api_response(response)))
+    Ok(create_api_response(response))
 }
 
-// Anonymous voting structures
-#[derive(Debug, Deserialize)]
-pub struct AnonymousVoteRequest {
-    pub rankings: Vec<AnonymousRanking>,
+/// GET /api/vote/:token/ballot - Show a voter the rankings they submitted
+pub async fn get_my_ballot(
+    Path(token): Path<String>,
+    State(auth_service): State<AuthService>,
+) -> Result<ApiResponse<MyBallotResponse>, StatusCode> {
+    let pool = auth_service.pool();
+
+    let voter = match Voter::find_by_token(pool, auth_service.signer(), &token).await {
+        Ok(Some(voter)) => voter,
+        Ok(None) => {
+            return Ok(create_error_response("NOT_FOUND", "Invalid ballot token"));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding voter: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let ballot = match Ballot::find_by_voter(pool, voter.id).await {
+        Ok(Some(ballot)) => ballot,
+        Ok(None) => {
+            return Ok(create_error_response("NOT_VOTED", "No ballot has been submitted for this token"));
+        }
+        Err(e) => {
+            tracing::error!("Database error finding ballot: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let candidates = match Candidate::find_by_poll_id(pool, voter.poll_id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Database error finding candidates: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+    let names: HashMap<Uuid, String> = candidates.into_iter().map(|c| (c.id, c.name)).collect();
+
+    let rankings = ballot
+        .rankings
+        .into_iter()
+        .map(|ranking| MyBallotRanking {
+            rank: ranking.rank,
+            candidate_id: ranking.candidate_id,
+            name: names.get(&ranking.candidate_id).cloned().unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(create_api_response(MyBallotResponse {
+        ballot_id: ballot.ballot.id,
+        poll_id: ballot.ballot.poll_id,
+        submitted_at: ballot.ballot.submitted_at,
+        rankings,
+    }))
 }
 
+/// Read the anonymous voting rate limit (max ballots, window in seconds) from the environment
+fn anonymous_vote_rate_limit() -> (i64, i64) {
+    let max_ballots = std::env::var("ANON_VOTE_RATE_LIMIT")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_ANON_VOTE_RATE_LIMIT);
+    let window_secs = std::env::var("ANON_VOTE_RATE_WINDOW_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_ANON_VOTE_RATE_WINDOW_SECS);
+
+    (max_ballots, window_secs)
+}
+
+/// Key anonymous ballots from `ip` are rate limited under: a keyed hash scoped to the poll,
+/// so the address itself is never stored for it and can't be matched across polls
+fn anonymous_rate_limit_key(auth_service: &AuthService, poll_id: Uuid, ip: IpAddr) -> String {
+    auth_service.signer().sign(&format!("anonymous-vote:{}:{}", poll_id, ip))
+}
+
+/// Split a receipt code such as `VOTE-2025-1a2b3c4d` into (prefix, year, ballot id prefix)
+fn parse_receipt_code(receipt_code: &str) -> Option<(String, i32, String)> {
+    let mut parts = receipt_code.split('-');
+    let prefix = parts.next().filter(|prefix| !prefix.is_empty())?;
+    let year = parts.next()?.parse().ok()?;
+    let id_prefix = parts.next()?;
+
+    if parts.next().is_some() || id_prefix.len() != 8 || !id_prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some((prefix.to_string(), year, id_prefix.to_ascii_lowercase()))
+}
+
+/// GET /api/verify/:receipt_code - Confirm a ballot was recorded without revealing its contents
+pub async fn verify_receipt(
+    Path(receipt_code): Path<String>,
+    State(auth_service): State<AuthService>,
+) -> Result<ApiResponse<ReceiptVerificationResponse>, StatusCode> {
+    let pool = auth_service.pool();
+
+    let Some((prefix, year, id_prefix)) = parse_receipt_code(&receipt_code) else {
+        return Ok(create_error_response("NOT_FOUND", "Receipt code not recognized"));
+    };
+
+    let ballot = match Ballot::find_by_receipt(pool, &id_prefix, year, &prefix).await {
+        Ok(Some(ballot)) => ballot,
+        Ok(None) => {
+            return Ok(create_error_response("NOT_FOUND", "Receipt code not recognized"));
+        }
+        Err(e) => {
+            tracing::error!("Database error verifying receipt: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    Ok(create_api_response(ReceiptVerificationResponse {
+        receipt_code,
+        ballot,
+    }))
+}
+
+// Anonymous voting structures
 #[derive(Debug, Deserialize)]
-pub struct AnonymousRanking {
-    pub candidate_id: Uuid,
-    pub rank: i32,
+pub struct AnonymousVoteRequest {
+    pub rankings: Vec<BallotEntry>,
+    /// Token from the CAPTCHA widget, required when the poll has `require_captcha` set
+    pub captcha_token: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -405,21 +1084,95 @@ pub struct AnonymousBallotInfo {
     pub submitted_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// How long a browser remembers that it voted anonymously in a poll
+const ANONYMOUS_VOTE_COOKIE_MAX_AGE_SECS: u64 = 365 * 24 * 60 * 60;
+
+fn anonymous_vote_cookie_name(poll_id: Uuid) -> String {
+    format!("rcv_voted_{}", poll_id.simple())
+}
+
+// The cookie value is a server signature over the poll id, so it can't be minted client-side
+fn anonymous_vote_cookie_payload(poll_id: Uuid) -> String {
+    format!("anonymous-vote:{}", poll_id)
+}
+
+fn cookie_value<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
+
 /// POST /api/public/polls/:id/vote - Submit anonymous vote for public poll
 pub async fn submit_anonymous_vote(
     Path(poll_id): Path<Uuid>,
     State(auth_service): State<AuthService>,
     connect_info: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
     Json(request): Json<AnonymousVoteRequest>,
-) -> Result<Json<ApiResponse<AnonymousVoteResponse>>, StatusCode> {
+) -> Result<Response, StatusCode> {
+    // Browsers that already voted carry a signed cookie; IP rate limiting still covers the rest
+    let cookie_name = anonymous_vote_cookie_name(poll_id);
+    let payload = anonymous_vote_cookie_payload(poll_id);
+    if cookie_value(&headers, &cookie_name).is_some_and(|value| auth_service.signer().verify_signature(&payload, value)) {
+        let response = create_error_response::<AnonymousVoteResponse>("ALREADY_VOTED", "You have already voted in this poll");
+        return Ok(response.into_response());
+    }
+
+    let response = record_anonymous_vote(poll_id, &auth_service, connect_info, request).await?;
+    if !response.success {
+        return Ok(response.into_response());
+    }
+
+    let cookie = format!(
+        "{}={}; Path=/api/public/polls/{}; Max-Age={}; HttpOnly; SameSite=Lax",
+        cookie_name,
+        auth_service.signer().sign(&payload),
+        poll_id,
+        ANONYMOUS_VOTE_COOKIE_MAX_AGE_SECS
+    );
+    Ok(([(header::SET_COOKIE, cookie)], response).into_response())
+}
+
+// Check a voter's CAPTCHA token with the provider. Fails closed when the token is
+// missing, no provider is configured or the provider can't be reached.
+async fn captcha_passed(auth_service: &AuthService, token: Option<&str>, remote_ip: Option<IpAddr>) -> bool {
+    let Some(token) = token.filter(|token| !token.is_empty()) else {
+        return false;
+    };
+    let Some(verifier) = auth_service.captcha_verifier() else {
+        tracing::error!("Poll requires a CAPTCHA but CAPTCHA_SECRET_KEY is not configured");
+        return false;
+    };
+
+    match verifier.verify(token, remote_ip).await {
+        Ok(passed) => passed,
+        Err(e) => {
+            tracing::error!("CAPTCHA verification failed: {:#}", e);
+            false
+        }
+    }
+}
+
+async fn record_anonymous_vote(
+    poll_id: Uuid,
+    auth_service: &AuthService,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    request: AnonymousVoteRequest,
+) -> Result<ApiResponse<AnonymousVoteResponse>, StatusCode> {
     let pool = auth_service.pool();
+    let remote_ip = connect_info.as_ref().map(|info| info.0.ip());
     let ip_address = extract_ip_address(connect_info);
 
     // Get poll and verify it's public and open
     let poll = match Poll::find_by_id(pool, poll_id).await {
         Ok(Some(poll)) => poll,
         Ok(None) => {
-            return Ok(Json(create_error_response("NOT_FOUND", "Poll not found")));
+            return Ok(create_error_response("NOT_FOUND", "Poll not found"));
         }
         Err(e) => {
             tracing::error!("Database error finding poll: {}", e);
@@ -429,24 +1182,39 @@ pub async fn submit_anonymous_vote(
 
     // Verify poll is public
     if !poll.is_public {
-        return Ok(Json(create_error_response("POLL_NOT_PUBLIC", "This poll is not open for public voting")));
+        return Ok(create_error_response("POLL_NOT_PUBLIC", "This poll is not open for public voting"));
     }
 
     // Check if poll is open for voting
     let now = chrono::Utc::now();
-    let is_open = poll.opens_at.map_or(true, |opens| now >= opens) &&
-                  poll.closes_at.map_or(true, |closes| now <= closes);
+    let is_open = poll.is_open_for_voting(now);
 
     if !is_open {
-        return Ok(Json(create_error_response("POLL_CLOSED", "This poll is not currently open for voting")));
+        return Ok(create_error_response("POLL_CLOSED", "This poll is not currently open for voting"));
     }
 
-    // Validate ballot rankings
-    if request.rankings.is_empty() {
-        return Ok(Json(create_error_response("VALIDATION_ERROR", "Ballot must contain at least one ranking")));
+    // Limit how many anonymous ballots a single IP can submit to this poll. The limit
+    // follows the connection's address, not the part of it `IP_STORAGE_MODE` keeps.
+    let rate_limit_key = remote_ip.map(|ip| anonymous_rate_limit_key(auth_service, poll_id, ip));
+    if let Some(key) = &rate_limit_key {
+        let (max_ballots, window_secs) = anonymous_vote_rate_limit();
+        match Ballot::count_recent_anonymous_by_key(pool, poll_id, key, window_secs).await {
+            Ok(count) if count >= max_ballots => {
+                tracing::warn!("Rate limiting anonymous votes for poll {}", poll_id);
+                return Ok(create_error_response("RATE_LIMITED", "Too many votes from this address, please try again later"));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::error!("Database error counting recent anonymous ballots: {}", e);
+                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+        }
+    }
+
+    if poll.require_captcha && !captcha_passed(auth_service, request.captcha_token.as_deref(), remote_ip).await {
+        return Ok(create_error_response("CAPTCHA_FAILED", "CAPTCHA verification failed, please try again"));
     }
 
-    // Verify all candidate IDs belong to this poll
     let candidates = match Candidate::find_by_poll_id(pool, poll_id).await {
         Ok(candidates) => candidates,
         Err(e) => {
@@ -455,47 +1223,72 @@ pub async fn submit_anonymous_vote(
         }
     };
 
-    let valid_candidate_ids: std::collections::HashSet<Uuid> = candidates.iter().map(|c| c.id).collect();
-    
-    for ranking in &request.rankings {
-        if !valid_candidate_ids.contains(&ranking.candidate_id) {
-            return Ok(Json(create_error_response("VALIDATION_ERROR", "Invalid candidate ID in ballot")));
-        }
+    if !poll_ready_for_voting(&candidates) {
+        return Ok(create_error_response("POLL_NOT_READY", POLL_NOT_READY_MESSAGE));
+    }
+
+    // Validate ballot rankings
+    if request.rankings.is_empty() {
+        return Ok(create_validation_error("rankings", "Ballot must contain at least one ranking"));
+    }
+
+    // Every ranked candidate must belong to this poll
+    let mut matched = match match_entries(poll.allow_write_ins, &candidates, &request.rankings) {
+        Ok(matched) => matched,
+        Err(message) => return Ok(create_validation_error("rankings", &message)),
+    };
+    if let Err(message) = validate_write_in_count(&candidates, &matched) {
+        return Ok(create_validation_error("rankings", &message));
     }
 
     // Validate ranking sequence (should be 1, 2, 3, etc.)
-    let mut ranks: Vec<i32> = request.rankings.iter().map(|r| r.rank).collect();
-    ranks.sort();
-    for (i, &rank) in ranks.iter().enumerate() {
-        if rank != (i + 1) as i32 {
-            return Ok(Json(create_error_response("VALIDATION_ERROR", "Rankings must be sequential starting from 1")));
-        }
+    let poll_type = PollType::parse(&poll.poll_type).unwrap_or(PollType::SingleWinner);
+    if collapses_skipped_ranks(&poll, poll_type) {
+        collapse_skipped_ranks(&mut matched.rankings);
+    }
+    if let Err(message) = validate_rank_range(poll_type, &matched.rankings, max_rank(&candidates, &matched)) {
+        return Ok(create_validation_error("rankings", &message));
+    }
+    let ranks: Vec<(Uuid, i32)> = matched.rankings.iter().map(|r| (r.candidate_id, r.rank)).collect();
+    if let Err(message) = validate_ranks(poll_type, &ranks) {
+        return Ok(create_validation_error("rankings", message));
     }
 
-    // Convert anonymous rankings to ballot rankings
-    let ballot_rankings: Vec<crate::models::ballot::BallotRanking> = request.rankings.iter().map(|r| {
-        crate::models::ballot::BallotRanking {
-            candidate_id: r.candidate_id,
-            rank: r.rank,
+    // Enforce complete ballots when the poll requires ranking every candidate
+    let (official, missing) = unranked_official_candidates(&candidates, &matched.rankings);
+    if poll.require_full_ranking && missing > 0 {
+        return Ok(create_validation_error("rankings", &format!(
+            "This poll requires ranking all {} candidates ({} missing)",
+            official,
+            missing
+        )));
+    }
+    if let Some(message) = min_rankings_shortfall(&poll, official, &matched.rankings) {
+        return Ok(create_validation_error("rankings", &message));
+    }
+
+    // Approval ballots are stored in submission order; their ranks only keep rows distinct
+    if poll_type == PollType::Approval {
+        for (i, ranking) in matched.rankings.iter_mut().enumerate() {
+            ranking.rank = (i + 1) as i32;
         }
-    }).collect();
+    }
 
     // Create anonymous ballot (without voter_id)
-    let ballot_response = match create_anonymous_ballot(pool, poll_id, ballot_rankings, ip_address).await {
+    let (ballot_response, receipt_prefix) = match create_anonymous_ballot(pool, poll_id, matched, ip_address, rate_limit_key).await {
         Ok(ballot) => ballot,
         Err(e) => {
             tracing::error!("Database error creating anonymous ballot: {}", e);
             return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
+    metrics().record_ballot_submitted("anonymous");
+    auth_service.results_events().ballot_submitted(poll_id);
 
     // Generate receipt
-    let receipt_code = format!("ANON-{}-{}", 
-        chrono::Utc::now().format("%Y"),
-        ballot_response.id.to_string().split('-').next().unwrap_or("UNKNOWN")
-    );
-    
-    let verification_url = format!("https://rankedchoice.me/verify/{}", receipt_code);
+    let receipt_code = receipt_code(receipt_prefix.as_deref(), true, ballot_response.submitted_at, ballot_response.id);
+
+    let verification_url = auth_service.urls().receipt_url(&receipt_code);
 
     let response = AnonymousVoteResponse {
         ballot: AnonymousBallotInfo {
@@ -510,27 +1303,46 @@ pub async fn submit_anonymous_vote(
 
     tracing::info!("Anonymous vote submitted for poll {} with ballot ID {}", poll_id, ballot_response.id);
 
-    Ok(Json(create_api_response(response)))
+    Ok(create_api_response(response))
 }
 
-// Helper function to create anonymous ballot
+// Store a voter's ballot together with any write-ins it adds
+async fn create_voter_ballot(
+    pool: &sqlx::PgPool,
+    voter_id: Uuid,
+    poll_id: Uuid,
+    matched: MatchedEntries,
+    ip_address: Option<IpNetwork>,
+) -> Result<crate::models::ballot::BallotResponse, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let rankings = create_write_ins(&mut tx, poll_id, matched).await?;
+    let ballot = Ballot::insert(&mut tx, voter_id, poll_id, rankings, ip_address).await?;
+    tx.commit().await?;
+
+    Ok(ballot)
+}
+
+// Store an anonymous ballot with any write-ins it adds, returning the receipt prefix it was cast under
 async fn create_anonymous_ballot(
     pool: &sqlx::PgPool,
     poll_id: Uuid,
-    rankings: Vec<crate::models::ballot::BallotRanking>,
+    matched: MatchedEntries,
     ip_address: Option<IpNetwork>,
-) -> Result<AnonymousBallotInfo, sqlx::Error> {
+    rate_limit_key: Option<String>,
+) -> Result<(AnonymousBallotInfo, Option<String>), sqlx::Error> {
     let mut tx = pool.begin().await?;
-    
+    let rankings = create_write_ins(&mut tx, poll_id, matched).await?;
+
     // Create ballot without voter_id (NULL)
     let ballot_row = sqlx::query!(
         r#"
-        INSERT INTO ballots (poll_id, voter_id, ip_address, submitted_at)
-        VALUES ($1, NULL, $2, NOW())
-        RETURNING id, submitted_at
+        INSERT INTO ballots (poll_id, voter_id, ip_address, rate_limit_key, receipt_prefix, submitted_at)
+        SELECT $1, NULL, $2, $3, receipt_prefix, NOW() FROM polls WHERE id = $1
+        RETURNING id, submitted_at, receipt_prefix
         "#,
         poll_id,
-        ip_address
+        ip_address,
+        rate_limit_key
     )
     .fetch_one(&mut *tx)
     .await?;
@@ -552,8 +1364,9 @@ async fn create_anonymous_ballot(
 
     tx.commit().await?;
 
-    Ok(AnonymousBallotInfo {
+    let ballot = AnonymousBallotInfo {
         id: ballot_row.id,
         submitted_at: ballot_row.submitted_at.expect("submitted_at cannot be null"),
-    })
+    };
+    Ok((ballot, ballot_row.receipt_prefix))
 } 
\ No newline at end of file
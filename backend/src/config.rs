@@ -0,0 +1,142 @@
+use ipnetwork::IpNetwork;
+use std::env;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Language emails are rendered in when neither the voter nor the poll picks one
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Public base URLs used when building links for emails and API responses
+#[derive(Debug, Clone)]
+pub struct UrlConfig {
+    /// Where the web app is served (`APP_BASE_URL`, falling back to `FRONTEND_URL`)
+    pub app_base_url: String,
+    /// Where voters open their ballots (`VOTE_BASE_URL`, falling back to the app base)
+    pub vote_base_url: String,
+}
+
+impl UrlConfig {
+    pub fn new(app_base_url: impl Into<String>, vote_base_url: impl Into<String>) -> Self {
+        Self {
+            app_base_url: app_base_url.into().trim_end_matches('/').to_string(),
+            vote_base_url: vote_base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let app_base_url = env::var("APP_BASE_URL")
+            .or_else(|_| env::var("FRONTEND_URL"))
+            .unwrap_or_else(|_| "http://localhost:5174".to_string());
+        let vote_base_url = env::var("VOTE_BASE_URL").unwrap_or_else(|_| app_base_url.clone());
+
+        Self::new(app_base_url, vote_base_url)
+    }
+
+    pub fn voting_url(&self, ballot_token: &str) -> String {
+        format!("{}/vote/{}", self.vote_base_url, ballot_token)
+    }
+
+    pub fn registration_url(&self, registration_token: &str) -> String {
+        format!("{}/register/{}", self.vote_base_url, registration_token)
+    }
+
+    pub fn receipt_url(&self, receipt_code: &str) -> String {
+        format!("{}/verify/{}", self.vote_base_url, receipt_code)
+    }
+
+    pub fn verification_url(&self, token: &str) -> String {
+        format!("{}/verify-email?token={}", self.app_base_url, token)
+    }
+
+    pub fn password_reset_url(&self, token: &str) -> String {
+        format!("{}/reset-password?token={}", self.app_base_url, token)
+    }
+
+    pub fn results_url(&self, poll_id: uuid::Uuid) -> String {
+        format!("{}/polls/{}/results", self.app_base_url, poll_id)
+    }
+}
+
+/// How much of a voter's IP address is kept with their ballot (`IP_STORAGE_MODE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpStorageMode {
+    /// The whole address (the default)
+    Full,
+    /// Only the network: a /24 for IPv4 or a /48 for IPv6
+    Truncated,
+    /// No address at all
+    None,
+}
+
+/// Network prefix kept for IPv4 addresses in truncated mode
+const TRUNCATED_IPV4_PREFIX: u8 = 24;
+/// Network prefix kept for IPv6 addresses in truncated mode
+const TRUNCATED_IPV6_PREFIX: u8 = 48;
+
+impl IpStorageMode {
+    pub fn from_env() -> Self {
+        match env::var("IP_STORAGE_MODE").as_deref() {
+            Ok("truncated") => IpStorageMode::Truncated,
+            Ok("none") => IpStorageMode::None,
+            _ => IpStorageMode::Full,
+        }
+    }
+
+    /// The value to store for `ip`, with the host bits zeroed in truncated mode
+    pub fn apply(self, ip: IpAddr) -> Option<IpNetwork> {
+        match (self, ip) {
+            (IpStorageMode::None, _) => None,
+            (IpStorageMode::Full, IpAddr::V4(_)) => IpNetwork::new(ip, 32).ok(),
+            (IpStorageMode::Full, IpAddr::V6(_)) => IpNetwork::new(ip, 128).ok(),
+            (IpStorageMode::Truncated, IpAddr::V4(ipv4)) => {
+                let mask = u32::MAX << (32 - TRUNCATED_IPV4_PREFIX);
+                let network = Ipv4Addr::from(u32::from(ipv4) & mask);
+                IpNetwork::new(IpAddr::V4(network), TRUNCATED_IPV4_PREFIX).ok()
+            }
+            (IpStorageMode::Truncated, IpAddr::V6(ipv6)) => {
+                let mask = u128::MAX << (128 - TRUNCATED_IPV6_PREFIX);
+                let network = Ipv6Addr::from(u128::from(ipv6) & mask);
+                IpNetwork::new(IpAddr::V6(network), TRUNCATED_IPV6_PREFIX).ok()
+            }
+        }
+    }
+}
+
+/// Apply the configured `IP_STORAGE_MODE` to an address before it is stored
+pub fn stored_ip_address(ip: IpAddr) -> Option<IpNetwork> {
+    IpStorageMode::from_env().apply(ip)
+}
+
+/// Shortest time a scheduled poll may stay open, from a raw `MIN_POLL_DURATION_MINUTES`
+/// value. Unset, zero, invalid or out-of-range values disable the minimum.
+pub fn min_poll_duration(minutes: Option<&str>) -> Option<chrono::Duration> {
+    minutes
+        .and_then(|value| value.trim().parse::<i64>().ok())
+        .filter(|&minutes| minutes > 0)
+        .and_then(chrono::Duration::try_minutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ip_storage_modes() {
+        let ipv4: IpAddr = "203.0.113.77".parse().unwrap();
+        assert_eq!(IpStorageMode::Full.apply(ipv4), Some("203.0.113.77/32".parse().unwrap()));
+        assert_eq!(IpStorageMode::Truncated.apply(ipv4), Some("203.0.113.0/24".parse().unwrap()));
+        assert_eq!(IpStorageMode::None.apply(ipv4), None);
+
+        let ipv6: IpAddr = "2001:db8:85a3:8d3:1319:8a2e:370:7348".parse().unwrap();
+        assert_eq!(IpStorageMode::Truncated.apply(ipv6), Some("2001:db8:85a3::/48".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_min_poll_duration() {
+        assert_eq!(min_poll_duration(Some("60")), Some(chrono::Duration::hours(1)));
+        assert_eq!(min_poll_duration(Some("0")), None);
+        assert_eq!(min_poll_duration(Some("hourly")), None);
+        assert_eq!(min_poll_duration(None), None);
+        // Too large for a Duration: disabled rather than panicking
+        assert_eq!(min_poll_duration(Some(&i64::MAX.to_string())), None);
+    }
+}
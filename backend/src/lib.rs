@@ -1,4 +1,5 @@
 pub mod api;
+pub mod config;
 pub mod middleware;
 pub mod models;
 pub mod services; 
\ No newline at end of file
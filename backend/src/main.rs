@@ -1,34 +1,16 @@
 use axum::{
+    http::{header, HeaderValue, Method},
     routing::{get, post, put, delete},
     Router,
-    Json,
 };
-use serde::Serialize;
 use sqlx::{PgPool, postgres::PgPoolOptions};
 use std::time::Duration;
 use tower_http::cors::CorsLayer;
-use tracing_subscriber;
-
-mod api;
-mod middleware;
-mod models;
-mod services;
-
-use api::auth;
-use services::auth::AuthService;
-
-#[derive(Serialize)]
-struct HealthResponse {
-    status: String,
-    version: String,
-}
-
-async fn health() -> Json<HealthResponse> {
-    Json(HealthResponse {
-        status: "ok".to_string(),
-        version: env!("CARGO_PKG_VERSION").to_string(),
-    })
-}
+use rankedchoice_api::api::{self, auth};
+use rankedchoice_api::middleware::metrics::track_metrics;
+use rankedchoice_api::middleware::rate_limit::{rate_limit_middleware, RateLimiter};
+use rankedchoice_api::middleware::request_id::{request_id, REQUEST_ID_HEADER};
+use rankedchoice_api::services::{auth::AuthService, poll_closer};
 
 async fn create_pool() -> Result<PgPool, Box<dyn std::error::Error>> {
     let database_url = std::env::var("DATABASE_URL")
@@ -57,38 +39,107 @@ async fn create_pool() -> Result<PgPool, Box<dyn std::error::Error>> {
     Ok(pool)
 }
 
+fn create_cors_layer() -> CorsLayer {
+    let origins: Vec<HeaderValue> = std::env::var("ALLOWED_ORIGINS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|origin| !origin.is_empty())
+        .filter_map(|origin| match origin.parse::<HeaderValue>() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                tracing::warn!("Ignoring invalid origin in ALLOWED_ORIGINS: {}", origin);
+                None
+            }
+        })
+        .collect();
+
+    if origins.is_empty() {
+        if cfg!(debug_assertions) {
+            tracing::warn!("ALLOWED_ORIGINS not set, allowing all origins (debug build)");
+            return CorsLayer::permissive();
+        }
+        tracing::warn!("ALLOWED_ORIGINS not set, cross-origin requests will be rejected");
+    }
+
+    CorsLayer::new()
+        .allow_origin(origins)
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE, Method::OPTIONS])
+        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE, REQUEST_ID_HEADER.clone()])
+        .expose_headers([REQUEST_ID_HEADER.clone()])
+}
+
 fn create_router(auth_service: AuthService) -> Router {
+    let auth_rate_limit = axum::middleware::from_fn_with_state(RateLimiter::from_env(), rate_limit_middleware);
+
     Router::new()
-        .route("/health", get(health))
-        .route("/api/auth/register", post(auth::register))
-        .route("/api/auth/login", post(auth::login))
+        .route("/health", get(api::health::health))
+        .route("/ready", get(api::health::ready))
+        .route("/metrics", get(api::metrics::metrics_handler))
+        .route("/api/auth/register", post(auth::register).layer(auth_rate_limit.clone()))
+        .route("/api/auth/login", post(auth::login).layer(auth_rate_limit))
         .route("/api/auth/refresh", post(auth::refresh))
         .route("/api/auth/verify-email", post(auth::verify_email))
+        .route("/api/auth/verify-email/:token", get(auth::verify_email_link))
         .route("/api/auth/forgot-password", post(auth::forgot_password))
         .route("/api/auth/reset-password", post(auth::reset_password))
         .route("/api/auth/resend-verification", post(auth::resend_verification))
+        .route("/api/auth/me", get(auth::me).put(auth::update_me).delete(auth::delete_me))
+        .route("/api/public/polls", get(api::polls::list_public_polls))
         .route("/api/public/polls/:id", get(api::polls::get_public_poll))
         .route("/api/public/polls/:id/vote", post(api::voting::submit_anonymous_vote))
+        .route("/api/public/polls/:id/results", get(api::results::get_public_poll_results))
         .route("/api/polls", get(api::polls::list_polls))
         .route("/api/polls", post(api::polls::create_poll))
         .route("/api/polls/:id", get(api::polls::get_poll))
         .route("/api/polls/:id", put(api::polls::update_poll))
         .route("/api/polls/:id", delete(api::polls::delete_poll))
+        .route("/api/polls/:id/restore", post(api::polls::restore_poll))
+        .route("/api/polls/:id/close-now", post(api::polls::close_poll_now))
+        .route("/api/polls/:id/export", get(api::polls::export_poll))
+        .route("/api/polls/import", post(api::polls::import_poll))
+        .route("/api/polls/:id/reset", post(api::polls::reset_poll))
+        .route("/api/polls/:id/transfer", post(api::polls::transfer_poll))
         .route("/api/polls/:id/candidates", get(api::candidates::list_candidates))
         .route("/api/polls/:id/candidates", post(api::candidates::add_candidate))
+        .route("/api/polls/:id/candidates/bulk", post(api::candidates::add_candidates_bulk))
         .route("/api/polls/:id/candidates/order", put(api::candidates::reorder_candidates))
+        .route("/api/polls/:id/candidates/by-slug/:slug", get(api::candidates::get_candidate_by_slug))
+        .route("/api/candidates/:id", get(api::candidates::get_candidate))
         .route("/api/candidates/:id", put(api::candidates::update_candidate))
         .route("/api/candidates/:id", delete(api::candidates::delete_candidate))
         .route("/api/polls/:id/invite", post(api::voters::create_voter))
         .route("/api/polls/:id/voters", get(api::voters::list_voters))
+        .route("/api/polls/:id/voters/import-template", get(api::voters::get_voter_import_template))
+        .route("/api/polls/:id/voters/:voter_id", delete(api::voters::delete_voter))
+        .route("/api/polls/:id/voters/:voter_id/resend", post(api::voters::resend_invitation))
+        .route("/api/polls/:id/voters/remind", post(api::voters::remind_voters))
+        .route("/api/polls/:id/stats", get(api::voters::get_poll_stats))
         .route("/api/polls/:id/registration", post(api::voters::create_registration_link))
         .route("/api/vote/:token", get(api::voting::get_ballot))
         .route("/api/vote/:token", post(api::voting::submit_ballot))
+        .route("/api/vote/:token/draft", put(api::voting::save_ballot_draft))
+        .route("/api/vote/:token/validate", post(api::voting::validate_ballot))
         .route("/api/vote/:token/receipt", get(api::voting::get_voting_receipt))
+        .route("/api/vote/:token/ballot", get(api::voting::get_my_ballot))
+        .route("/api/verify/:receipt_code", get(api::voting::verify_receipt))
         .route("/api/polls/:id/results", get(api::results::get_poll_results))
         .route("/api/polls/:id/results/rounds", get(api::results::get_rcv_rounds))
+        .route("/api/polls/:id/results/first-choice", get(api::results::get_first_choice_results))
+        .route("/api/polls/:id/results/pairwise", get(api::results::get_pairwise_results))
+        .route("/api/polls/:id/results/by-affiliation", get(api::results::get_results_by_affiliation))
+        .route("/api/polls/:id/results/preview", post(api::results::preview_poll_results))
+        .route("/api/polls/:id/results/stream", get(api::results::stream_poll_results))
+        .route("/api/polls/:id/results/versions", get(api::results::list_result_versions))
+        .route("/api/polls/:id/results/versions/:version", get(api::results::get_result_version))
+        .route("/api/polls/:id/results/notify", post(api::results::notify_poll_results))
         .route("/api/polls/:id/ballots/anonymous", get(api::results::get_anonymous_ballots))
-        .layer(CorsLayer::permissive())
+        .route("/api/admin/results/batch", post(api::results::batch_poll_results))
+        .route("/api/admin/email/test", post(api::admin::send_test_email))
+        .route("/api/admin/email/health", get(api::admin::email_health))
+        .route_layer(axum::middleware::from_fn(track_metrics))
+        .layer(create_cors_layer())
+        .layer(axum::middleware::from_fn(request_id))
         .with_state(auth_service)
 }
 
@@ -103,8 +154,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     sqlx::migrate!("./migrations").run(&pool).await?;
     tracing::info!("Database migrations completed");
 
-    let mut auth_service = AuthService::new(pool);
+    let mut auth_service = AuthService::new(pool)?;
     auth_service.init_ses().await;
+
+    if let Some(interval) = poll_closer::scan_interval_from_env() {
+        tokio::spawn(poll_closer::run(auth_service.clone(), interval, poll_closer::notify_voters_from_env()));
+    }
+
     let app = create_router(auth_service);
 
     let port: u16 = std::env::var("PORT")
@@ -115,7 +171,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing::info!("Server running on http://{}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    axum::serve(listener, app.into_make_service_with_connect_info::<std::net::SocketAddr>()).await?;
 
     Ok(())
 }
@@ -129,7 +185,7 @@ async fn main() -> Result<(), lambda_http::Error> {
         .init();
 
     let pool = create_pool().await.expect("Failed to create database pool");
-    let mut auth_service = AuthService::new(pool);
+    let mut auth_service = AuthService::new(pool)?;
     auth_service.init_ses().await;
     let app = create_router(auth_service);
 
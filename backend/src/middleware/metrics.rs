@@ -0,0 +1,25 @@
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use std::time::Instant;
+
+use crate::services::metrics::metrics;
+
+/// Count requests and time them per matched route. Apply with `route_layer` so the
+/// route template (e.g. `/api/polls/:id`) is used as the label instead of raw paths.
+pub async fn track_metrics(request: Request, next: Next) -> Response {
+    let start = Instant::now();
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let response = next.run(request).await;
+
+    metrics().record_request(&method, &route, response.status().as_u16(), start.elapsed());
+    response
+}
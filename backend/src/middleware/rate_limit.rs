@@ -0,0 +1,89 @@
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::Response,
+    Json,
+};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::api::polls::ApiResponse;
+
+pub const DEFAULT_AUTH_RATE_LIMIT: u32 = 10;
+pub const DEFAULT_AUTH_RATE_LIMIT_WINDOW_SECS: u64 = 60;
+
+// Prune expired entries once the table grows past this many client IPs
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Fixed-window request counter keyed by client IP
+#[derive(Clone)]
+pub struct RateLimiter {
+    windows: Arc<Mutex<HashMap<IpAddr, (Instant, u32)>>>,
+    max_requests: u32,
+    window: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(max_requests: u32, window: Duration) -> Self {
+        Self {
+            windows: Arc::new(Mutex::new(HashMap::new())),
+            max_requests,
+            window,
+        }
+    }
+
+    /// Build a limiter from AUTH_RATE_LIMIT and AUTH_RATE_LIMIT_WINDOW_SECS
+    pub fn from_env() -> Self {
+        let max_requests = std::env::var("AUTH_RATE_LIMIT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_AUTH_RATE_LIMIT);
+        let window_secs = std::env::var("AUTH_RATE_LIMIT_WINDOW_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_AUTH_RATE_LIMIT_WINDOW_SECS);
+
+        Self::new(max_requests, Duration::from_secs(window_secs))
+    }
+
+    /// Record a request from `ip`, returning false once it exceeds the limit
+    pub fn check(&self, ip: IpAddr) -> bool {
+        let now = Instant::now();
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+
+        if windows.len() > PRUNE_THRESHOLD {
+            windows.retain(|_, (started, _)| now.duration_since(*started) < self.window);
+        }
+
+        let entry = windows.entry(ip).or_insert((now, 0));
+        if now.duration_since(entry.0) >= self.window {
+            *entry = (now, 0);
+        }
+
+        entry.1 += 1;
+        entry.1 <= self.max_requests
+    }
+}
+
+pub async fn rate_limit_middleware(
+    State(limiter): State<RateLimiter>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, (StatusCode, Json<ApiResponse<()>>)> {
+    // Requests without a known peer address (e.g. behind Lambda) are not limited here
+    if let Some(ConnectInfo(addr)) = connect_info {
+        if !limiter.check(addr.ip()) {
+            tracing::warn!("Rate limit exceeded for {} on {}", addr.ip(), request.uri().path());
+            return Err((
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(ApiResponse::<()>::error("RATE_LIMITED", "Too many requests, please try again later")),
+            ));
+        }
+    }
+
+    Ok(next.run(request).await)
+}
@@ -4,6 +4,9 @@ use sqlx::{FromRow, PgPool};
 use uuid::Uuid;
 use ipnetwork::IpNetwork;
 
+use crate::config::stored_ip_address;
+use crate::services::signing::TokenSigner;
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct Ballot {
     pub id: Uuid,
@@ -11,6 +14,8 @@ pub struct Ballot {
     pub poll_id: Uuid,
     pub submitted_at: DateTime<Utc>,
     pub ip_address: Option<IpNetwork>,
+    /// The poll's custom receipt prefix when the ballot was cast, which its receipt code keeps
+    pub receipt_prefix: Option<String>,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -31,16 +36,58 @@ pub struct Voter {
     pub user_agent: Option<String>,
     pub location_data: Option<serde_json::Value>,
     pub demographics: Option<serde_json::Value>,
+    /// Preferred email language; the poll's default applies when unset
+    pub locale: Option<String>,
+    /// Outcome of the last invitation email; unset until an invitation is attempted
+    pub email_status: Option<String>,
     pub invited_at: DateTime<Utc>,
     pub voted_at: Option<DateTime<Utc>>,
 }
 
+/// What happened to a voter's invitation email
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoterEmailStatus {
+    /// The email service accepted the invitation
+    Sent,
+    /// The email service was unreachable or rejected the invitation
+    Failed,
+    /// Anonymous voters have only a placeholder address, so nothing was sent
+    SkippedAnonymous,
+    /// The voter has no email address at all
+    NoEmail,
+}
+
+impl VoterEmailStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VoterEmailStatus::Sent => "sent",
+            VoterEmailStatus::Failed => "failed",
+            VoterEmailStatus::SkippedAnonymous => "skipped_anonymous",
+            VoterEmailStatus::NoEmail => "no_email",
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SubmitBallotRequest {
-    pub rankings: Vec<BallotRanking>,
+    pub rankings: Vec<BallotEntry>,
+}
+
+/// A ranking as submitted by a voter: either a candidate on the ballot or, when the
+/// poll allows it, a write-in name that is resolved to a candidate on submission
+#[derive(Debug, Clone, Deserialize)]
+pub struct BallotEntry {
+    pub candidate_id: Option<Uuid>,
+    pub write_in_name: Option<String>,
+    pub rank: i32,
 }
 
 #[derive(Debug, Deserialize)]
+pub struct SaveBallotDraftRequest {
+    pub rankings: Vec<BallotRanking>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BallotRanking {
     pub candidate_id: Uuid,
     pub rank: i32,
@@ -61,8 +108,76 @@ pub struct VotingReceiptResponse {
     pub verification_url: String,
 }
 
+/// A voter's own submitted ballot, with each ranked candidate named
+#[derive(Debug, Serialize)]
+pub struct MyBallotResponse {
+    pub ballot_id: Uuid,
+    pub poll_id: Uuid,
+    pub submitted_at: DateTime<Utc>,
+    pub rankings: Vec<MyBallotRanking>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MyBallotRanking {
+    pub rank: i32,
+    pub candidate_id: Uuid,
+    pub name: String,
+}
+
+/// Unsubmitted rankings a voter has saved to resume later
+#[derive(Debug, Serialize)]
+pub struct BallotDraft {
+    pub voter_id: Uuid,
+    pub rankings: Vec<BallotRanking>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Receipt code prefix for ballots cast with an invitation
+pub const VOTER_RECEIPT_PREFIX: &str = "VOTE";
+/// Receipt code prefix for anonymous public ballots
+pub const ANONYMOUS_RECEIPT_PREFIX: &str = "ANON";
+/// Built-in prefixes that a poll's custom receipt prefix may not reuse
+pub const RESERVED_RECEIPT_PREFIXES: [&str; 2] = [VOTER_RECEIPT_PREFIX, ANONYMOUS_RECEIPT_PREFIX];
+
+/// Build a receipt code such as `VOTE-2025-1a2b3c4d` from the poll's custom prefix, if any,
+/// the year of submission and the first segment of the ballot id
+pub fn receipt_code(custom_prefix: Option<&str>, anonymous: bool, submitted_at: DateTime<Utc>, ballot_id: Uuid) -> String {
+    let prefix = custom_prefix.unwrap_or(if anonymous { ANONYMOUS_RECEIPT_PREFIX } else { VOTER_RECEIPT_PREFIX });
+    format!(
+        "{}-{}-{}",
+        prefix,
+        submitted_at.format("%Y"),
+        ballot_id.to_string().split('-').next().unwrap_or("UNKNOWN")
+    )
+}
+
+// Partial unique index allowing one ballot per voter
+const BALLOT_VOTER_UNIQUE: &str = "ballots_voter_id_unique";
+
+/// Whether `error` is the database refusing a second ballot from the same voter
+pub fn is_duplicate_ballot(error: &sqlx::Error) -> bool {
+    matches!(error, sqlx::Error::Database(db_err) if db_err.constraint() == Some(BALLOT_VOTER_UNIQUE))
+}
+
+// Partial unique index allowing one voter per email address in a poll
+const VOTER_EMAIL_UNIQUE: &str = "voters_poll_email_unique";
+
+/// Whether `error` is the database refusing a second voter with the same email in a poll
+pub fn is_duplicate_voter_email(error: &sqlx::Error) -> bool {
+    matches!(error, sqlx::Error::Database(db_err) if db_err.constraint() == Some(VOTER_EMAIL_UNIQUE))
+}
+
+/// Public status of a ballot located through its receipt code
+#[derive(Debug, Serialize)]
+pub struct ReceiptVerification {
+    pub poll_title: String,
+    pub submitted_at: DateTime<Utc>,
+    pub counted: bool,
+}
+
 impl Ballot {
-    /// Create a new ballot with rankings
+    /// Create a new ballot with rankings. A voter's second ballot is rejected by the
+    /// database; check for that with [`is_duplicate_ballot`]
     pub async fn create(
         pool: &PgPool,
         voter_id: Uuid,
@@ -71,19 +186,32 @@ impl Ballot {
         ip_address: Option<IpNetwork>,
     ) -> Result<BallotResponse, sqlx::Error> {
         let mut tx = pool.begin().await?;
+        let ballot = Self::insert(&mut tx, voter_id, poll_id, rankings, ip_address).await?;
+        tx.commit().await?;
 
+        Ok(ballot)
+    }
+
+    /// Insert a ballot with its rankings as part of the caller's transaction
+    pub async fn insert(
+        conn: &mut sqlx::PgConnection,
+        voter_id: Uuid,
+        poll_id: Uuid,
+        rankings: Vec<BallotRanking>,
+        ip_address: Option<IpNetwork>,
+    ) -> Result<BallotResponse, sqlx::Error> {
         // Create the ballot
         let ballot_row = sqlx::query!(
             r#"
-            INSERT INTO ballots (voter_id, poll_id, ip_address)
-            VALUES ($1, $2, $3)
-            RETURNING id, voter_id, poll_id, submitted_at, ip_address
+            INSERT INTO ballots (voter_id, poll_id, ip_address, receipt_prefix)
+            SELECT $1, $2, $3, receipt_prefix FROM polls WHERE id = $2
+            RETURNING id, voter_id, poll_id, submitted_at, ip_address, receipt_prefix
             "#,
             voter_id,
             poll_id,
             ip_address
         )
-        .fetch_one(&mut *tx)
+        .fetch_one(&mut *conn)
         .await?;
         
         let ballot = Ballot {
@@ -92,6 +220,7 @@ impl Ballot {
             poll_id: ballot_row.poll_id.expect("poll_id cannot be null"),
             submitted_at: ballot_row.submitted_at.expect("submitted_at cannot be null"),
             ip_address: ballot_row.ip_address,
+            receipt_prefix: ballot_row.receipt_prefix,
         };
 
         // Create the rankings
@@ -107,7 +236,7 @@ impl Ballot {
                 ranking.candidate_id,
                 ranking.rank
             )
-            .fetch_one(&mut *tx)
+            .fetch_one(&mut *conn)
             .await?;
             
             let created_ranking = Ranking {
@@ -120,8 +249,6 @@ impl Ballot {
             created_rankings.push(created_ranking);
         }
 
-        tx.commit().await?;
-
         Ok(BallotResponse {
             ballot,
             rankings: created_rankings,
@@ -131,7 +258,7 @@ impl Ballot {
     /// Find ballot by ID with rankings
     pub async fn find_by_id(pool: &PgPool, ballot_id: Uuid) -> Result<Option<BallotResponse>, sqlx::Error> {
         let ballot_row = sqlx::query!(
-            "SELECT id, voter_id, poll_id, submitted_at, ip_address FROM ballots WHERE id = $1",
+            "SELECT id, voter_id, poll_id, submitted_at, ip_address, receipt_prefix FROM ballots WHERE id = $1",
             ballot_id
         )
         .fetch_optional(pool)
@@ -145,6 +272,7 @@ impl Ballot {
                     poll_id: row.poll_id.expect("poll_id cannot be null"),
                     submitted_at: row.submitted_at.expect("submitted_at cannot be null"),
                     ip_address: row.ip_address,
+                    receipt_prefix: row.receipt_prefix,
                 };
                 
                 let ranking_rows = sqlx::query!(
@@ -167,6 +295,90 @@ impl Ballot {
         }
     }
 
+    /// Find the ballot a registered voter submitted, with rankings
+    pub async fn find_by_voter(pool: &PgPool, voter_id: Uuid) -> Result<Option<BallotResponse>, sqlx::Error> {
+        let ballot_id = sqlx::query_scalar!("SELECT id FROM ballots WHERE voter_id = $1", voter_id)
+            .fetch_optional(pool)
+            .await?;
+
+        match ballot_id {
+            Some(ballot_id) => Self::find_by_id(pool, ballot_id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Find a ballot by the id prefix and year embedded in a receipt code
+    pub async fn find_by_receipt(
+        pool: &PgPool,
+        id_prefix: &str,
+        year: i32,
+        prefix: &str,
+    ) -> Result<Option<ReceiptVerification>, sqlx::Error> {
+        // Built-in prefixes say whether the ballot was anonymous; a custom one is the prefix
+        // the ballot was issued under
+        let anonymous = match prefix {
+            VOTER_RECEIPT_PREFIX => Some(false),
+            ANONYMOUS_RECEIPT_PREFIX => Some(true),
+            _ => None,
+        };
+
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                p.title,
+                b.submitted_at as "submitted_at!",
+                EXISTS(SELECT 1 FROM rankings r WHERE r.ballot_id = b.id) as "counted!"
+            FROM ballots b
+            JOIN polls p ON p.id = b.poll_id
+            WHERE split_part(b.id::text, '-', 1) = $1
+              AND EXTRACT(YEAR FROM b.submitted_at AT TIME ZONE 'UTC')::int = $2
+              AND CASE WHEN $3::bool IS NULL THEN b.receipt_prefix = $4
+                       ELSE b.receipt_prefix IS NULL AND (b.voter_id IS NULL) = $3 END
+            ORDER BY b.submitted_at DESC
+            LIMIT 1
+            "#,
+            id_prefix,
+            year,
+            anonymous,
+            prefix
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| ReceiptVerification {
+            poll_title: row.title,
+            submitted_at: row.submitted_at,
+            counted: row.counted,
+        }))
+    }
+
+    /// Count anonymous ballots submitted to a poll under `rate_limit_key` (see
+    /// `anonymous_rate_limit_key`) within the last `window_secs` seconds
+    pub async fn count_recent_anonymous_by_key(
+        pool: &PgPool,
+        poll_id: Uuid,
+        rate_limit_key: &str,
+        window_secs: i64,
+    ) -> Result<i64, sqlx::Error> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM ballots
+            WHERE poll_id = $1
+              AND voter_id IS NULL
+              AND rate_limit_key = $2
+              AND submitted_at > NOW() - make_interval(secs => $3)
+            "#,
+            poll_id,
+            rate_limit_key,
+            window_secs as f64
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
+
     /// Get all ballots for a poll (for RCV tabulation)
     pub async fn find_by_poll_id(pool: &PgPool, poll_id: Uuid) -> Result<Vec<crate::services::rcv::Ballot>, sqlx::Error> {
         let ballot_data = sqlx::query!(
@@ -174,9 +386,9 @@ impl Ballot {
             SELECT 
                 b.id,
                 b.voter_id,
-                array_agg(r.candidate_id ORDER BY r.rank) as candidate_ids
+                array_agg(r.candidate_id ORDER BY r.rank) FILTER (WHERE r.candidate_id IS NOT NULL) as candidate_ids
             FROM ballots b
-            JOIN rankings r ON b.id = r.ballot_id
+            LEFT JOIN rankings r ON b.id = r.ballot_id
             WHERE b.poll_id = $1
             GROUP BY b.id, b.voter_id
             "#,
@@ -189,8 +401,9 @@ impl Ballot {
             .into_iter()
             .map(|row| crate::services::rcv::Ballot {
                 id: row.id,
-                // For anonymous ballots, voter_id is NULL, so use a placeholder UUID
-                voter_id: row.voter_id.unwrap_or_else(|| Uuid::nil()),
+                // For anonymous ballots, voter_id is NULL, so use a placeholder UUID.
+                // Ballots whose rankings were all removed come back empty (spoiled).
+                voter_id: row.voter_id.unwrap_or_else(Uuid::nil),
                 rankings: row.candidate_ids.unwrap_or_default(),
             })
             .collect();
@@ -200,28 +413,54 @@ impl Ballot {
 }
 
 impl Voter {
-    /// Create a new voter with ballot token
+    /// Create a new voter with a ballot token signed by `signer`
     pub async fn create(
         pool: &PgPool,
+        signer: &TokenSigner,
+        poll_id: Uuid,
+        email: Option<String>,
+        ip_address: Option<IpNetwork>,
+        user_agent: Option<String>,
+    ) -> Result<Voter, sqlx::Error> {
+        Self::insert(pool, signer, poll_id, email, ip_address, user_agent, None).await
+    }
+
+    /// Create an invited voter who prefers emails in `locale`
+    pub async fn create_with_locale(
+        pool: &PgPool,
+        signer: &TokenSigner,
+        poll_id: Uuid,
+        email: Option<String>,
+        locale: Option<String>,
+    ) -> Result<Voter, sqlx::Error> {
+        Self::insert(pool, signer, poll_id, email, None, None, locale).await
+    }
+
+    async fn insert(
+        pool: &PgPool,
+        signer: &TokenSigner,
         poll_id: Uuid,
         email: Option<String>,
         ip_address: Option<IpNetwork>,
         user_agent: Option<String>,
+        locale: Option<String>,
     ) -> Result<Voter, sqlx::Error> {
-        let ballot_token = generate_ballot_token();
+        let ballot_token = generate_ballot_token(signer);
+        let ip_address = ip_address.and_then(|network| stored_ip_address(network.ip()));
         
         let voter_row = sqlx::query!(
             r#"
-            INSERT INTO voters (poll_id, email, ballot_token, ip_address, user_agent)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO voters (poll_id, email, ballot_token, ip_address, user_agent, locale)
+            VALUES ($1, $2, $3, $4, $5, $6)
             RETURNING id, poll_id, email, ballot_token, ip_address, user_agent, 
-                      location_data, demographics, invited_at, voted_at
+                      location_data, demographics, locale, email_status, invited_at, voted_at
             "#,
             poll_id,
             email,
             ballot_token,
             ip_address,
-            user_agent
+            user_agent,
+            locale
         )
         .fetch_one(pool)
         .await?;
@@ -235,6 +474,8 @@ impl Voter {
             user_agent: voter_row.user_agent,
             location_data: voter_row.location_data,
             demographics: voter_row.demographics,
+            locale: voter_row.locale,
+            email_status: voter_row.email_status,
             invited_at: voter_row.invited_at.expect("invited_at cannot be null"),
             voted_at: voter_row.voted_at,
         };
@@ -242,12 +483,17 @@ impl Voter {
         Ok(voter)
     }
 
-    /// Find voter by ballot token
-    pub async fn find_by_token(pool: &PgPool, token: &str) -> Result<Option<Voter>, sqlx::Error> {
+    /// Find voter by ballot token. Tokens whose signature does not match are rejected
+    /// without touching the database.
+    pub async fn find_by_token(pool: &PgPool, signer: &TokenSigner, token: &str) -> Result<Option<Voter>, sqlx::Error> {
+        if !ballot_token_is_authentic(signer, token) {
+            return Ok(None);
+        }
+
         let voter_row = sqlx::query!(
             r#"
             SELECT id, poll_id, email, ballot_token, ip_address, user_agent,
-                   location_data, demographics, invited_at, voted_at
+                   location_data, demographics, locale, email_status, invited_at, voted_at
             FROM voters
             WHERE ballot_token = $1
             "#,
@@ -266,6 +512,8 @@ impl Voter {
                 user_agent: row.user_agent,
                 location_data: row.location_data,
                 demographics: row.demographics,
+                locale: row.locale,
+                email_status: row.email_status,
                 invited_at: row.invited_at.expect("invited_at cannot be null"),
                 voted_at: row.voted_at,
             })),
@@ -273,6 +521,119 @@ impl Voter {
         }
     }
 
+    /// Find voter by ID
+    pub async fn find_by_id(pool: &PgPool, voter_id: Uuid) -> Result<Option<Voter>, sqlx::Error> {
+        let voter_row = sqlx::query!(
+            r#"
+            SELECT id, poll_id, email, ballot_token, ip_address, user_agent,
+                   location_data, demographics, locale, email_status, invited_at, voted_at
+            FROM voters
+            WHERE id = $1
+            "#,
+            voter_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(voter_row.map(|row| Voter {
+            id: row.id,
+            poll_id: row.poll_id.expect("poll_id cannot be null"),
+            email: row.email,
+            ballot_token: row.ballot_token,
+            ip_address: row.ip_address,
+            user_agent: row.user_agent,
+            location_data: row.location_data,
+            demographics: row.demographics,
+            locale: row.locale,
+            email_status: row.email_status,
+            invited_at: row.invited_at.expect("invited_at cannot be null"),
+            voted_at: row.voted_at,
+        }))
+    }
+
+    /// Find all voters invited to a poll, most recent first
+    pub async fn find_by_poll_id(pool: &PgPool, poll_id: Uuid) -> Result<Vec<Voter>, sqlx::Error> {
+        Self::list_by_poll(pool, poll_id, None).await
+    }
+
+    /// Find a poll's voters, optionally only those whose email contains `q` (case-insensitive).
+    /// `Anonymous-` placeholder addresses only match a search that starts with "anon".
+    pub async fn list_by_poll(pool: &PgPool, poll_id: Uuid, q: Option<&str>) -> Result<Vec<Voter>, sqlx::Error> {
+        let q = q.map(str::trim).filter(|q| !q.is_empty());
+        // Escape LIKE wildcards so they match literally
+        let search_pattern = q.map(|q| format!("%{}%", q.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")));
+        let include_anonymous = q.is_none_or(|q| q.to_ascii_lowercase().starts_with("anon"));
+
+        let voter_rows = sqlx::query!(
+            r#"
+            SELECT id, poll_id, email, ballot_token, ip_address, user_agent,
+                   location_data, demographics, locale, email_status, invited_at, voted_at
+            FROM voters
+            WHERE poll_id = $1
+              AND ($2::text IS NULL OR email ILIKE $2)
+              AND ($3 OR email NOT LIKE 'Anonymous-%')
+            ORDER BY invited_at DESC
+            "#,
+            poll_id,
+            search_pattern,
+            include_anonymous
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let voters = voter_rows
+            .into_iter()
+            .map(|row| Voter {
+                id: row.id,
+                poll_id: row.poll_id.expect("poll_id cannot be null"),
+                email: row.email,
+                ballot_token: row.ballot_token,
+                ip_address: row.ip_address,
+                user_agent: row.user_agent,
+                location_data: row.location_data,
+                demographics: row.demographics,
+                locale: row.locale,
+                email_status: row.email_status,
+                invited_at: row.invited_at.expect("invited_at cannot be null"),
+                voted_at: row.voted_at,
+            })
+            .collect();
+
+        Ok(voters)
+    }
+
+    /// Whether the poll already has a voter with this email, ignoring case and surrounding
+    /// whitespace. Anonymous placeholder addresses never match.
+    pub async fn email_registered(pool: &PgPool, poll_id: Uuid, email: &str) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM voters
+                WHERE poll_id = $1
+                  AND LOWER(TRIM(email)) = LOWER(TRIM($2))
+                  AND email NOT LIKE 'Anonymous-%'
+            )
+            "#
+        )
+        .bind(poll_id)
+        .bind(email)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Record how the voter's latest invitation email went
+    pub async fn set_email_status(pool: &PgPool, voter_id: Uuid, status: VoterEmailStatus) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE voters SET email_status = $1 WHERE id = $2",
+            status.as_str(),
+            voter_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
     /// Mark voter as having voted
     pub async fn mark_as_voted(pool: &PgPool, voter_id: Uuid) -> Result<(), sqlx::Error> {
         sqlx::query!(
@@ -285,41 +646,174 @@ impl Voter {
         Ok(())
     }
 
+    /// Delete a voter along with any ballot, rankings and draft they left behind
+    pub async fn delete(pool: &PgPool, voter_id: Uuid) -> Result<bool, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query!(
+            "DELETE FROM rankings WHERE ballot_id IN (SELECT id FROM ballots WHERE voter_id = $1)",
+            voter_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("DELETE FROM ballots WHERE voter_id = $1", voter_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // Keep ad impression history but detach it from the removed voter
+        sqlx::query!("UPDATE ad_impressions SET voter_id = NULL WHERE voter_id = $1", voter_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let result = sqlx::query!("DELETE FROM voters WHERE id = $1", voter_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     /// Check if voter has already voted
     pub fn has_voted(&self) -> bool {
         self.voted_at.is_some()
     }
 }
 
-/// Generate a cryptographically secure ballot token
-fn generate_ballot_token() -> String {
+impl BallotDraft {
+    /// Save a voter's draft rankings, replacing any previous draft
+    pub async fn save(
+        pool: &PgPool,
+        voter_id: Uuid,
+        rankings: Vec<BallotRanking>,
+    ) -> Result<BallotDraft, sqlx::Error> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO ballot_drafts (voter_id, rankings, updated_at)
+            VALUES ($1, $2, CURRENT_TIMESTAMP)
+            ON CONFLICT (voter_id)
+            DO UPDATE SET rankings = EXCLUDED.rankings, updated_at = EXCLUDED.updated_at
+            RETURNING updated_at as "updated_at!"
+            "#,
+            voter_id,
+            sqlx::types::Json(&rankings) as _
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(BallotDraft {
+            voter_id,
+            rankings,
+            updated_at: row.updated_at,
+        })
+    }
+
+    /// Find the saved draft for a voter
+    pub async fn find_by_voter(pool: &PgPool, voter_id: Uuid) -> Result<Option<BallotDraft>, sqlx::Error> {
+        let row = sqlx::query!(
+            r#"
+            SELECT voter_id, rankings as "rankings: sqlx::types::Json<Vec<BallotRanking>>", updated_at as "updated_at!"
+            FROM ballot_drafts
+            WHERE voter_id = $1
+            "#,
+            voter_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| BallotDraft {
+            voter_id: row.voter_id,
+            rankings: row.rankings.0,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Remove a voter's draft once their ballot has been submitted
+    pub async fn delete(pool: &PgPool, voter_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query!("DELETE FROM ballot_drafts WHERE voter_id = $1", voter_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Length of tokens issued before they were signed (`VOTE-YYYY-XXXXXX`), which are still accepted
+const LEGACY_BALLOT_TOKEN_LEN: usize = 16;
+/// Random characters in a ballot token, about 82 bits of entropy
+const BALLOT_TOKEN_RANDOM_LEN: usize = 16;
+/// Hex characters of the HMAC kept at the end of a ballot token
+const BALLOT_TOKEN_SIGNATURE_LEN: usize = 16;
+
+/// Generate a cryptographically secure ballot token of the form
+/// `VOTE-YYYY-<random>-<signature>`, where the signature is a truncated HMAC of the rest
+fn generate_ballot_token(signer: &TokenSigner) -> String {
     use rand::Rng;
     let mut rng = rand::thread_rng();
     
-    // Generate a random string with format: VOTE-YYYY-XXXXXX
     let year = chrono::Utc::now().format("%Y");
-    let random_part: String = (0..6)
+    let random_part: String = (0..BALLOT_TOKEN_RANDOM_LEN)
         .map(|_| {
             let chars = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
             chars[rng.gen_range(0..chars.len())] as char
         })
         .collect();
     
-    format!("VOTE-{}-{}", year, random_part)
+    let unsigned = format!("VOTE-{}-{}", year, random_part);
+    let signature = signer.sign(&unsigned);
+    format!("{}-{}", unsigned, &signature[..BALLOT_TOKEN_SIGNATURE_LEN])
+}
+
+/// Whether `token` carries a valid signature. Legacy unsigned tokens pass so they keep
+/// working; they are still looked up like any other token.
+fn ballot_token_is_authentic(signer: &TokenSigner, token: &str) -> bool {
+    if token.len() == LEGACY_BALLOT_TOKEN_LEN {
+        return true;
+    }
+    token.rsplit_once('-').is_some_and(|(unsigned, signature)| {
+        signature.len() == BALLOT_TOKEN_SIGNATURE_LEN && signer.verify_truncated_signature(unsigned, signature)
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_ballot_token_generation() {
-        let token1 = generate_ballot_token();
-        let token2 = generate_ballot_token();
+    /// A pool that never connects, so any query through it fails
+    fn unreachable_pool() -> PgPool {
+        PgPool::connect_lazy("postgres://nobody@unreachable.invalid/none").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_ballot_token_generation() {
+        let signer = TokenSigner::new("test-signing-key");
+        let token1 = generate_ballot_token(&signer);
+        let token2 = generate_ballot_token(&signer);
         
         assert_ne!(token1, token2);
         assert!(token1.starts_with("VOTE-"));
-        assert_eq!(token1.len(), 16); // VOTE-YYYY-XXXXXX = 16 chars
+        assert_eq!(token1.len(), 43); // VOTE-YYYY-<16 random>-<16 signature>
+        assert!(ballot_token_is_authentic(&signer, &token1));
+    }
+
+    #[tokio::test]
+    async fn test_tampered_ballot_token_rejected_without_database() {
+        let pool = unreachable_pool();
+        let signer = TokenSigner::new("test-signing-key");
+        let token = generate_ballot_token(&signer);
+
+        // Changing one random character breaks the signature
+        let mut tampered: Vec<char> = token.chars().collect();
+        tampered[12] = if tampered[12] == 'A' { 'B' } else { 'A' };
+        let tampered: String = tampered.into_iter().collect();
+
+        assert!(matches!(Voter::find_by_token(&pool, &signer, &tampered).await, Ok(None)));
+        assert!(matches!(Voter::find_by_token(&pool, &signer, "VOTE-2025-ABC123-0000").await, Ok(None)));
+
+        // Authentic and legacy tokens go on to the database, which this pool cannot reach
+        assert!(Voter::find_by_token(&pool, &signer, &token).await.is_err());
+        assert!(Voter::find_by_token(&pool, &signer, "VOTE-2024-ABC123").await.is_err());
     }
 
     #[test]
@@ -333,6 +827,8 @@ mod tests {
             user_agent: None,
             location_data: None,
             demographics: None,
+            locale: None,
+            email_status: None,
             invited_at: Utc::now(),
             voted_at: None,
         };
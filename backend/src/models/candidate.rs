@@ -1,28 +1,194 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::{FromRow, PgPool};
+use sqlx::postgres::PgArguments;
+use sqlx::query::QueryAs;
+use sqlx::{Connection, FromRow, PgConnection, PgPool, Postgres};
 use uuid::Uuid;
 
+use super::nullable;
+
+/// Column list selected for every `Candidate` row
+pub const CANDIDATE_COLUMNS: &str = "id, poll_id, name, slug, description, statement, affiliation, image_url, display_order, is_write_in, is_nota, created_at";
+
+/// Name of the reserved candidate added to polls that allow rejecting the whole field
+pub const NOTA_CANDIDATE_NAME: &str = "None of the above";
+
+/// Longest slug generated from a name, before any collision suffix
+const MAX_SLUG_BASE_LEN: usize = 48;
+
+// Unique constraint on a poll's candidate slugs
+const CANDIDATE_SLUG_UNIQUE: &str = "candidates_poll_slug_unique";
+
+/// Times to pick a fresh slug when a concurrent insert takes the one chosen
+const SLUG_INSERT_ATTEMPTS: usize = 3;
+
+/// Longest candidate name, in characters, after sanitizing
+pub const MAX_CANDIDATE_NAME_LEN: usize = 200;
+/// Longest candidate description, in characters, after sanitizing
+pub const MAX_CANDIDATE_DESCRIPTION_LEN: usize = 500;
+/// Longest candidate statement, in characters, after sanitizing
+pub const MAX_CANDIDATE_STATEMENT_LEN: usize = 10_000;
+/// Longest candidate affiliation, in characters, after sanitizing
+pub const MAX_CANDIDATE_AFFILIATION_LEN: usize = 100;
+
+/// Trim a candidate name and drop control characters (including line breaks),
+/// since names end up in ballots and email subjects
+pub fn sanitize_candidate_name(name: &str) -> String {
+    name.chars().filter(|c| !c.is_control()).collect::<String>().trim().to_string()
+}
+
+/// The form two candidate names are compared in: sanitized and lowercased, so
+/// "Alice" and " alice " count as the same name
+pub fn candidate_name_key(name: &str) -> String {
+    sanitize_candidate_name(name).to_lowercase()
+}
+
+/// Trim a candidate description and drop control characters other than line breaks.
+/// A description that ends up empty is treated as missing.
+pub fn sanitize_candidate_description(description: Option<&str>) -> Option<String> {
+    description
+        .map(|d| d.chars().filter(|&c| !c.is_control() || c == '\n').collect::<String>().trim().to_string())
+        .filter(|d| !d.is_empty())
+}
+
+/// Clean a candidate's party or group the same way as a name. An empty affiliation is
+/// treated as missing.
+pub fn sanitize_candidate_affiliation(affiliation: Option<&str>) -> Option<String> {
+    affiliation.map(sanitize_candidate_name).filter(|a| !a.is_empty())
+}
+
+/// Clean a markdown candidate statement the same way as a description. The markdown is
+/// kept as written; `render_candidate_statement` is what makes it safe to display. An
+/// empty statement is treated as missing.
+pub fn sanitize_candidate_statement(statement: Option<&str>) -> Option<String> {
+    sanitize_candidate_description(statement)
+}
+
+/// Render a markdown statement to HTML that is safe to insert into a page. The rendered
+/// HTML goes through ammonia, which parses it like a browser would, so tags split around
+/// other tags, entity-encoded link schemes and `data:` URLs can't slip through.
+pub fn render_candidate_statement(statement: &str) -> String {
+    let mut html = String::with_capacity(statement.len());
+    pulldown_cmark::html::push_html(&mut html, pulldown_cmark::Parser::new(statement));
+    ammonia::clean(&html)
+}
+
+/// Lowercase a name into a URL-safe slug: "John Smith" becomes `john-smith`.
+/// Names with no ASCII letters or digits fall back to `candidate`.
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.truncate(MAX_SLUG_BASE_LEN);
+
+    match slug.trim_matches('-') {
+        "" => "candidate".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+#[serde(into = "CandidateView")]
 pub struct Candidate {
     pub id: Uuid,
     pub poll_id: Uuid,
     pub name: String,
+    /// Short code unique within the poll, derived from the name when the candidate is added
+    pub slug: String,
     pub description: Option<String>,
+    /// Longer markdown statement shown to voters on the ballot
+    pub statement: Option<String>,
+    /// Party or group the candidate stands for; results can be grouped by it
+    pub affiliation: Option<String>,
+    pub image_url: Option<String>,
     pub display_order: i32,
+    /// Added by a voter's write-in rather than by the poll owner
+    pub is_write_in: bool,
+    /// The reserved "None of the above" option rather than a real candidate
+    pub is_nota: bool,
     pub created_at: DateTime<Utc>,
 }
 
+/// A candidate as sent to clients: the stored fields plus the statement rendered to HTML.
+/// Clients should show `statement_html`; `statement` is the markdown for editing.
+#[derive(Serialize)]
+struct CandidateView {
+    id: Uuid,
+    poll_id: Uuid,
+    name: String,
+    slug: String,
+    description: Option<String>,
+    statement: Option<String>,
+    statement_html: Option<String>,
+    affiliation: Option<String>,
+    image_url: Option<String>,
+    display_order: i32,
+    is_write_in: bool,
+    is_nota: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl From<Candidate> for CandidateView {
+    fn from(candidate: Candidate) -> Self {
+        Self {
+            statement_html: candidate.statement.as_deref().map(render_candidate_statement),
+            id: candidate.id,
+            poll_id: candidate.poll_id,
+            name: candidate.name,
+            slug: candidate.slug,
+            description: candidate.description,
+            statement: candidate.statement,
+            affiliation: candidate.affiliation,
+            image_url: candidate.image_url,
+            display_order: candidate.display_order,
+            is_write_in: candidate.is_write_in,
+            is_nota: candidate.is_nota,
+            created_at: candidate.created_at,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateCandidateRequest {
     pub name: String,
     pub description: Option<String>,
+    /// Markdown statement for the ballot; only a sanitized rendering is shown to voters
+    pub statement: Option<String>,
+    /// Party or group, e.g. `Green`
+    pub affiliation: Option<String>,
+    pub image_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct UpdateCandidateRequest {
     pub name: Option<String>,
     pub description: Option<String>,
+    /// `null` or an empty statement removes it
+    #[serde(default, deserialize_with = "nullable")]
+    pub statement: Option<Option<String>>,
+    /// `null` or an empty affiliation makes the candidate unaffiliated
+    #[serde(default, deserialize_with = "nullable")]
+    pub affiliation: Option<Option<String>>,
+    /// `null` removes the image
+    #[serde(default, deserialize_with = "nullable")]
+    pub image_url: Option<Option<String>>,
+}
+
+/// Candidate entry in a poll update. Entries with an `id` update that
+/// candidate in place; entries without one are inserted as new candidates.
+#[derive(Debug, Deserialize)]
+pub struct UpsertCandidateRequest {
+    pub id: Option<Uuid>,
+    pub name: String,
+    pub description: Option<String>,
+    pub statement: Option<String>,
+    pub affiliation: Option<String>,
+    pub image_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -33,7 +199,7 @@ pub struct ReorderCandidatesRequest {
 impl Candidate {
     pub async fn find_by_poll_id(pool: &PgPool, poll_id: Uuid) -> Result<Vec<Candidate>, sqlx::Error> {
         let candidates = sqlx::query_as::<_, Candidate>(
-            "SELECT id, poll_id, name, description, display_order, created_at FROM candidates WHERE poll_id = $1 ORDER BY display_order ASC"
+            &format!("SELECT {} FROM candidates WHERE poll_id = $1 ORDER BY display_order ASC", CANDIDATE_COLUMNS)
         )
         .bind(poll_id)
         .fetch_all(pool)
@@ -42,9 +208,94 @@ impl Candidate {
         Ok(candidates)
     }
 
+    pub async fn find_by_slug(pool: &PgPool, poll_id: Uuid, slug: &str) -> Result<Option<Candidate>, sqlx::Error> {
+        sqlx::query_as::<_, Candidate>(
+            &format!("SELECT {} FROM candidates WHERE poll_id = $1 AND slug = $2", CANDIDATE_COLUMNS)
+        )
+        .bind(poll_id)
+        .bind(slug)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Slug for a new candidate, numbered (`john-smith-2`, `john-smith-3`, ...) when the
+    /// poll already has one with the same base
+    async fn unique_slug<'e, E>(executor: E, poll_id: Uuid, name: &str) -> Result<String, sqlx::Error>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        let base = slugify(name);
+        let taken: Vec<String> = sqlx::query_scalar(
+            "SELECT slug FROM candidates WHERE poll_id = $1 AND (slug = $2 OR slug LIKE $2 || '-%')"
+        )
+        .bind(poll_id)
+        .bind(&base)
+        .fetch_all(executor)
+        .await?;
+
+        if !taken.contains(&base) {
+            return Ok(base);
+        }
+        let slug = (2..)
+            .map(|n| format!("{}-{}", base, n))
+            .find(|candidate| !taken.contains(candidate))
+            .expect("an unused suffix always exists");
+        Ok(slug)
+    }
+
+    /// Run the candidate insert `insert` builds for a slug derived from `name`. Picking the
+    /// slug and inserting it isn't atomic, so when a concurrent insert takes the slug first
+    /// the statement is rolled back to a savepoint and retried with the next free one.
+    pub(crate) async fn insert_with_unique_slug<'q, F>(
+        conn: &mut PgConnection,
+        poll_id: Uuid,
+        name: &str,
+        insert: F,
+    ) -> Result<Candidate, sqlx::Error>
+    where
+        F: Fn(String) -> QueryAs<'q, Postgres, Candidate, PgArguments>,
+    {
+        let mut attempt = 1;
+        loop {
+            let slug = Self::unique_slug(&mut *conn, poll_id, name).await?;
+            let mut savepoint = conn.begin().await?;
+            match insert(slug).fetch_one(&mut *savepoint).await {
+                Ok(candidate) => {
+                    savepoint.commit().await?;
+                    return Ok(candidate);
+                }
+                Err(sqlx::Error::Database(db_err))
+                    if db_err.constraint() == Some(CANDIDATE_SLUG_UNIQUE) && attempt < SLUG_INSERT_ATTEMPTS =>
+                {
+                    savepoint.rollback().await?;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Whether the poll has closed, after which its candidates are fixed
+    pub async fn poll_is_closed(pool: &PgPool, poll_id: Uuid) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM polls WHERE id = $1 AND status = 'closed')")
+            .bind(poll_id)
+            .fetch_one(pool)
+            .await
+    }
+
+    /// Number of candidates on a poll, including write-ins but not "None of the above"
+    pub async fn count_by_poll(pool: &PgPool, poll_id: Uuid) -> Result<usize, sqlx::Error> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM candidates WHERE poll_id = $1 AND NOT is_nota")
+            .bind(poll_id)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(count as usize)
+    }
+
     pub async fn find_by_id(pool: &PgPool, candidate_id: Uuid) -> Result<Option<Candidate>, sqlx::Error> {
         let candidate = sqlx::query_as::<_, Candidate>(
-            "SELECT id, poll_id, name, description, display_order, created_at FROM candidates WHERE id = $1"
+            &format!("SELECT {} FROM candidates WHERE id = $1", CANDIDATE_COLUMNS)
         )
         .bind(candidate_id)
         .fetch_optional(pool)
@@ -53,36 +304,159 @@ impl Candidate {
         Ok(candidate)
     }
 
+    /// A candidate, provided its poll belongs to `user_id` and has not been deleted
+    pub async fn find_by_id_for_owner(pool: &PgPool, candidate_id: Uuid, user_id: Uuid) -> Result<Option<Candidate>, sqlx::Error> {
+        let candidate = sqlx::query_as::<_, Candidate>(&format!(
+            "SELECT {} FROM candidates WHERE id = $1 AND poll_id IN (SELECT id FROM polls WHERE user_id = $2 AND deleted_at IS NULL)",
+            CANDIDATE_COLUMNS
+        ))
+        .bind(candidate_id)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(candidate)
+    }
+
     pub async fn create(
         pool: &PgPool,
         poll_id: Uuid,
         req: CreateCandidateRequest,
     ) -> Result<Candidate, sqlx::Error> {
-        // Get the next display order
+        let display_order = Self::next_display_order(pool, poll_id).await?;
+        let name = sanitize_candidate_name(&req.name);
+        let sql = format!(
+            r#"
+            INSERT INTO candidates (poll_id, name, slug, description, statement, affiliation, image_url, display_order)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {}
+            "#,
+            CANDIDATE_COLUMNS
+        );
+
+        let mut conn = pool.acquire().await?;
+        Self::insert_with_unique_slug(&mut conn, poll_id, &name, |slug| {
+            sqlx::query_as::<_, Candidate>(&sql)
+                .bind(poll_id)
+                .bind(&name)
+                .bind(slug)
+                .bind(sanitize_candidate_description(req.description.as_deref()))
+                .bind(sanitize_candidate_statement(req.statement.as_deref()))
+                .bind(sanitize_candidate_affiliation(req.affiliation.as_deref()))
+                .bind(&req.image_url)
+                .bind(display_order)
+        })
+        .await
+    }
+
+    /// Insert several candidates in one transaction, continuing the poll's display order
+    pub async fn create_bulk(
+        pool: &PgPool,
+        poll_id: Uuid,
+        reqs: Vec<CreateCandidateRequest>,
+    ) -> Result<Vec<Candidate>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        let first_order = Self::next_display_order(&mut *tx, poll_id).await?;
+
+        let sql = format!(
+            "INSERT INTO candidates (poll_id, name, slug, description, statement, affiliation, image_url, display_order) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING {}",
+            CANDIDATE_COLUMNS
+        );
+        for (index, req) in reqs.iter().enumerate() {
+            let name = sanitize_candidate_name(&req.name);
+            Self::insert_with_unique_slug(&mut tx, poll_id, &name, |slug| {
+                sqlx::query_as::<_, Candidate>(&sql)
+                    .bind(poll_id)
+                    .bind(&name)
+                    .bind(slug)
+                    .bind(sanitize_candidate_description(req.description.as_deref()))
+                    .bind(sanitize_candidate_statement(req.statement.as_deref()))
+                    .bind(sanitize_candidate_affiliation(req.affiliation.as_deref()))
+                    .bind(&req.image_url)
+                    .bind(first_order + index as i32)
+            })
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Self::find_by_poll_id(pool, poll_id).await
+    }
+
+    /// Get the next display order for a new candidate in a poll
+    async fn next_display_order<'e, E>(executor: E, poll_id: Uuid) -> Result<i32, sqlx::Error>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
         let next_order: (Option<i32>,) = sqlx::query_as(
             "SELECT MAX(display_order) FROM candidates WHERE poll_id = $1"
         )
         .bind(poll_id)
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
 
-        let display_order = next_order.0.unwrap_or(0) + 1;
+        Ok(next_order.0.unwrap_or(0) + 1)
+    }
 
-        let candidate = sqlx::query_as::<_, Candidate>(
+    /// Add the poll's "None of the above" candidate, or move the existing one, after all the others
+    pub(crate) async fn ensure_nota(conn: &mut PgConnection, poll_id: Uuid) -> Result<Candidate, sqlx::Error> {
+        let display_order = Self::next_display_order(&mut *conn, poll_id).await?;
+        let sql = format!(
             r#"
-            INSERT INTO candidates (poll_id, name, description, display_order)
-            VALUES ($1, $2, $3, $4)
-            RETURNING id, poll_id, name, description, display_order, created_at
+            INSERT INTO candidates (poll_id, name, slug, display_order, is_nota)
+            VALUES ($1, $2, $3, $4, true)
+            ON CONFLICT (poll_id) WHERE is_nota
+            DO UPDATE SET display_order = EXCLUDED.display_order
+            RETURNING {}
             "#,
-        )
-        .bind(poll_id)
-        .bind(&req.name)
-        .bind(&req.description)
-        .bind(display_order)
-        .fetch_one(pool)
-        .await?;
+            CANDIDATE_COLUMNS
+        );
 
-        Ok(candidate)
+        Self::insert_with_unique_slug(conn, poll_id, NOTA_CANDIDATE_NAME, |slug| {
+            sqlx::query_as::<_, Candidate>(&sql)
+                .bind(poll_id)
+                .bind(NOTA_CANDIDATE_NAME)
+                .bind(slug)
+                .bind(display_order)
+        })
+        .await
+    }
+
+    /// Remove the poll's "None of the above" candidate, if it has one, reporting whether it did
+    pub(crate) async fn remove_nota(conn: &mut PgConnection, poll_id: Uuid) -> Result<bool, sqlx::Error> {
+        let removed = sqlx::query("DELETE FROM candidates WHERE poll_id = $1 AND is_nota")
+            .bind(poll_id)
+            .execute(conn)
+            .await?;
+
+        Ok(removed.rows_affected() > 0)
+    }
+
+    /// Find the poll's write-in candidate with this name (ignoring case), creating it if needed
+    pub async fn find_or_create_write_in(conn: &mut PgConnection, poll_id: Uuid, name: &str) -> Result<Candidate, sqlx::Error> {
+        let display_order = Self::next_display_order(&mut *conn, poll_id).await?;
+
+        // The no-op update makes RETURNING yield the existing row on conflict
+        let sql = format!(
+            r#"
+            INSERT INTO candidates (poll_id, name, slug, display_order, is_write_in)
+            VALUES ($1, $2, $3, $4, true)
+            ON CONFLICT (poll_id, LOWER(name)) WHERE is_write_in
+            DO UPDATE SET name = candidates.name
+            RETURNING {}
+            "#,
+            CANDIDATE_COLUMNS
+        );
+
+        Self::insert_with_unique_slug(conn, poll_id, name, |slug| {
+            sqlx::query_as::<_, Candidate>(&sql)
+                .bind(poll_id)
+                .bind(name)
+                .bind(slug)
+                .bind(display_order)
+        })
+        .await
     }
 
     pub async fn update(
@@ -90,39 +464,31 @@ impl Candidate {
         candidate_id: Uuid,
         req: UpdateCandidateRequest,
     ) -> Result<Option<Candidate>, sqlx::Error> {
-        // Simple approach: construct update based on what fields are provided
-        if req.name.is_some() && req.description.is_some() {
-            let candidate = sqlx::query_as::<_, Candidate>(
-                "UPDATE candidates SET name = $1, description = $2 WHERE id = $3 RETURNING id, poll_id, name, description, display_order, created_at"
-            )
-            .bind(&req.name)
-            .bind(&req.description)
-            .bind(candidate_id)
-            .fetch_optional(pool)
-            .await?;
-            Ok(candidate)
-        } else if req.name.is_some() {
-            let candidate = sqlx::query_as::<_, Candidate>(
-                "UPDATE candidates SET name = $1 WHERE id = $2 RETURNING id, poll_id, name, description, display_order, created_at"
-            )
-            .bind(&req.name)
-            .bind(candidate_id)
-            .fetch_optional(pool)
-            .await?;
-            Ok(candidate)
-        } else if req.description.is_some() {
-            let candidate = sqlx::query_as::<_, Candidate>(
-                "UPDATE candidates SET description = $1 WHERE id = $2 RETURNING id, poll_id, name, description, display_order, created_at"
-            )
-            .bind(&req.description)
-            .bind(candidate_id)
-            .fetch_optional(pool)
-            .await?;
-            Ok(candidate)
-        } else {
-            // No changes requested, return current candidate
-            Self::find_by_id(pool, candidate_id).await
-        }
+        // Fields left out of the request keep their current values; a null statement,
+        // affiliation or image is removed
+        sqlx::query_as::<_, Candidate>(&format!(
+            r#"
+            UPDATE candidates
+            SET name = COALESCE($1, name), description = COALESCE($2, description),
+                statement = CASE WHEN $3 THEN $4 ELSE statement END,
+                affiliation = CASE WHEN $5 THEN $6 ELSE affiliation END,
+                image_url = CASE WHEN $7 THEN $8 ELSE image_url END
+            WHERE id = $9
+            RETURNING {}
+            "#,
+            CANDIDATE_COLUMNS
+        ))
+        .bind(req.name.as_deref().map(sanitize_candidate_name))
+        .bind(sanitize_candidate_description(req.description.as_deref()))
+        .bind(req.statement.is_some())
+        .bind(sanitize_candidate_statement(req.statement.flatten().as_deref()))
+        .bind(req.affiliation.is_some())
+        .bind(sanitize_candidate_affiliation(req.affiliation.flatten().as_deref()))
+        .bind(req.image_url.is_some())
+        .bind(req.image_url.flatten())
+        .bind(candidate_id)
+        .fetch_optional(pool)
+        .await
     }
 
     pub async fn delete(pool: &PgPool, candidate_id: Uuid) -> Result<bool, sqlx::Error> {
@@ -158,4 +524,76 @@ impl Candidate {
         // Return updated candidates
         Self::find_by_poll_id(pool, poll_id).await
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("John Smith"), "john-smith");
+        assert_eq!(slugify("  Dr. Jane O'Neil, Jr. "), "dr-jane-o-neil-jr");
+        assert_eq!(slugify("Zoë 2024"), "zo-2024");
+        assert_eq!(slugify("日本"), "candidate");
+        assert_eq!(slugify(&"a".repeat(100)).len(), MAX_SLUG_BASE_LEN);
+    }
+
+    #[test]
+    fn test_sanitize_candidate_text() {
+        assert_eq!(sanitize_candidate_name("  Ali\u{7}ce\n"), "Alice");
+        assert_eq!(sanitize_candidate_name("\t\u{0}"), "");
+        assert_eq!(
+            sanitize_candidate_description(Some(" First line\nSecond\u{1b}[31m line ")),
+            Some("First line\nSecond[31m line".to_string())
+        );
+        assert_eq!(sanitize_candidate_description(Some(" \r\n ")), None);
+        assert_eq!(sanitize_candidate_description(None), None);
+    }
+
+    #[test]
+    fn test_sanitize_candidate_statement() {
+        assert_eq!(
+            sanitize_candidate_statement(Some(" ## Plan\n\nFix **roads**\u{7} ")),
+            Some("## Plan\n\nFix **roads**".to_string())
+        );
+        assert_eq!(sanitize_candidate_statement(Some(" \n ")), None);
+    }
+
+    #[test]
+    fn test_render_candidate_statement() {
+        assert_eq!(
+            render_candidate_statement("## Plan\n\nFix **roads** [site](https://example.org) 1 < 2"),
+            "<h2>Plan</h2>\n<p>Fix <strong>roads</strong> <a href=\"https://example.org\" rel=\"noopener noreferrer\">site</a> 1 &lt; 2</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_candidate_statement_blocks_script() {
+        let inputs = [
+            "<script>alert(1)</script>",
+            "<b onclick=\"x()\">now</b>",
+            "<im<b>g src=x onerror=alert(1)>",
+            "<scr<script></script>ipt>alert(1)</script>",
+            "[x](javascript:alert(1))",
+            "[x](JavaScript:alert(1))",
+            "[x](&#106;avascript:alert(1))",
+            "[x](java&#x09;script:alert(1))",
+            "[x](data:text/html;base64,PHNjcmlwdD5hbGVydCgxKTwvc2NyaXB0Pg==)",
+            "![x](data:image/svg+xml,<svg onload=alert(1)>)",
+            "<a href=\"&#106;avascript:alert(1)\">x</a>",
+            "<iframe src=x></iframe><style>p{}</style>",
+        ];
+        for input in inputs {
+            let html = render_candidate_statement(input).to_ascii_lowercase();
+            // Text is escaped, so every literal `<` opens a tag that a browser would build
+            for tag in html.split('<').skip(1).map(|rest| rest.split('>').next().unwrap_or_default()) {
+                let name = tag.trim_start_matches('/').split([' ', '/']).next().unwrap_or_default();
+                assert!(!["script", "iframe", "style"].contains(&name), "{:?} rendered as {:?}", input, html);
+                for unsafe_fragment in ["onerror", "onclick", "onload", "javascript:", "data:"] {
+                    assert!(!tag.contains(unsafe_fragment), "{:?} rendered as {:?}", input, html);
+                }
+            }
+        }
+    }
+}
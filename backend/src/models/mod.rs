@@ -2,4 +2,18 @@ pub mod auth_token;
 pub mod ballot;
 pub mod candidate;
 pub mod poll;
-pub mod user; 
\ No newline at end of file
+pub mod poll_closure;
+pub mod result_version;
+pub mod user; 
+use serde::{Deserialize, Deserializer};
+
+/// Deserialize an optional field of a partial update so an explicit `null` can be told
+/// apart from a missing field: missing is `None` (keep it), `null` is `Some(None)` (clear
+/// it) and a value is `Some(Some(_))`. Use with `#[serde(default, deserialize_with = "nullable")]`.
+pub fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
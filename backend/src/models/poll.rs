@@ -1,9 +1,135 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, PgPool};
 use uuid::Uuid;
 
-use super::candidate::{Candidate, CreateCandidateRequest};
+use crate::config::DEFAULT_LOCALE;
+use crate::services::rcv::{TieBreakMethod, WinCondition};
+
+use super::candidate::{
+    sanitize_candidate_affiliation, sanitize_candidate_description, sanitize_candidate_name, sanitize_candidate_statement,
+    Candidate, CreateCandidateRequest, UpsertCandidateRequest, CANDIDATE_COLUMNS,
+};
+use super::poll_closure::CloseReason;
+use super::nullable;
+
+/// Most tags a single poll can carry
+pub const MAX_POLL_TAGS: usize = 20;
+/// Longest tag, in characters, after normalizing
+pub const MAX_POLL_TAG_LEN: usize = 50;
+
+/// Trim and lowercase each tag, then drop duplicates so "Board" and " board " are one tag.
+/// The result is sorted, matching the order tags are read back in.
+pub fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = tags.iter().map(|t| t.trim().to_lowercase()).collect();
+    normalized.sort();
+    normalized.dedup();
+    normalized
+}
+
+/// Whether `name` is an IANA time zone such as `America/New_York`
+pub fn is_valid_timezone(name: &str) -> bool {
+    name.parse::<Tz>().is_ok()
+}
+
+/// `at` rendered in the IANA time zone `timezone`; `None` without both
+pub fn local_time(at: Option<DateTime<Utc>>, timezone: Option<&str>) -> Option<DateTime<FixedOffset>> {
+    let tz: Tz = timezone?.parse().ok()?;
+    Some(at?.with_timezone(&tz).fixed_offset())
+}
+
+/// Column list selected for every `Poll` row
+const POLL_COLUMNS: &str = "id, user_id, title, description, poll_type, num_winners, opens_at, closes_at, is_public, registration_required, require_full_ranking, allow_write_ins, quorum, skipped_rankings_policy, default_locale, results_visibility, min_rankings, allow_none_of_the_above, receipt_prefix, win_condition, plurality_round_limit, require_captcha, timezone, tie_break_seed, status, ARRAY(SELECT tag FROM poll_tags WHERE poll_tags.poll_id = polls.id ORDER BY tag) as tags, created_at, updated_at";
+
+/// Poll types the tabulation engine knows how to count
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollType {
+    SingleWinner,
+    MultiWinner,
+    Approval,
+    Borda,
+    Condorcet,
+}
+
+impl PollType {
+    pub const ALL: [PollType; 5] = [
+        PollType::SingleWinner,
+        PollType::MultiWinner,
+        PollType::Approval,
+        PollType::Borda,
+        PollType::Condorcet,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PollType::SingleWinner => "single_winner",
+            PollType::MultiWinner => "multi_winner",
+            PollType::Approval => "approval",
+            PollType::Borda => "borda",
+            PollType::Condorcet => "condorcet",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<PollType> {
+        Self::ALL.into_iter().find(|poll_type| poll_type.as_str() == value)
+    }
+}
+
+/// What to do with a ranked ballot that skips a rank, e.g. 1, 2, 4
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkippedRankingsPolicy {
+    /// Refuse the ballot (the default)
+    Reject,
+    /// Close the gaps so 1, 2, 4 is stored as 1, 2, 3
+    Collapse,
+}
+
+impl SkippedRankingsPolicy {
+    pub const ALL: [SkippedRankingsPolicy; 2] = [SkippedRankingsPolicy::Reject, SkippedRankingsPolicy::Collapse];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SkippedRankingsPolicy::Reject => "reject",
+            SkippedRankingsPolicy::Collapse => "collapse",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<SkippedRankingsPolicy> {
+        Self::ALL.into_iter().find(|policy| policy.as_str() == value)
+    }
+}
+
+/// Who besides the owner may read a poll's results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultsVisibility {
+    /// Owner only (the default)
+    Private,
+    /// Anyone, once voting has closed
+    PublicAfterClose,
+    /// Anyone, including while voting is open
+    PublicAlways,
+}
+
+impl ResultsVisibility {
+    pub const ALL: [ResultsVisibility; 3] = [
+        ResultsVisibility::Private,
+        ResultsVisibility::PublicAfterClose,
+        ResultsVisibility::PublicAlways,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ResultsVisibility::Private => "private",
+            ResultsVisibility::PublicAfterClose => "public_after_close",
+            ResultsVisibility::PublicAlways => "public_always",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<ResultsVisibility> {
+        Self::ALL.into_iter().find(|visibility| visibility.as_str() == value)
+    }
+}
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct Poll {
@@ -17,6 +143,23 @@ pub struct Poll {
     pub closes_at: Option<DateTime<Utc>>,
     pub is_public: bool,
     pub registration_required: bool,
+    pub require_full_ranking: bool,
+    pub allow_write_ins: bool,
+    pub quorum: Option<i32>,
+    pub skipped_rankings_policy: String,
+    pub default_locale: String,
+    pub results_visibility: String,
+    pub min_rankings: Option<i32>,
+    pub allow_none_of_the_above: bool,
+    pub receipt_prefix: Option<String>,
+    pub win_condition: String,
+    pub plurality_round_limit: Option<i32>,
+    pub require_captcha: bool,
+    pub timezone: Option<String>,
+    pub tie_break_seed: Option<i64>,
+    /// `open`, or `closed` once the poll has been finalized
+    pub status: String,
+    pub tags: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -31,6 +174,35 @@ pub struct CreatePollRequest {
     pub closes_at: Option<DateTime<Utc>>,
     pub is_public: Option<bool>,
     pub registration_required: Option<bool>,
+    pub require_full_ranking: Option<bool>,
+    pub allow_write_ins: Option<bool>,
+    /// Minimum ballots for the result to be valid
+    pub quorum: Option<i32>,
+    /// `reject` (default) or `collapse`
+    pub skipped_rankings_policy: Option<String>,
+    /// Email language for voters without their own locale (defaults to `en`)
+    pub default_locale: Option<String>,
+    /// `private` (default), `public_after_close` or `public_always`
+    pub results_visibility: Option<String>,
+    /// Fewest candidates each ballot must rank (defaults to one)
+    pub min_rankings: Option<i32>,
+    /// Add a reserved "None of the above" candidate; if it wins, the result is no confidence
+    pub allow_none_of_the_above: Option<bool>,
+    /// Replaces `VOTE`/`ANON` at the start of receipt codes, e.g. `ACME`
+    pub receipt_prefix: Option<String>,
+    /// `majority` (default), or `plurality_after_rounds` to accept the leader without a majority
+    /// once `plurality_round_limit` rounds have been counted; single-winner polls only
+    pub win_condition: Option<String>,
+    /// Round at which `plurality_after_rounds` declares the leader the winner
+    pub plurality_round_limit: Option<i32>,
+    /// Ask anonymous voters on a public poll to pass a CAPTCHA
+    pub require_captcha: Option<bool>,
+    /// IANA time zone the open/close times are shown in, e.g. `Europe/Berlin`
+    pub timezone: Option<String>,
+    /// Seed for random tie-breaks; derived from the poll id when unset
+    pub tie_break_seed: Option<i64>,
+    /// Labels for grouping polls; trimmed and lowercased before they are stored
+    pub tags: Option<Vec<String>>,
     pub candidates: Vec<CreateCandidateRequest>,
 }
 
@@ -44,6 +216,44 @@ pub struct UpdatePollRequest {
     pub closes_at: Option<DateTime<Utc>>,
     pub is_public: Option<bool>,
     pub registration_required: Option<bool>,
+    pub require_full_ranking: Option<bool>,
+    pub allow_write_ins: Option<bool>,
+    /// Minimum ballots for the result to be valid; `null` removes the quorum
+    #[serde(default, deserialize_with = "nullable")]
+    pub quorum: Option<Option<i32>>,
+    /// `reject` (default) or `collapse`
+    pub skipped_rankings_policy: Option<String>,
+    /// Email language for voters without their own locale (defaults to `en`)
+    pub default_locale: Option<String>,
+    /// `private` (default), `public_after_close` or `public_always`
+    pub results_visibility: Option<String>,
+    /// Fewest candidates each ballot must rank; `null` goes back to one
+    #[serde(default, deserialize_with = "nullable")]
+    pub min_rankings: Option<Option<i32>>,
+    /// Add a reserved "None of the above" candidate; if it wins, the result is no confidence
+    pub allow_none_of_the_above: Option<bool>,
+    /// Replaces `VOTE`/`ANON` at the start of receipt codes, e.g. `ACME`; `null` restores them.
+    /// Receipts already issued keep the prefix they were issued with.
+    #[serde(default, deserialize_with = "nullable")]
+    pub receipt_prefix: Option<Option<String>>,
+    /// `majority` (default), or `plurality_after_rounds` to accept the leader without a majority
+    /// once `plurality_round_limit` rounds have been counted; single-winner polls only
+    pub win_condition: Option<String>,
+    /// Round at which `plurality_after_rounds` declares the leader the winner; `null` removes it
+    #[serde(default, deserialize_with = "nullable")]
+    pub plurality_round_limit: Option<Option<i32>>,
+    /// Ask anonymous voters on a public poll to pass a CAPTCHA
+    pub require_captcha: Option<bool>,
+    /// IANA time zone the open/close times are shown in, e.g. `Europe/Berlin`; `null` shows them in UTC
+    #[serde(default, deserialize_with = "nullable")]
+    pub timezone: Option<Option<String>>,
+    /// Seed for random tie-breaks; `null` goes back to deriving it from the poll id
+    #[serde(default, deserialize_with = "nullable")]
+    pub tie_break_seed: Option<Option<i64>>,
+    /// Replaces the poll's tags when present
+    pub tags: Option<Vec<String>>,
+    /// Full desired candidate list. Existing candidates omitted from this list are deleted.
+    pub candidates: Option<Vec<UpsertCandidateRequest>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -58,11 +268,55 @@ pub struct PollResponse {
     pub closes_at: Option<DateTime<Utc>>,
     pub is_public: bool,
     pub registration_required: bool,
+    pub require_full_ranking: bool,
+    pub allow_write_ins: bool,
+    pub quorum: Option<i32>,
+    pub skipped_rankings_policy: String,
+    pub default_locale: String,
+    pub results_visibility: String,
+    pub min_rankings: Option<i32>,
+    pub allow_none_of_the_above: bool,
+    pub receipt_prefix: Option<String>,
+    pub win_condition: String,
+    pub plurality_round_limit: Option<i32>,
+    pub require_captcha: bool,
+    pub timezone: Option<String>,
+    pub tie_break_seed: Option<i64>,
+    /// `open`, or `closed` once the poll has been finalized
+    pub status: String,
+    /// `opens_at`/`closes_at` in the poll's time zone, with its UTC offset at that moment
+    pub opens_at_local: Option<DateTime<FixedOffset>>,
+    pub closes_at_local: Option<DateTime<FixedOffset>>,
+    pub tags: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub candidates: Vec<Candidate>,
 }
 
+impl PollResponse {
+    /// The counting rule for single-winner tabulation; the database only holds valid settings
+    pub fn win_condition(&self) -> WinCondition {
+        WinCondition::from_settings(&self.win_condition, self.plurality_round_limit).unwrap_or(WinCondition::Majority)
+    }
+
+    /// How eliminating tabulations settle ties: a random draw from the organizer's seed,
+    /// or from one derived from the poll id
+    pub fn tie_break(&self) -> TieBreakMethod {
+        match self.tie_break_seed {
+            Some(seed) => TieBreakMethod::Random(seed as u64),
+            None => TieBreakMethod::random_for_poll(self.id),
+        }
+    }
+
+    /// Whether ballots are accepted at `now`: the poll hasn't been closed, and `now` falls
+    /// within its open/close times
+    pub fn is_open_for_voting(&self, now: DateTime<Utc>) -> bool {
+        self.status != "closed"
+            && self.opens_at.is_none_or(|opens| now >= opens)
+            && self.closes_at.is_none_or(|closes| now <= closes)
+    }
+}
+
 #[derive(Debug, FromRow, Serialize)]
 pub struct PollListItem {
     pub id: Uuid,
@@ -73,11 +327,30 @@ pub struct PollListItem {
     pub opens_at: Option<DateTime<Utc>>,
     pub closes_at: Option<DateTime<Utc>>,
     pub is_public: bool,
+    pub tags: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub candidate_count: i64,
     pub vote_count: i64,
 }
 
+/// A public poll open for voting, as listed for discovery; leaves out the owner and settings
+#[derive(Debug, FromRow, Serialize)]
+pub struct PublicPollListItem {
+    pub id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub poll_type: String,
+    pub closes_at: Option<DateTime<Utc>>,
+    pub candidate_count: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PublicPollListQuery {
+    pub page: Option<i32>,
+    pub limit: Option<i32>,
+    pub order: Option<String>, // asc (default, closing soonest first), desc; by close time
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PollListQuery {
     pub page: Option<i32>,
@@ -85,9 +358,253 @@ pub struct PollListQuery {
     pub status: Option<String>, // active, closed, draft
     pub sort: Option<String>,   // created_at, title, closes_at
     pub order: Option<String>,  // asc, desc
+    pub q: Option<String>,      // case-insensitive match on title or description
+    pub tag: Option<String>,    // only polls carrying this tag
+}
+
+/// Aggregate voter and ballot counts for a poll
+#[derive(Debug, FromRow)]
+pub struct PollParticipation {
+    pub invited_voters: i64,
+    pub registered_ballots: i64,
+    pub anonymous_ballots: i64,
+    pub first_ballot_at: Option<DateTime<Utc>>,
+    pub last_ballot_at: Option<DateTime<Utc>>,
+}
+
+/// Number of ballots submitted on a given (UTC) day
+#[derive(Debug, FromRow, Serialize)]
+pub struct DailySubmissions {
+    pub day: NaiveDate,
+    pub ballots: i64,
+}
+
+/// Width of the buckets in a poll's submission series
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubmissionInterval {
+    Hour,
+    Day,
+}
+
+impl SubmissionInterval {
+    /// Voting windows up to this long are bucketed by hour, longer ones by day
+    pub const HOURLY_MAX_SPAN: chrono::Duration = chrono::Duration::days(3);
+
+    /// Hourly buckets for a short voting window, daily for a long one
+    pub fn for_window(opens_at: DateTime<Utc>, closes_at: DateTime<Utc>) -> Self {
+        if closes_at - opens_at <= Self::HOURLY_MAX_SPAN {
+            SubmissionInterval::Hour
+        } else {
+            SubmissionInterval::Day
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SubmissionInterval::Hour => "hour",
+            SubmissionInterval::Day => "day",
+        }
+    }
+}
+
+/// Ballots submitted in one bucket of a poll's submission series
+#[derive(Debug, FromRow)]
+pub struct SubmissionBucket {
+    pub bucket_start: DateTime<Utc>,
+    pub ballots: i64,
+    pub registered_ballots: i64,
+    pub anonymous_ballots: i64,
+}
+
+/// Current version of the poll export document
+pub const POLL_EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// A poll's configuration and candidates as a portable document, without votes or voters
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PollExport {
+    pub format_version: u32,
+    pub title: String,
+    pub description: Option<String>,
+    pub poll_type: String,
+    pub num_winners: i32,
+    pub opens_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+    pub is_public: bool,
+    pub registration_required: bool,
+    pub require_full_ranking: bool,
+    pub allow_write_ins: bool,
+    pub quorum: Option<i32>,
+    pub skipped_rankings_policy: String,
+    pub default_locale: String,
+    pub results_visibility: String,
+    pub min_rankings: Option<i32>,
+    #[serde(default)]
+    pub allow_none_of_the_above: bool,
+    #[serde(default)]
+    pub receipt_prefix: Option<String>,
+    #[serde(default = "default_win_condition")]
+    pub win_condition: String,
+    #[serde(default)]
+    pub plurality_round_limit: Option<i32>,
+    #[serde(default)]
+    pub require_captcha: bool,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub tie_break_seed: Option<i64>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub candidates: Vec<ExportedCandidate>,
+}
+
+// Exports from before win conditions existed were all counted to a majority
+fn default_win_condition() -> String {
+    WinCondition::Majority.name().to_string()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportedCandidate {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub statement: Option<String>,
+    #[serde(default)]
+    pub affiliation: Option<String>,
+    pub image_url: Option<String>,
+}
+
+impl From<PollResponse> for PollExport {
+    fn from(poll: PollResponse) -> Self {
+        Self {
+            format_version: POLL_EXPORT_FORMAT_VERSION,
+            title: poll.title,
+            description: poll.description,
+            poll_type: poll.poll_type,
+            num_winners: poll.num_winners,
+            opens_at: poll.opens_at,
+            closes_at: poll.closes_at,
+            is_public: poll.is_public,
+            registration_required: poll.registration_required,
+            require_full_ranking: poll.require_full_ranking,
+            allow_write_ins: poll.allow_write_ins,
+            quorum: poll.quorum,
+            skipped_rankings_policy: poll.skipped_rankings_policy,
+            default_locale: poll.default_locale,
+            results_visibility: poll.results_visibility,
+            min_rankings: poll.min_rankings,
+            allow_none_of_the_above: poll.allow_none_of_the_above,
+            receipt_prefix: poll.receipt_prefix,
+            win_condition: poll.win_condition,
+            plurality_round_limit: poll.plurality_round_limit,
+            require_captcha: poll.require_captcha,
+            timezone: poll.timezone,
+            tie_break_seed: poll.tie_break_seed,
+            tags: poll.tags,
+            // Write-ins came from voters, so they belong to the votes rather than the setup,
+            // and the reserved "None of the above" candidate comes back with its flag
+            candidates: poll.candidates.into_iter()
+                .filter(|c| !c.is_write_in && !c.is_nota)
+                .map(|c| ExportedCandidate {
+                    name: c.name,
+                    description: c.description,
+                    statement: c.statement,
+                    affiliation: c.affiliation,
+                    image_url: c.image_url,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<PollExport> for CreatePollRequest {
+    fn from(export: PollExport) -> Self {
+        Self {
+            title: export.title,
+            description: export.description,
+            poll_type: Some(export.poll_type),
+            num_winners: Some(export.num_winners),
+            opens_at: export.opens_at,
+            closes_at: export.closes_at,
+            is_public: Some(export.is_public),
+            registration_required: Some(export.registration_required),
+            require_full_ranking: Some(export.require_full_ranking),
+            allow_write_ins: Some(export.allow_write_ins),
+            quorum: export.quorum,
+            skipped_rankings_policy: Some(export.skipped_rankings_policy),
+            default_locale: Some(export.default_locale),
+            results_visibility: Some(export.results_visibility),
+            min_rankings: export.min_rankings,
+            allow_none_of_the_above: Some(export.allow_none_of_the_above),
+            receipt_prefix: export.receipt_prefix,
+            win_condition: Some(export.win_condition),
+            plurality_round_limit: export.plurality_round_limit,
+            require_captcha: Some(export.require_captcha),
+            timezone: export.timezone,
+            tie_break_seed: export.tie_break_seed,
+            tags: Some(export.tags),
+            candidates: export.candidates.into_iter()
+                .map(|c| CreateCandidateRequest {
+                    name: c.name,
+                    description: c.description,
+                    statement: c.statement,
+                    affiliation: c.affiliation,
+                    image_url: c.image_url,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Why `Poll::update` refused to apply a change
+#[derive(Debug, thiserror::Error)]
+pub enum PollUpdateError {
+    #[error("candidates cannot be removed after votes have been cast")]
+    CandidatesLocked,
+    #[error("the schedule and candidates of a closed poll cannot be changed")]
+    PollClosed,
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
 }
 
 impl Poll {
+    /// Combine a poll row with its candidates into an API response
+    pub fn into_response(self, candidates: Vec<Candidate>) -> PollResponse {
+        PollResponse {
+            id: self.id,
+            user_id: self.user_id,
+            title: self.title,
+            description: self.description,
+            poll_type: self.poll_type,
+            num_winners: self.num_winners,
+            opens_at: self.opens_at,
+            closes_at: self.closes_at,
+            is_public: self.is_public,
+            registration_required: self.registration_required,
+            require_full_ranking: self.require_full_ranking,
+            allow_write_ins: self.allow_write_ins,
+            quorum: self.quorum,
+            skipped_rankings_policy: self.skipped_rankings_policy,
+            default_locale: self.default_locale,
+            results_visibility: self.results_visibility,
+            min_rankings: self.min_rankings,
+            allow_none_of_the_above: self.allow_none_of_the_above,
+            receipt_prefix: self.receipt_prefix,
+            win_condition: self.win_condition,
+            plurality_round_limit: self.plurality_round_limit,
+            require_captcha: self.require_captcha,
+            opens_at_local: local_time(self.opens_at, self.timezone.as_deref()),
+            closes_at_local: local_time(self.closes_at, self.timezone.as_deref()),
+            timezone: self.timezone,
+            tie_break_seed: self.tie_break_seed,
+            status: self.status,
+            tags: self.tags,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            candidates,
+        }
+    }
+
     pub async fn create(
         pool: &PgPool,
         user_id: Uuid,
@@ -96,62 +613,79 @@ impl Poll {
         let mut tx = pool.begin().await?;
 
         // Create the poll
-        let poll = sqlx::query_as::<_, Poll>(
+        let mut poll = sqlx::query_as::<_, Poll>(&format!(
             r#"
-            INSERT INTO polls (user_id, title, description, poll_type, num_winners, opens_at, closes_at, is_public, registration_required)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-            RETURNING id, user_id, title, description, poll_type, num_winners, opens_at, closes_at, is_public, registration_required, created_at, updated_at
+            INSERT INTO polls (user_id, title, description, poll_type, num_winners, opens_at, closes_at, is_public, registration_required, require_full_ranking, allow_write_ins, quorum, skipped_rankings_policy, default_locale, results_visibility, min_rankings, allow_none_of_the_above, receipt_prefix, win_condition, plurality_round_limit, require_captcha, timezone, tie_break_seed)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23)
+            RETURNING {}
             "#,
-        )
+            POLL_COLUMNS
+        ))
         .bind(user_id)
         .bind(&req.title)
         .bind(&req.description)
-        .bind(req.poll_type.unwrap_or_else(|| "single_winner".to_string()))
+        .bind(req.poll_type.unwrap_or_else(|| PollType::SingleWinner.as_str().to_string()))
         .bind(req.num_winners.unwrap_or(1))
         .bind(req.opens_at)
         .bind(req.closes_at)
         .bind(req.is_public.unwrap_or(false))
         .bind(req.registration_required.unwrap_or(false))
+        .bind(req.require_full_ranking.unwrap_or(false))
+        .bind(req.allow_write_ins.unwrap_or(false))
+        .bind(req.quorum)
+        .bind(req.skipped_rankings_policy.as_deref().unwrap_or(SkippedRankingsPolicy::Reject.as_str()))
+        .bind(req.default_locale.as_deref().unwrap_or(DEFAULT_LOCALE))
+        .bind(req.results_visibility.as_deref().unwrap_or(ResultsVisibility::Private.as_str()))
+        .bind(req.min_rankings)
+        .bind(req.allow_none_of_the_above.unwrap_or(false))
+        .bind(&req.receipt_prefix)
+        .bind(req.win_condition.as_deref().unwrap_or(WinCondition::Majority.name()))
+        .bind(req.plurality_round_limit)
+        .bind(req.require_captcha.unwrap_or(false))
+        .bind(&req.timezone)
+        .bind(req.tie_break_seed)
         .fetch_one(&mut *tx)
         .await?;
 
         // Create candidates
+        let insert_candidate_sql = format!(
+            r#"
+            INSERT INTO candidates (poll_id, name, slug, description, statement, affiliation, image_url, display_order)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {}
+            "#,
+            CANDIDATE_COLUMNS
+        );
         let mut candidates = Vec::new();
         for (index, candidate_req) in req.candidates.iter().enumerate() {
-            let candidate = sqlx::query_as::<_, Candidate>(
-                r#"
-                INSERT INTO candidates (poll_id, name, description, display_order)
-                VALUES ($1, $2, $3, $4)
-                RETURNING id, poll_id, name, description, display_order, created_at
-                "#,
-            )
-            .bind(poll.id)
-            .bind(&candidate_req.name)
-            .bind(&candidate_req.description)
-            .bind(index as i32 + 1)
-            .fetch_one(&mut *tx)
+            let name = sanitize_candidate_name(&candidate_req.name);
+            let candidate = Candidate::insert_with_unique_slug(&mut tx, poll.id, &name, |slug| {
+                sqlx::query_as::<_, Candidate>(&insert_candidate_sql)
+                    .bind(poll.id)
+                    .bind(&name)
+                    .bind(slug)
+                    .bind(sanitize_candidate_description(candidate_req.description.as_deref()))
+                    .bind(sanitize_candidate_statement(candidate_req.statement.as_deref()))
+                    .bind(sanitize_candidate_affiliation(candidate_req.affiliation.as_deref()))
+                    .bind(&candidate_req.image_url)
+                    .bind(index as i32 + 1)
+            })
             .await?;
 
             candidates.push(candidate);
         }
 
+        if poll.allow_none_of_the_above {
+            candidates.push(Candidate::ensure_nota(&mut tx, poll.id).await?);
+        }
+
+        if let Some(tags) = &req.tags {
+            poll.tags = Self::replace_tags(&mut tx, poll.id, tags).await?;
+        }
+
         tx.commit().await?;
 
-        Ok(PollResponse {
-            id: poll.id,
-            user_id: poll.user_id,
-            title: poll.title,
-            description: poll.description,
-            poll_type: poll.poll_type,
-            num_winners: poll.num_winners,
-            opens_at: poll.opens_at,
-            closes_at: poll.closes_at,
-            is_public: poll.is_public,
-            registration_required: poll.registration_required,
-            created_at: poll.created_at,
-            updated_at: poll.updated_at,
-            candidates,
-        })
+        Ok(poll.into_response(candidates))
     }
 
     pub async fn find_by_id_and_user(
@@ -160,7 +694,7 @@ impl Poll {
         user_id: Uuid,
     ) -> Result<Option<PollResponse>, sqlx::Error> {
         let poll = sqlx::query_as::<_, Poll>(
-            "SELECT id, user_id, title, description, poll_type, num_winners, opens_at, closes_at, is_public, registration_required, created_at, updated_at FROM polls WHERE id = $1 AND user_id = $2"
+            &format!("SELECT {} FROM polls WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL", POLL_COLUMNS)
         )
         .bind(poll_id)
         .bind(user_id)
@@ -170,21 +704,7 @@ impl Poll {
         if let Some(poll) = poll {
             let candidates = Candidate::find_by_poll_id(pool, poll.id).await?;
             
-            Ok(Some(PollResponse {
-                id: poll.id,
-                user_id: poll.user_id,
-                title: poll.title,
-                description: poll.description,
-                poll_type: poll.poll_type,
-                num_winners: poll.num_winners,
-                opens_at: poll.opens_at,
-                closes_at: poll.closes_at,
-                is_public: poll.is_public,
-                registration_required: poll.registration_required,
-                created_at: poll.created_at,
-                updated_at: poll.updated_at,
-                candidates,
-            }))
+            Ok(Some(poll.into_response(candidates)))
         } else {
             Ok(None)
         }
@@ -192,7 +712,7 @@ impl Poll {
 
     pub async fn find_by_id(pool: &PgPool, poll_id: Uuid) -> Result<Option<PollResponse>, sqlx::Error> {
         let poll = sqlx::query_as::<_, Poll>(
-            "SELECT id, user_id, title, description, poll_type, num_winners, opens_at, closes_at, is_public, registration_required, created_at, updated_at FROM polls WHERE id = $1"
+            &format!("SELECT {} FROM polls WHERE id = $1 AND deleted_at IS NULL", POLL_COLUMNS)
         )
         .bind(poll_id)
         .fetch_optional(pool)
@@ -201,21 +721,7 @@ impl Poll {
         if let Some(poll) = poll {
             let candidates = Candidate::find_by_poll_id(pool, poll.id).await?;
             
-            Ok(Some(PollResponse {
-                id: poll.id,
-                user_id: poll.user_id,
-                title: poll.title,
-                description: poll.description,
-                poll_type: poll.poll_type,
-                num_winners: poll.num_winners,
-                opens_at: poll.opens_at,
-                closes_at: poll.closes_at,
-                is_public: poll.is_public,
-                registration_required: poll.registration_required,
-                created_at: poll.created_at,
-                updated_at: poll.updated_at,
-                candidates,
-            }))
+            Ok(Some(poll.into_response(candidates)))
         } else {
             Ok(None)
         }
@@ -228,26 +734,47 @@ impl Poll {
     ) -> Result<(Vec<PollListItem>, i64), sqlx::Error> {
         let page = query.page.unwrap_or(1).max(1);
         let limit = query.limit.unwrap_or(20).min(100);
-        let offset = (page - 1) * limit;
+        let offset = (i64::from(page) - 1) * i64::from(limit);
 
-        let mut where_clauses = vec!["p.user_id = $1".to_string()];
+        let mut where_clauses = vec!["p.user_id = $1".to_string(), "p.deleted_at IS NULL".to_string()];
 
         // Add status filter
         if let Some(status) = &query.status {
             match status.as_str() {
                 "active" => {
-                    where_clauses.push(format!("(p.opens_at IS NULL OR p.opens_at <= NOW()) AND (p.closes_at IS NULL OR p.closes_at > NOW())"));
+                    where_clauses.push("(p.opens_at IS NULL OR p.opens_at <= NOW()) AND (p.closes_at IS NULL OR p.closes_at > NOW())".to_string());
                 }
                 "closed" => {
-                    where_clauses.push(format!("p.closes_at IS NOT NULL AND p.closes_at <= NOW()"));
+                    where_clauses.push("p.closes_at IS NOT NULL AND p.closes_at <= NOW()".to_string());
                 }
                 "draft" => {
-                    where_clauses.push(format!("p.opens_at IS NOT NULL AND p.opens_at > NOW()"));
+                    where_clauses.push("p.opens_at IS NOT NULL AND p.opens_at > NOW()".to_string());
                 }
                 _ => {} // Invalid status, ignore
             }
         }
 
+        // Add text search, escaping LIKE wildcards so they match literally
+        let search_pattern = query.q.as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .map(|q| format!("%{}%", q.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")));
+        if search_pattern.is_some() {
+            where_clauses.push("(p.title ILIKE $2 OR p.description ILIKE $2)".to_string());
+        }
+
+        // Add tag filter, normalized the same way tags are stored
+        let tag = query.tag.as_deref()
+            .map(|t| t.trim().to_lowercase())
+            .filter(|t| !t.is_empty());
+        if tag.is_some() {
+            let param = if search_pattern.is_some() { 3 } else { 2 };
+            where_clauses.push(format!(
+                "EXISTS (SELECT 1 FROM poll_tags t WHERE t.poll_id = p.id AND t.tag = ${})",
+                param
+            ));
+        }
+
         let where_clause = where_clauses.join(" AND ");
 
         // Build ORDER BY clause
@@ -272,6 +799,7 @@ impl Poll {
                 p.opens_at,
                 p.closes_at,
                 p.is_public,
+                ARRAY(SELECT tag FROM poll_tags t WHERE t.poll_id = p.id ORDER BY tag) as tags,
                 p.created_at,
                 COUNT(DISTINCT c.id) as candidate_count,
                 COUNT(DISTINCT b.id) as vote_count
@@ -286,18 +814,68 @@ impl Poll {
             where_clause, sort_field, order, limit, offset
         );
 
-        let polls = sqlx::query_as::<_, PollListItem>(&query_sql)
-            .bind(user_id)
-            .fetch_all(pool)
-            .await?;
+        let mut polls_query = sqlx::query_as::<_, PollListItem>(&query_sql).bind(user_id);
+        if let Some(pattern) = &search_pattern {
+            polls_query = polls_query.bind(pattern);
+        }
+        if let Some(tag) = &tag {
+            polls_query = polls_query.bind(tag);
+        }
+        let polls = polls_query.fetch_all(pool).await?;
 
         // Get total count
         let count_query = format!(
             "SELECT COUNT(*) FROM polls p WHERE {}",
             where_clause
         );
-        let total_count: (i64,) = sqlx::query_as(&count_query)
-            .bind(user_id)
+        let mut count_query = sqlx::query_as(&count_query).bind(user_id);
+        if let Some(pattern) = &search_pattern {
+            count_query = count_query.bind(pattern);
+        }
+        if let Some(tag) = &tag {
+            count_query = count_query.bind(tag);
+        }
+        let total_count: (i64,) = count_query.fetch_one(pool).await?;
+
+        Ok((polls, total_count.0))
+    }
+
+    /// Public polls currently accepting votes, by close time; polls without one come last
+    pub async fn list_public(
+        pool: &PgPool,
+        query: &PublicPollListQuery,
+    ) -> Result<(Vec<PublicPollListItem>, i64), sqlx::Error> {
+        let page = query.page.unwrap_or(1).max(1);
+        let limit = query.limit.unwrap_or(20).clamp(1, 100);
+        let offset = (i64::from(page) - 1) * i64::from(limit);
+
+        let where_clause = "p.is_public = true AND p.deleted_at IS NULL AND p.status = 'open' \
+            AND (p.opens_at IS NULL OR p.opens_at <= NOW()) AND (p.closes_at IS NULL OR p.closes_at > NOW())";
+        let order = match query.order.as_deref() {
+            Some("desc") => "DESC",
+            _ => "ASC", // default
+        };
+
+        let polls = sqlx::query_as::<_, PublicPollListItem>(&format!(
+            r#"
+            SELECT
+                p.id,
+                p.title,
+                p.description,
+                p.poll_type,
+                p.closes_at,
+                (SELECT COUNT(*) FROM candidates c WHERE c.poll_id = p.id AND NOT c.is_write_in AND NOT c.is_nota) as candidate_count
+            FROM polls p
+            WHERE {}
+            ORDER BY p.closes_at {} NULLS LAST, p.id
+            LIMIT {} OFFSET {}
+            "#,
+            where_clause, order, limit, offset
+        ))
+        .fetch_all(pool)
+        .await?;
+
+        let total_count: (i64,) = sqlx::query_as(&format!("SELECT COUNT(*) FROM polls p WHERE {}", where_clause))
             .fetch_one(pool)
             .await?;
 
@@ -309,14 +887,17 @@ impl Poll {
         poll_id: Uuid,
         user_id: Uuid,
         req: UpdatePollRequest,
-    ) -> Result<Option<PollResponse>, sqlx::Error> {
-        // Get the current poll first
+    ) -> Result<Option<PollResponse>, PollUpdateError> {
+        let mut tx = pool.begin().await?;
+
+        // Lock the poll row: inserting a ballot takes a key-share lock on it, so no vote
+        // can land between the check below and the candidate changes
         let current_poll = sqlx::query_as::<_, Poll>(
-            "SELECT id, user_id, title, description, poll_type, num_winners, opens_at, closes_at, is_public, registration_required, created_at, updated_at FROM polls WHERE id = $1 AND user_id = $2"
+            &format!("SELECT {} FROM polls WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL FOR UPDATE", POLL_COLUMNS)
         )
         .bind(poll_id)
         .bind(user_id)
-        .fetch_optional(pool)
+        .fetch_optional(&mut *tx)
         .await?;
 
         let current_poll = match current_poll {
@@ -324,6 +905,19 @@ impl Poll {
             None => return Ok(None),
         };
 
+        // A closed poll's declared result stands; moving its schedule or changing who
+        // was on the ballot would contradict it
+        let changes_candidates = req.candidates.is_some()
+            || req.allow_none_of_the_above.is_some_and(|nota| nota != current_poll.allow_none_of_the_above);
+        if current_poll.status == "closed" && (req.opens_at.is_some() || req.closes_at.is_some() || changes_candidates) {
+            return Err(PollUpdateError::PollClosed);
+        }
+
+        let has_votes: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM ballots WHERE poll_id = $1)")
+            .bind(poll_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
         // Use current values as defaults for fields not being updated
         let title = req.title.unwrap_or(current_poll.title);
         let description = req.description.or(current_poll.description);
@@ -331,48 +925,313 @@ impl Poll {
         let closes_at = req.closes_at.or(current_poll.closes_at);
         let is_public = req.is_public.unwrap_or(current_poll.is_public);
         let registration_required = req.registration_required.unwrap_or(current_poll.registration_required);
+        let require_full_ranking = req.require_full_ranking.unwrap_or(current_poll.require_full_ranking);
+        let allow_write_ins = req.allow_write_ins.unwrap_or(current_poll.allow_write_ins);
+        let quorum = req.quorum.unwrap_or(current_poll.quorum);
+        let skipped_rankings_policy = req.skipped_rankings_policy.unwrap_or(current_poll.skipped_rankings_policy);
+        let default_locale = req.default_locale.unwrap_or(current_poll.default_locale);
+        let results_visibility = req.results_visibility.unwrap_or(current_poll.results_visibility);
+        let min_rankings = req.min_rankings.unwrap_or(current_poll.min_rankings);
+        let had_nota = current_poll.allow_none_of_the_above;
+        let allow_none_of_the_above = req.allow_none_of_the_above.unwrap_or(had_nota);
+        let candidates_replaced = req.candidates.is_some();
+        let receipt_prefix = req.receipt_prefix.unwrap_or(current_poll.receipt_prefix);
+        let win_condition = req.win_condition.unwrap_or(current_poll.win_condition);
+        let plurality_round_limit = req.plurality_round_limit.unwrap_or(current_poll.plurality_round_limit);
+        let require_captcha = req.require_captcha.unwrap_or(current_poll.require_captcha);
+        let timezone = req.timezone.unwrap_or(current_poll.timezone);
+        let tie_break_seed = req.tie_break_seed.unwrap_or(current_poll.tie_break_seed);
 
         // Update the poll
-        let poll = sqlx::query_as::<_, Poll>(
+        let mut poll = sqlx::query_as::<_, Poll>(&format!(
             r#"
             UPDATE polls 
             SET title = $1, description = $2, opens_at = $3, closes_at = $4, 
-                is_public = $5, registration_required = $6, updated_at = CURRENT_TIMESTAMP
-            WHERE id = $7 AND user_id = $8
-            RETURNING id, user_id, title, description, poll_type, num_winners, opens_at, closes_at, is_public, registration_required, created_at, updated_at
+                is_public = $5, registration_required = $6, require_full_ranking = $7,
+                allow_write_ins = $8, quorum = $9, skipped_rankings_policy = $10,
+                default_locale = $11, results_visibility = $12, min_rankings = $13,
+                allow_none_of_the_above = $14, receipt_prefix = $15,
+                win_condition = $16, plurality_round_limit = $17, require_captcha = $18,
+                timezone = $19, tie_break_seed = $20, updated_at = CURRENT_TIMESTAMP
+            WHERE id = $21 AND user_id = $22
+            RETURNING {}
             "#,
-        )
+            POLL_COLUMNS
+        ))
         .bind(title)
         .bind(description)
         .bind(opens_at)
         .bind(closes_at)
         .bind(is_public)
         .bind(registration_required)
+        .bind(require_full_ranking)
+        .bind(allow_write_ins)
+        .bind(quorum)
+        .bind(skipped_rankings_policy)
+        .bind(default_locale)
+        .bind(results_visibility)
+        .bind(min_rankings)
+        .bind(allow_none_of_the_above)
+        .bind(receipt_prefix)
+        .bind(win_condition)
+        .bind(plurality_round_limit)
+        .bind(require_captcha)
+        .bind(timezone)
+        .bind(tie_break_seed)
         .bind(poll_id)
         .bind(user_id)
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
         .await?;
 
+        if let Some(tags) = &req.tags {
+            poll.tags = Self::replace_tags(&mut tx, poll.id, tags).await?;
+        }
+
+        // Diff candidates against the requested list, if one was provided
+        if let Some(candidate_reqs) = req.candidates {
+            let kept_ids: Vec<Uuid> = candidate_reqs.iter().filter_map(|c| c.id).collect();
+
+            // The reserved "None of the above" candidate follows the poll setting instead
+            let removed = sqlx::query("DELETE FROM candidates WHERE poll_id = $1 AND NOT is_nota AND NOT (id = ANY($2))")
+                .bind(poll.id)
+                .bind(&kept_ids)
+                .execute(&mut *tx)
+                .await?;
+
+            // Candidates cannot be removed once ballots reference them
+            if removed.rows_affected() > 0 && has_votes {
+                return Err(PollUpdateError::CandidatesLocked);
+            }
+
+            for (index, candidate_req) in candidate_reqs.iter().enumerate() {
+                let name = sanitize_candidate_name(&candidate_req.name);
+                let description = sanitize_candidate_description(candidate_req.description.as_deref());
+                let statement = sanitize_candidate_statement(candidate_req.statement.as_deref());
+                let affiliation = sanitize_candidate_affiliation(candidate_req.affiliation.as_deref());
+                match candidate_req.id {
+                    Some(candidate_id) => {
+                        sqlx::query(
+                            "UPDATE candidates SET name = $1, description = $2, statement = $3, affiliation = $4, image_url = $5, display_order = $6 WHERE id = $7 AND poll_id = $8"
+                        )
+                        .bind(name)
+                        .bind(description)
+                        .bind(statement)
+                        .bind(affiliation)
+                        .bind(&candidate_req.image_url)
+                        .bind(index as i32 + 1)
+                        .bind(candidate_id)
+                        .bind(poll.id)
+                        .execute(&mut *tx)
+                        .await?;
+                    }
+                    None => {
+                        let sql = format!(
+                            "INSERT INTO candidates (poll_id, name, slug, description, statement, affiliation, image_url, display_order) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING {}",
+                            CANDIDATE_COLUMNS
+                        );
+                        Candidate::insert_with_unique_slug(&mut tx, poll.id, &name, |slug| {
+                            sqlx::query_as::<_, Candidate>(&sql)
+                                .bind(poll.id)
+                                .bind(&name)
+                                .bind(slug)
+                                .bind(&description)
+                                .bind(&statement)
+                                .bind(&affiliation)
+                                .bind(&candidate_req.image_url)
+                                .bind(index as i32 + 1)
+                        })
+                        .await?;
+                    }
+                }
+            }
+        }
+
+        // Keep "None of the above" last when it is first enabled or the list is rewritten
+        if poll.allow_none_of_the_above && (!had_nota || candidates_replaced) {
+            Candidate::ensure_nota(&mut tx, poll.id).await?;
+        } else if !poll.allow_none_of_the_above && Candidate::remove_nota(&mut tx, poll.id).await? && has_votes {
+            return Err(PollUpdateError::CandidatesLocked);
+        }
+
+        tx.commit().await?;
+
         let candidates = Candidate::find_by_poll_id(pool, poll.id).await?;
         
-        Ok(Some(PollResponse {
-            id: poll.id,
-            user_id: poll.user_id,
-            title: poll.title,
-            description: poll.description,
-            poll_type: poll.poll_type,
-            num_winners: poll.num_winners,
-            opens_at: poll.opens_at,
-            closes_at: poll.closes_at,
-            is_public: poll.is_public,
-            registration_required: poll.registration_required,
-            created_at: poll.created_at,
-            updated_at: poll.updated_at,
-            candidates,
-        }))
+        Ok(Some(poll.into_response(candidates)))
     }
 
+    /// Replace a poll's tags with the normalized `tags`, returning what was stored
+    pub(crate) async fn replace_tags(
+        conn: &mut sqlx::PgConnection,
+        poll_id: Uuid,
+        tags: &[String],
+    ) -> Result<Vec<String>, sqlx::Error> {
+        let tags = normalize_tags(tags);
+
+        sqlx::query("DELETE FROM poll_tags WHERE poll_id = $1")
+            .bind(poll_id)
+            .execute(&mut *conn)
+            .await?;
+
+        sqlx::query("INSERT INTO poll_tags (poll_id, tag) SELECT $1, UNNEST($2::text[])")
+            .bind(poll_id)
+            .bind(&tags)
+            .execute(&mut *conn)
+            .await?;
+
+        Ok(tags)
+    }
+
+    /// Delete every ballot, ranking, draft and voter-added write-in in a poll, mark its voters
+    /// as not having voted and reopen it if its close time has not passed, keeping the official
+    /// candidates and voters. Returns the number of ballots removed, or `None` if `user_id` does not own the poll.
+    pub async fn reset_votes(pool: &PgPool, poll_id: Uuid, user_id: Uuid) -> Result<Option<u64>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        // Cached final results would describe ballots that no longer exist. A poll past its
+        // close time stays closed, or the closing job would close it again straight away
+        let owned = sqlx::query(
+            r#"
+            UPDATE polls
+            SET final_results = NULL,
+                status = CASE WHEN closes_at IS NULL OR closes_at > NOW() THEN 'open' ELSE status END,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL
+            "#
+        )
+        .bind(poll_id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if owned.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        sqlx::query("DELETE FROM rankings WHERE ballot_id IN (SELECT id FROM ballots WHERE poll_id = $1)")
+            .bind(poll_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let ballots = sqlx::query("DELETE FROM ballots WHERE poll_id = $1")
+            .bind(poll_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("UPDATE voters SET voted_at = NULL WHERE poll_id = $1")
+            .bind(poll_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM ballot_drafts WHERE voter_id IN (SELECT id FROM voters WHERE poll_id = $1)")
+            .bind(poll_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // Write-ins only exist because a ballot named them
+        sqlx::query("DELETE FROM candidates WHERE poll_id = $1 AND is_write_in")
+            .bind(poll_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // The closure record declared results from the deleted ballots
+        sqlx::query("DELETE FROM poll_closures WHERE poll_id = $1")
+            .bind(poll_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(ballots.rows_affected()))
+    }
+
+    /// Count invited voters and cast ballots for a poll
+    pub async fn participation(pool: &PgPool, poll_id: Uuid) -> Result<PollParticipation, sqlx::Error> {
+        sqlx::query_as::<_, PollParticipation>(
+            r#"
+            SELECT
+                (SELECT COUNT(*) FROM voters WHERE poll_id = $1) as invited_voters,
+                COUNT(b.id) FILTER (WHERE b.voter_id IS NOT NULL) as registered_ballots,
+                COUNT(b.id) FILTER (WHERE b.voter_id IS NULL) as anonymous_ballots,
+                MIN(b.submitted_at) as first_ballot_at,
+                MAX(b.submitted_at) as last_ballot_at
+            FROM ballots b
+            WHERE b.poll_id = $1
+            "#
+        )
+        .bind(poll_id)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Ballot submissions per day, oldest first
+    pub async fn daily_submissions(pool: &PgPool, poll_id: Uuid) -> Result<Vec<DailySubmissions>, sqlx::Error> {
+        sqlx::query_as::<_, DailySubmissions>(
+            r#"
+            SELECT (submitted_at AT TIME ZONE 'UTC')::date as day, COUNT(*) as ballots
+            FROM ballots
+            WHERE poll_id = $1
+            GROUP BY day
+            ORDER BY day
+            "#
+        )
+        .bind(poll_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Ballot submissions per UTC hour or day from the first ballot to the last, oldest first.
+    /// Buckets without ballots are included so the series has no gaps.
+    pub async fn submission_series(
+        pool: &PgPool,
+        poll_id: Uuid,
+        interval: SubmissionInterval,
+    ) -> Result<Vec<SubmissionBucket>, sqlx::Error> {
+        sqlx::query_as::<_, SubmissionBucket>(
+            r#"
+            WITH counts AS (
+                SELECT date_trunc($2, submitted_at AT TIME ZONE 'UTC') AT TIME ZONE 'UTC' as bucket_start,
+                       COUNT(*) as ballots,
+                       COUNT(*) FILTER (WHERE voter_id IS NOT NULL) as registered_ballots,
+                       COUNT(*) FILTER (WHERE voter_id IS NULL) as anonymous_ballots
+                FROM ballots
+                WHERE poll_id = $1
+                GROUP BY 1
+            )
+            SELECT series.bucket_start,
+                   COALESCE(counts.ballots, 0) as ballots,
+                   COALESCE(counts.registered_ballots, 0) as registered_ballots,
+                   COALESCE(counts.anonymous_ballots, 0) as anonymous_ballots
+            FROM generate_series(
+                (SELECT MIN(bucket_start) FROM counts),
+                (SELECT MAX(bucket_start) FROM counts),
+                ('1 ' || $2)::interval
+            ) as series(bucket_start)
+            LEFT JOIN counts USING (bucket_start)
+            ORDER BY series.bucket_start
+            "#
+        )
+        .bind(poll_id)
+        .bind(interval.as_str())
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Soft-delete a poll; it disappears from lookups and listings until restored
     pub async fn delete(pool: &PgPool, poll_id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE polls SET deleted_at = CURRENT_TIMESTAMP WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL"
+        )
+        .bind(poll_id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Remove a poll (deleted or not) along with its candidates, voters and ballots
+    pub async fn delete_permanently(pool: &PgPool, poll_id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error> {
         let result = sqlx::query("DELETE FROM polls WHERE id = $1 AND user_id = $2")
             .bind(poll_id)
             .bind(user_id)
@@ -381,4 +1240,145 @@ impl Poll {
 
         Ok(result.rows_affected() > 0)
     }
+
+    /// Remove every poll a user owns, deleted or not. Candidates, voters and ballots
+    /// go with them through the schema's cascades.
+    pub(crate) async fn delete_all_for_user(conn: &mut sqlx::PgConnection, user_id: Uuid) -> Result<u64, sqlx::Error> {
+        // Ad impressions point at voters without cascading, so clear them first
+        sqlx::query(
+            "DELETE FROM ad_impressions WHERE voter_id IN (SELECT v.id FROM voters v JOIN polls p ON p.id = v.poll_id WHERE p.user_id = $1)"
+        )
+        .bind(user_id)
+        .execute(&mut *conn)
+        .await?;
+
+        let result = sqlx::query("DELETE FROM polls WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *conn)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Open polls whose close time has passed and that still need finalizing
+    pub async fn find_due_for_close(pool: &PgPool) -> Result<Vec<Uuid>, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT id FROM polls WHERE status = 'open' AND closes_at <= NOW() AND deleted_at IS NULL ORDER BY closes_at"
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Mark a poll closed, cache its final results and record why it closed in
+    /// `poll_closures`. Returns `false` if it was already closed, so concurrent
+    /// finalizers only act once.
+    pub async fn mark_closed(
+        pool: &PgPool,
+        poll_id: Uuid,
+        close_reason: CloseReason,
+        final_results: Option<serde_json::Value>,
+    ) -> Result<bool, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        let result = sqlx::query(
+            "UPDATE polls SET status = 'closed', final_results = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2 AND status = 'open'"
+        )
+        .bind(&final_results)
+        .bind(poll_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            "INSERT INTO poll_closures (poll_id, close_reason, results) VALUES ($1, $2, $3) ON CONFLICT (poll_id) DO NOTHING"
+        )
+        .bind(poll_id)
+        .bind(close_reason.as_str())
+        .bind(&final_results)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// End voting on an open poll now by pulling `closes_at` forward (a close time already
+    /// in the past is kept). Returns `false` if the poll is missing, not owned or already closed.
+    pub async fn close_now(pool: &PgPool, poll_id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE polls
+            SET closes_at = LEAST(COALESCE(closes_at, NOW()), NOW()), updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1 AND user_id = $2 AND status = 'open' AND deleted_at IS NULL
+            "#
+        )
+        .bind(poll_id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Hand a poll to another user, recording the change in the transfer audit log.
+    /// Returns `false` if `from_user_id` does not own the poll.
+    pub async fn transfer_ownership(
+        pool: &PgPool,
+        poll_id: Uuid,
+        from_user_id: Uuid,
+        to_user_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        let result = sqlx::query(
+            "UPDATE polls SET user_id = $1, updated_at = CURRENT_TIMESTAMP WHERE id = $2 AND user_id = $3 AND deleted_at IS NULL"
+        )
+        .bind(to_user_id)
+        .bind(poll_id)
+        .bind(from_user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query("INSERT INTO poll_ownership_transfers (poll_id, from_user_id, to_user_id) VALUES ($1, $2, $3)")
+            .bind(poll_id)
+            .bind(from_user_id)
+            .bind(to_user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// Undo a soft delete. Returns `None` if the poll does not exist or is not deleted.
+    pub async fn restore(pool: &PgPool, poll_id: Uuid, user_id: Uuid) -> Result<Option<PollResponse>, sqlx::Error> {
+        let poll = sqlx::query_as::<_, Poll>(&format!(
+            r#"
+            UPDATE polls
+            SET deleted_at = NULL, updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1 AND user_id = $2 AND deleted_at IS NOT NULL
+            RETURNING {}
+            "#,
+            POLL_COLUMNS
+        ))
+        .bind(poll_id)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+        match poll {
+            Some(poll) => {
+                let candidates = Candidate::find_by_poll_id(pool, poll.id).await?;
+                Ok(Some(poll.into_response(candidates)))
+            }
+            None => Ok(None),
+        }
+    }
 } 
\ No newline at end of file
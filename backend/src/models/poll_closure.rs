@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+/// Why a poll stopped taking ballots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseReason {
+    /// Its close time passed
+    Scheduled,
+    /// Its owner closed it early
+    Manual,
+    /// It closed with too few ballots to meet its quorum
+    QuorumFailed,
+}
+
+impl CloseReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CloseReason::Scheduled => "scheduled",
+            CloseReason::Manual => "manual",
+            CloseReason::QuorumFailed => "quorum_failed",
+        }
+    }
+}
+
+/// How a poll closed and the results it declared at that moment. Written once
+/// and never updated, so later edits to the poll can't change the outcome.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct PollClosure {
+    pub poll_id: Uuid,
+    pub close_reason: String,
+    /// Final results as counted at close, or `None` if the poll couldn't be tabulated
+    pub results: Option<Value>,
+    pub closed_at: DateTime<Utc>,
+}
+
+impl PollClosure {
+    pub async fn find(pool: &PgPool, poll_id: Uuid) -> Result<Option<PollClosure>, sqlx::Error> {
+        sqlx::query_as::<_, PollClosure>(
+            "SELECT poll_id, close_reason, results, closed_at FROM poll_closures WHERE poll_id = $1"
+        )
+        .bind(poll_id)
+        .fetch_optional(pool)
+        .await
+    }
+}
@@ -0,0 +1,117 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+const RESULT_VERSION_COLUMNS: &str = "poll_id, version, settings, results, computed_at, source_etag";
+
+/// A recorded tabulation of a poll. Versions count up from 1 per poll.
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct ResultVersion {
+    pub poll_id: Uuid,
+    pub version: i32,
+    pub settings: Value,
+    pub results: Value,
+    pub computed_at: DateTime<Utc>,
+    /// Fingerprint of the poll and ballots the version was last confirmed against
+    #[serde(skip_serializing)]
+    pub source_etag: Option<String>,
+}
+
+/// A version as listed, without its full results
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct ResultVersionSummary {
+    pub version: i32,
+    pub settings: Value,
+    pub outcome: Option<String>,
+    pub total_votes: Option<i64>,
+    pub computed_at: DateTime<Utc>,
+}
+
+// Results differ in their timestamp on every count, so compare everything else
+fn same_results(a: &Value, b: &Value) -> bool {
+    let strip = |value: &Value| {
+        let mut value = value.clone();
+        if let Some(object) = value.as_object_mut() {
+            object.remove("computed_at");
+        }
+        value
+    };
+    strip(a) == strip(b)
+}
+
+impl ResultVersion {
+    /// Store a tabulation as the poll's next version, unless the latest version was counted
+    /// from the same inputs (`source_etag`) or matches it in both settings and results.
+    /// Takes no locks: if a concurrent count claims the next version number first, its
+    /// version stands and this one is dropped.
+    pub async fn record(pool: &PgPool, poll_id: Uuid, source_etag: &str, settings: &Value, results: &Value) -> Result<(), sqlx::Error> {
+        let latest = sqlx::query_as::<_, ResultVersion>(&format!(
+            "SELECT {} FROM poll_result_versions WHERE poll_id = $1 ORDER BY version DESC LIMIT 1",
+            RESULT_VERSION_COLUMNS
+        ))
+        .bind(poll_id)
+        .fetch_optional(pool)
+        .await?;
+
+        if let Some(latest) = &latest {
+            if latest.source_etag.as_deref() == Some(source_etag) {
+                return Ok(());
+            }
+            if latest.settings == *settings && same_results(&latest.results, results) {
+                // Remember the new inputs so the next count can skip the comparison
+                sqlx::query("UPDATE poll_result_versions SET source_etag = $1 WHERE poll_id = $2 AND version = $3")
+                    .bind(source_etag)
+                    .bind(poll_id)
+                    .bind(latest.version)
+                    .execute(pool)
+                    .await?;
+                return Ok(());
+            }
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO poll_result_versions (poll_id, version, settings, results, source_etag)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (poll_id, version) DO NOTHING
+            "#,
+        )
+        .bind(poll_id)
+        .bind(latest.map_or(1, |latest| latest.version + 1))
+        .bind(settings)
+        .bind(results)
+        .bind(source_etag)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// All of a poll's versions, oldest first
+    pub async fn list_for_poll(pool: &PgPool, poll_id: Uuid) -> Result<Vec<ResultVersionSummary>, sqlx::Error> {
+        sqlx::query_as::<_, ResultVersionSummary>(
+            r#"
+            SELECT version, settings, results->>'outcome' AS outcome, (results->>'total_votes')::BIGINT AS total_votes, computed_at
+            FROM poll_result_versions
+            WHERE poll_id = $1
+            ORDER BY version
+            "#,
+        )
+        .bind(poll_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find(pool: &PgPool, poll_id: Uuid, version: i32) -> Result<Option<ResultVersion>, sqlx::Error> {
+        sqlx::query_as::<_, ResultVersion>(&format!(
+            "SELECT {} FROM poll_result_versions WHERE poll_id = $1 AND version = $2",
+            RESULT_VERSION_COLUMNS
+        ))
+        .bind(poll_id)
+        .bind(version)
+        .fetch_optional(pool)
+        .await
+    }
+}
@@ -3,6 +3,11 @@ use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, PgPool};
 use uuid::Uuid;
 
+use crate::models::poll::Poll;
+
+/// Role for operators who may act across every poll, not just their own
+pub const ADMIN_ROLE: &str = "admin";
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct User {
     pub id: Uuid,
@@ -12,6 +17,7 @@ pub struct User {
     pub name: Option<String>,
     pub role: String,
     pub email_verified: bool,
+    pub last_login_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -29,6 +35,13 @@ pub struct LoginRequest {
     pub password: String,
 }
 
+/// Profile changes; omitted fields are left as they are
+#[derive(Debug, Deserialize)]
+pub struct UpdateProfileRequest {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct UserResponse {
     pub id: Uuid,
@@ -36,6 +49,7 @@ pub struct UserResponse {
     pub name: Option<String>,
     pub role: String,
     pub email_verified: bool,
+    pub last_login_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -47,6 +61,7 @@ impl From<User> for UserResponse {
             name: user.name,
             role: user.role,
             email_verified: user.email_verified,
+            last_login_at: user.last_login_at,
             created_at: user.created_at,
         }
     }
@@ -58,7 +73,7 @@ impl User {
             r#"
             INSERT INTO users (email, password_hash, name, role)
             VALUES ($1, $2, $3, 'pollster')
-            RETURNING id, email, password_hash, name, role, email_verified, created_at, updated_at
+            RETURNING id, email, password_hash, name, role, email_verified, last_login_at, created_at, updated_at
             "#,
         )
         .bind(req.email)
@@ -72,7 +87,7 @@ impl User {
 
     pub async fn find_by_email(pool: &PgPool, email: &str) -> Result<Option<User>, sqlx::Error> {
         let user = sqlx::query_as::<_, User>(
-            "SELECT id, email, password_hash, name, role, email_verified, created_at, updated_at FROM users WHERE email = $1"
+            "SELECT id, email, password_hash, name, role, email_verified, last_login_at, created_at, updated_at FROM users WHERE email = $1"
         )
         .bind(email)
         .fetch_optional(pool)
@@ -81,9 +96,13 @@ impl User {
         Ok(user)
     }
 
+    pub fn is_admin(&self) -> bool {
+        self.role == ADMIN_ROLE
+    }
+
     pub async fn find_by_id(pool: &PgPool, id: Uuid) -> Result<Option<User>, sqlx::Error> {
         let user = sqlx::query_as::<_, User>(
-            "SELECT id, email, password_hash, name, role, email_verified, created_at, updated_at FROM users WHERE id = $1"
+            "SELECT id, email, password_hash, name, role, email_verified, last_login_at, created_at, updated_at FROM users WHERE id = $1"
         )
         .bind(id)
         .fetch_optional(pool)
@@ -100,6 +119,36 @@ impl User {
         Ok(())
     }
 
+    /// Stamp the user's last sign-in as now, returning the new timestamp
+    pub async fn record_login(pool: &PgPool, user_id: Uuid) -> Result<DateTime<Utc>, sqlx::Error> {
+        sqlx::query_scalar("UPDATE users SET last_login_at = NOW() WHERE id = $1 RETURNING last_login_at")
+            .bind(user_id)
+            .fetch_one(pool)
+            .await
+    }
+
+    pub async fn update_profile(
+        pool: &PgPool,
+        user_id: Uuid,
+        name: Option<&str>,
+        email: &str,
+        email_verified: bool,
+    ) -> Result<User, sqlx::Error> {
+        sqlx::query_as::<_, User>(
+            r#"
+            UPDATE users SET name = $1, email = $2, email_verified = $3, updated_at = NOW()
+            WHERE id = $4
+            RETURNING id, email, password_hash, name, role, email_verified, last_login_at, created_at, updated_at
+            "#,
+        )
+        .bind(name)
+        .bind(email)
+        .bind(email_verified)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await
+    }
+
     pub async fn update_password(pool: &PgPool, user_id: Uuid, password_hash: &str) -> Result<(), sqlx::Error> {
         sqlx::query("UPDATE users SET password_hash = $1, updated_at = NOW() WHERE id = $2")
             .bind(password_hash)
@@ -108,4 +157,25 @@ impl User {
             .await?;
         Ok(())
     }
-} 
\ No newline at end of file
+
+    /// Delete the user along with every poll they own, all in one transaction
+    pub async fn delete_with_data(pool: &PgPool, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        Poll::delete_all_for_user(&mut tx, user_id).await?;
+
+        // Transfers of polls the user handed on still name them
+        sqlx::query("DELETE FROM poll_ownership_transfers WHERE from_user_id = $1 OR to_user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let result = sqlx::query("DELETE FROM users WHERE id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
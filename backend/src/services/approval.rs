@@ -0,0 +1,184 @@
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use super::rcv::{count_spoiled, order_by_votes, Ballot, Candidate, RcvResult, Round, TabulationError};
+
+#[derive(Debug, Clone)]
+pub struct ApprovalResult {
+    pub approvals: HashMap<Uuid, f64>,
+    pub winners: Vec<Uuid>,
+    pub total_ballots: usize,
+    pub spoiled_ballots: usize,
+}
+
+impl ApprovalResult {
+    /// Express the count as a single round so it fits the RCV results shape.
+    /// A tie for the most approvals leaves `winner` unset.
+    pub fn into_rcv_result(self) -> RcvResult {
+        let total_votes = self.total_ballots as f64;
+        let winner = match self.winners.as_slice() {
+            [winner] => Some(*winner),
+            _ => None,
+        };
+        let elimination_order = order_by_votes(&self.approvals, winner);
+
+        RcvResult {
+            rounds: vec![Round {
+                round_number: 1,
+                vote_counts: self.approvals,
+                eliminated: None,
+                winner,
+                exhausted_ballots: 0,
+                total_votes,
+                majority_threshold: total_votes / 2.0,
+                tiebreak_reason: None,
+                tied_candidates: Vec::new(),
+            }],
+            winner,
+            total_ballots: self.total_ballots,
+            exhausted_ballots: 0,
+            spoiled_ballots: self.spoiled_ballots,
+            elimination_order,
+            elected: Vec::new(),
+            quota: None,
+        }
+    }
+}
+
+/// Approval voting: every candidate listed on a ballot receives one approval,
+/// regardless of the rank it was given
+pub struct ApprovalVoting {
+    candidates: Vec<Candidate>,
+    ballots: Vec<Ballot>,
+}
+
+impl ApprovalVoting {
+    pub fn new(candidates: Vec<Candidate>, ballots: Vec<Ballot>) -> Self {
+        Self { candidates, ballots }
+    }
+
+    /// Validate all ballots before counting
+    pub fn validate_ballots(&self) -> Result<(), String> {
+        // Approvals of candidates no longer in the poll are skipped when counting, not rejected
+        for ballot in &self.ballots {
+            let mut seen_candidates = HashSet::new();
+            for &candidate_id in &ballot.rankings {
+                if !seen_candidates.insert(candidate_id) {
+                    return Err(format!("Duplicate candidate approval in ballot {}", ballot.id));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Count approvals and return the candidate(s) with the most
+    pub fn tabulate(&self) -> Result<ApprovalResult, TabulationError> {
+        self.validate_ballots().map_err(TabulationError::InvalidBallot)?;
+
+        if self.candidates.len() < 2 {
+            return Err(TabulationError::InsufficientCandidates(self.candidates.len()));
+        }
+
+        // Include every candidate so unapproved ones still show up in the results
+        let mut approvals: HashMap<Uuid, f64> = self.candidates.iter().map(|c| (c.id, 0.0)).collect();
+        for ballot in &self.ballots {
+            for candidate_id in &ballot.rankings {
+                if let Some(count) = approvals.get_mut(candidate_id) {
+                    *count += 1.0;
+                }
+            }
+        }
+
+        let max_approvals = approvals.values().copied().fold(0.0, f64::max);
+        let mut winners: Vec<Uuid> = if max_approvals > 0.0 {
+            approvals.iter()
+                .filter(|(_, &count)| count == max_approvals)
+                .map(|(id, _)| *id)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        winners.sort();
+
+        Ok(ApprovalResult {
+            approvals,
+            winners,
+            total_ballots: self.ballots.len(),
+            spoiled_ballots: count_spoiled(&self.ballots),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_candidates() -> Vec<Candidate> {
+        vec![
+            Candidate { id: Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap(), name: "Alice".to_string() },
+            Candidate { id: Uuid::parse_str("00000000-0000-0000-0000-000000000002").unwrap(), name: "Bob".to_string() },
+            Candidate { id: Uuid::parse_str("00000000-0000-0000-0000-000000000003").unwrap(), name: "Charlie".to_string() },
+        ]
+    }
+
+    fn ballot(rankings: Vec<Uuid>) -> Ballot {
+        Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings }
+    }
+
+    #[test]
+    fn test_overlapping_approvals() {
+        let candidates = create_test_candidates();
+        let (alice_id, bob_id, charlie_id) = (candidates[0].id, candidates[1].id, candidates[2].id);
+
+        // Bob is everyone's acceptable compromise even though he's nobody's favourite
+        let ballots = vec![
+            ballot(vec![alice_id, bob_id]),
+            ballot(vec![charlie_id, bob_id]),
+            ballot(vec![alice_id, bob_id, charlie_id]),
+            ballot(vec![charlie_id]),
+        ];
+
+        let result = ApprovalVoting::new(candidates, ballots).tabulate().unwrap();
+
+        assert_eq!(result.approvals[&alice_id], 2.0);
+        assert_eq!(result.approvals[&bob_id], 3.0);
+        assert_eq!(result.approvals[&charlie_id], 3.0);
+        assert_eq!(result.winners, vec![bob_id, charlie_id]);
+        assert_eq!(result.total_ballots, 4);
+
+        // A tie for first leaves no single winner in the RCV-shaped result
+        let rcv_result = result.into_rcv_result();
+        assert_eq!(rcv_result.winner, None);
+        assert_eq!(rcv_result.rounds.len(), 1);
+    }
+
+    #[test]
+    fn test_single_approval_winner() {
+        let candidates = create_test_candidates();
+        let (alice_id, bob_id, charlie_id) = (candidates[0].id, candidates[1].id, candidates[2].id);
+
+        let ballots = vec![
+            ballot(vec![alice_id, bob_id]),
+            ballot(vec![bob_id]),
+            ballot(vec![charlie_id, bob_id, alice_id]),
+        ];
+
+        let result = ApprovalVoting::new(candidates, ballots).tabulate().unwrap();
+        assert_eq!(result.winners, vec![bob_id]);
+        assert_eq!(result.approvals[&charlie_id], 1.0);
+
+        let rcv_result = result.into_rcv_result();
+        assert_eq!(rcv_result.winner, Some(bob_id));
+        assert_eq!(rcv_result.rounds[0].total_votes, 3.0);
+        assert_eq!(rcv_result.rounds[0].vote_counts.len(), 3);
+    }
+
+    #[test]
+    fn test_duplicate_approval_rejected() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+
+        let result = ApprovalVoting::new(candidates, vec![ballot(vec![alice_id, alice_id])]).tabulate();
+        assert!(result.unwrap_err().to_string().contains("Duplicate candidate"));
+    }
+}
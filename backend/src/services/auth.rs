@@ -9,10 +9,14 @@ use sqlx::PgPool;
 use std::{env, sync::Arc};
 use uuid::Uuid;
 
+use crate::config::{min_poll_duration, UrlConfig};
 use crate::models::auth_token::AuthToken;
-use crate::models::user::{CreateUserRequest, LoginRequest, User, UserResponse};
+use crate::models::user::{CreateUserRequest, LoginRequest, UpdateProfileRequest, User, UserResponse};
+use crate::services::captcha::CaptchaVerifier;
+use crate::services::results_stream::ResultsEvents;
 use crate::services::email::{EmailService, EmailVerificationRequest, PasswordResetRequest};
 use crate::services::ses::SesEmailSender;
+use crate::services::signing::TokenSigner;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
@@ -46,23 +50,105 @@ pub enum AuthError {
     InvalidToken,
     #[error("Token expired")]
     TokenExpired,
+    #[error("{0}")]
+    WeakPassword(String),
+    #[error("User not found")]
+    UserNotFound,
+    #[error("Email address is required")]
+    InvalidEmail,
+    #[error("{0}")]
+    InsecureJwtSecret(String),
+}
+
+/// Access tokens last 24 hours unless `ACCESS_TOKEN_TTL_MINUTES` says otherwise
+const DEFAULT_ACCESS_TOKEN_TTL_MINUTES: i64 = 24 * 60;
+/// Refresh tokens last 7 days unless `REFRESH_TOKEN_TTL_DAYS` says otherwise
+const DEFAULT_REFRESH_TOKEN_TTL_DAYS: i64 = 7;
+
+/// Minimum password length unless `PASSWORD_MIN_LENGTH` says otherwise
+const DEFAULT_PASSWORD_MIN_LENGTH: i64 = 8;
+
+/// Shortest `JWT_SECRET` accepted, in bytes: the HMAC-SHA256 key size
+pub const MIN_JWT_SECRET_LEN: usize = 32;
+
+/// Placeholder secret used by debug builds when `JWT_SECRET` is unset
+const DEV_JWT_SECRET: &str = "your-256-bit-secret-here-change-in-production";
+
+/// Decide which JWT secret to sign with. A configured secret shorter than
+/// `MIN_JWT_SECRET_LEN` is always rejected, and a missing one only falls back to the
+/// development placeholder in debug builds, unless `allow_insecure` overrides both.
+pub fn resolve_jwt_secret(configured: Option<&str>, allow_insecure: bool) -> Result<String, AuthError> {
+    match configured {
+        Some(secret) if secret.len() >= MIN_JWT_SECRET_LEN => Ok(secret.to_string()),
+        Some(secret) if allow_insecure => {
+            tracing::warn!("JWT_SECRET is shorter than {} bytes; allowed by ALLOW_INSECURE_JWT_SECRET", MIN_JWT_SECRET_LEN);
+            Ok(secret.to_string())
+        }
+        Some(_) => Err(AuthError::InsecureJwtSecret(format!(
+            "JWT_SECRET must be at least {} bytes long",
+            MIN_JWT_SECRET_LEN
+        ))),
+        None if allow_insecure || cfg!(debug_assertions) => {
+            tracing::warn!("JWT_SECRET not set, using the insecure development secret");
+            Ok(DEV_JWT_SECRET.to_string())
+        }
+        None => Err(AuthError::InsecureJwtSecret("JWT_SECRET must be set".to_string())),
+    }
+}
+
+/// Read a positive integer from the environment, falling back when unset or unparseable
+fn env_positive_i64(key: &str, default: i64) -> i64 {
+    parse_positive_i64(env::var(key).ok().as_deref()).unwrap_or(default)
+}
+
+fn parse_positive_i64(value: Option<&str>) -> Option<i64> {
+    value
+        .and_then(|value| value.parse::<i64>().ok())
+        .filter(|&value| value > 0)
+}
+
+/// Access and refresh token lifetimes from raw `ACCESS_TOKEN_TTL_MINUTES` and
+/// `REFRESH_TOKEN_TTL_DAYS` values. Missing, invalid or out-of-range values use the defaults.
+pub fn token_ttls(access_minutes: Option<&str>, refresh_days: Option<&str>) -> (Duration, Duration) {
+    let access = parse_positive_i64(access_minutes)
+        .and_then(Duration::try_minutes)
+        .unwrap_or_else(|| Duration::minutes(DEFAULT_ACCESS_TOKEN_TTL_MINUTES));
+    let refresh = parse_positive_i64(refresh_days)
+        .and_then(Duration::try_days)
+        .unwrap_or_else(|| Duration::days(DEFAULT_REFRESH_TOKEN_TTL_DAYS));
+    (access, refresh)
 }
 
 #[derive(Clone)]
 pub struct AuthService {
     pool: PgPool,
     jwt_secret: Arc<String>,
-    frontend_url: Arc<String>,
+    signer: TokenSigner,
+    access_token_ttl: Duration,
+    refresh_token_ttl: Duration,
+    password_min_length: usize,
+    urls: Arc<UrlConfig>,
     email_service: Option<Arc<EmailService>>,
     ses_sender: Option<Arc<SesEmailSender>>,
+    captcha_verifier: Option<Arc<CaptchaVerifier>>,
+    min_poll_duration: Option<Duration>,
+    env_lookup: fn(&str) -> Option<String>,
+    results_events: ResultsEvents,
 }
 
 impl AuthService {
-    pub fn new(pool: PgPool) -> Self {
-        let jwt_secret = env::var("JWT_SECRET")
-            .unwrap_or_else(|_| "your-256-bit-secret-here-change-in-production".to_string());
-        let frontend_url = env::var("FRONTEND_URL")
-            .unwrap_or_else(|_| "http://localhost:5174".to_string());
+    /// Build the service from the environment. Fails when `JWT_SECRET` is missing or
+    /// too short to be safe; see `resolve_jwt_secret`.
+    pub fn new(pool: PgPool) -> Result<Self, AuthError> {
+        let jwt_secret = resolve_jwt_secret(
+            env::var("JWT_SECRET").ok().filter(|secret| !secret.is_empty()).as_deref(),
+            env::var("ALLOW_INSECURE_JWT_SECRET").is_ok_and(|v| v == "1"),
+        )?;
+        let (access_token_ttl, refresh_token_ttl) = token_ttls(
+            env::var("ACCESS_TOKEN_TTL_MINUTES").ok().as_deref(),
+            env::var("REFRESH_TOKEN_TTL_DAYS").ok().as_deref(),
+        );
+        let password_min_length = env_positive_i64("PASSWORD_MIN_LENGTH", DEFAULT_PASSWORD_MIN_LENGTH) as usize;
 
         let email_service = match EmailService::new() {
             Ok(svc) => {
@@ -75,13 +161,21 @@ impl AuthService {
             }
         };
 
-        Self {
+        Ok(Self {
             pool,
+            signer: TokenSigner::new(&jwt_secret),
             jwt_secret: Arc::new(jwt_secret),
-            frontend_url: Arc::new(frontend_url),
+            access_token_ttl,
+            refresh_token_ttl,
+            password_min_length,
+            urls: Arc::new(UrlConfig::from_env()),
             email_service,
             ses_sender: None,
-        }
+            captcha_verifier: CaptchaVerifier::from_env().map(Arc::new),
+            min_poll_duration: min_poll_duration(env::var("MIN_POLL_DURATION_MINUTES").ok().as_deref()),
+            env_lookup: |key| env::var(key).ok(),
+            results_events: ResultsEvents::new(),
+        })
     }
 
     pub async fn init_ses(&mut self) {
@@ -99,11 +193,69 @@ impl AuthService {
         }
     }
 
+    /// Override the public URLs read from the environment
+    pub fn with_urls(mut self, urls: UrlConfig) -> Self {
+        self.urls = Arc::new(urls);
+        self
+    }
+
+    /// Override the token lifetimes read from the environment
+    pub fn with_token_ttls(mut self, access: Duration, refresh: Duration) -> Self {
+        self.access_token_ttl = access;
+        self.refresh_token_ttl = refresh;
+        self
+    }
+
+    /// Override the CAPTCHA provider read from the environment
+    pub fn with_captcha_verifier(mut self, verifier: CaptchaVerifier) -> Self {
+        self.captcha_verifier = Some(Arc::new(verifier));
+        self
+    }
+
+    /// Override the minimum poll duration read from the environment
+    pub fn with_min_poll_duration(mut self, duration: Option<Duration>) -> Self {
+        self.min_poll_duration = duration;
+        self
+    }
+
+    /// Override how readiness checks look up required environment variables
+    pub fn with_env_lookup(mut self, lookup: fn(&str) -> Option<String>) -> Self {
+        self.env_lookup = lookup;
+        self
+    }
+
     pub fn pool(&self) -> &PgPool {
         &self.pool
     }
 
+    pub fn urls(&self) -> &UrlConfig {
+        &self.urls
+    }
+
+    pub fn results_events(&self) -> &ResultsEvents {
+        &self.results_events
+    }
+
+    pub fn email_service(&self) -> Option<&EmailService> {
+        self.email_service.as_deref()
+    }
+
+    pub fn captcha_verifier(&self) -> Option<&CaptchaVerifier> {
+        self.captcha_verifier.as_deref()
+    }
+
+    /// Shortest gap allowed between a poll's open and close times, if any
+    pub fn min_poll_duration(&self) -> Option<Duration> {
+        self.min_poll_duration
+    }
+
+    /// Look up an environment variable the way readiness checks see it
+    pub fn env_var(&self, key: &str) -> Option<String> {
+        (self.env_lookup)(key)
+    }
+
     pub async fn register(&self, req: CreateUserRequest) -> Result<AuthResponse, AuthError> {
+        self.validate_password_strength(&req.password)?;
         let password_hash = self.hash_password(&req.password)?;
 
         match User::create(&self.pool, req, password_hash).await {
@@ -142,6 +294,9 @@ impl AuthService {
             return Err(AuthError::InvalidCredentials);
         }
 
+        let mut user = user;
+        user.last_login_at = Some(User::record_login(&self.pool, user.id).await?);
+
         // Generate tokens
         let token = self.generate_token(&user, false)?;
         let refresh_token = self.generate_token(&user, true)?;
@@ -179,12 +334,17 @@ impl AuthService {
         Ok(token_data.claims)
     }
 
+    /// Signs values clients must not forge, keyed by the JWT secret
+    pub fn signer(&self) -> &TokenSigner {
+        &self.signer
+    }
+
     pub fn generate_token(&self, user: &User, is_refresh: bool) -> Result<String, AuthError> {
         let now = Utc::now();
         let exp_duration = if is_refresh {
-            Duration::days(7) // Refresh token expires in 7 days
+            self.refresh_token_ttl
         } else {
-            Duration::hours(24) // Access token expires in 24 hours
+            self.access_token_ttl
         };
 
         let claims = Claims {
@@ -241,7 +401,7 @@ impl AuthService {
         )
         .await?;
 
-        let reset_url = format!("{}/reset-password?token={}", self.frontend_url, auth_token.token);
+        let reset_url = self.urls.password_reset_url(&auth_token.token);
 
         if let Some(ref ses) = self.ses_sender {
             if let Err(e) = ses
@@ -305,6 +465,65 @@ impl AuthService {
         self.send_verification_email(&user).await
     }
 
+    /// Change the user's name and/or email. A new email address has to be verified again.
+    pub async fn update_profile(&self, user_id: Uuid, req: UpdateProfileRequest) -> Result<UserResponse, AuthError> {
+        let user = User::find_by_id(&self.pool, user_id)
+            .await?
+            .ok_or(AuthError::UserNotFound)?;
+
+        let name = match req.name {
+            Some(name) => Some(name.trim().to_string()).filter(|name| !name.is_empty()),
+            None => user.name.clone(),
+        };
+        let email = match req.email {
+            Some(email) => {
+                let email = email.trim().to_string();
+                if email.is_empty() {
+                    return Err(AuthError::InvalidEmail);
+                }
+                email
+            }
+            None => user.email.clone(),
+        };
+        let email_changed = email != user.email;
+        let email_verified = user.email_verified && !email_changed;
+
+        let updated = match User::update_profile(&self.pool, user_id, name.as_deref(), &email, email_verified).await {
+            Ok(updated) => updated,
+            Err(sqlx::Error::Database(db_err)) if db_err.constraint() == Some("users_email_key") => {
+                return Err(AuthError::UserAlreadyExists);
+            }
+            Err(e) => return Err(AuthError::Database(e)),
+        };
+
+        if email_changed {
+            if let Err(e) = self.send_verification_email(&updated).await {
+                tracing::warn!("Failed to send verification email for {}: {}", updated.email, e);
+            }
+        }
+
+        Ok(updated.into())
+    }
+
+    /// Permanently delete the user's account and everything they own, once they've
+    /// confirmed their password
+    pub async fn delete_account(&self, user_id: Uuid, password: &str) -> Result<(), AuthError> {
+        let user = User::find_by_id(&self.pool, user_id)
+            .await?
+            .ok_or(AuthError::UserNotFound)?;
+
+        if !self.verify_password(password, &user.password_hash)? {
+            return Err(AuthError::InvalidCredentials);
+        }
+
+        if !User::delete_with_data(&self.pool, user_id).await? {
+            return Err(AuthError::UserNotFound);
+        }
+
+        tracing::info!("Deleted account {}", user_id);
+        Ok(())
+    }
+
     async fn send_verification_email(&self, user: &User) -> Result<(), AuthError> {
         AuthToken::delete_for_user(&self.pool, user.id, "email_verification").await?;
 
@@ -316,10 +535,7 @@ impl AuthService {
         )
         .await?;
 
-        let verification_url = format!(
-            "{}/verify-email?token={}",
-            self.frontend_url, auth_token.token
-        );
+        let verification_url = self.urls.verification_url(&auth_token.token);
 
         if let Some(ref ses) = self.ses_sender {
             if let Err(e) = ses
@@ -357,6 +573,29 @@ impl AuthService {
         Ok(())
     }
 
+    /// Reject passwords that are too short or trivially guessable
+    pub fn validate_password_strength(&self, password: &str) -> Result<(), AuthError> {
+        if password.chars().count() < self.password_min_length {
+            return Err(AuthError::WeakPassword(format!(
+                "Password must be at least {} characters",
+                self.password_min_length
+            )));
+        }
+
+        if password.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AuthError::WeakPassword("Password cannot be entirely numeric".to_string()));
+        }
+
+        let mut chars = password.chars();
+        if let Some(first) = chars.next() {
+            if chars.all(|c| c == first) {
+                return Err(AuthError::WeakPassword("Password cannot be a single repeated character".to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn hash_password(&self, password: &str) -> Result<String, AuthError> {
         let salt = SaltString::generate(&mut OsRng);
         let argon2 = Argon2::default();
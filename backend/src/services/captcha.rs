@@ -0,0 +1,68 @@
+use anyhow::{Context, Result};
+use reqwest::Client;
+use serde::Deserialize;
+use std::net::IpAddr;
+use std::time::Duration;
+
+/// reCAPTCHA's server-side verification endpoint
+const DEFAULT_VERIFY_URL: &str = "https://www.google.com/recaptcha/api/siteverify";
+
+/// How long a vote waits on the provider before verification fails
+const VERIFY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Checks CAPTCHA tokens from the browser with the provider. Works with any
+/// service that speaks the reCAPTCHA `siteverify` protocol (hCaptcha, Turnstile).
+#[derive(Debug, Clone)]
+pub struct CaptchaVerifier {
+    client: Client,
+    verify_url: String,
+    secret_key: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerifyResponse {
+    success: bool,
+}
+
+impl CaptchaVerifier {
+    pub fn new(verify_url: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            client: Client::new(),
+            verify_url: verify_url.into(),
+            secret_key: secret_key.into(),
+        }
+    }
+
+    /// Configure from `CAPTCHA_SECRET_KEY` and optionally `CAPTCHA_VERIFY_URL`.
+    /// `None` when no secret key is set.
+    pub fn from_env() -> Option<Self> {
+        let secret_key = std::env::var("CAPTCHA_SECRET_KEY").ok().filter(|key| !key.is_empty())?;
+        let verify_url = std::env::var("CAPTCHA_VERIFY_URL").unwrap_or_else(|_| DEFAULT_VERIFY_URL.to_string());
+
+        Some(Self::new(verify_url, secret_key))
+    }
+
+    /// Whether the provider accepts `token`, optionally checked against the voter's address
+    pub async fn verify(&self, token: &str, remote_ip: Option<IpAddr>) -> Result<bool> {
+        let mut form = vec![("secret", self.secret_key.clone()), ("response", token.to_string())];
+        if let Some(ip) = remote_ip {
+            form.push(("remoteip", ip.to_string()));
+        }
+
+        let response: VerifyResponse = self
+            .client
+            .post(&self.verify_url)
+            .timeout(VERIFY_TIMEOUT)
+            .form(&form)
+            .send()
+            .await
+            .context("CAPTCHA provider unreachable")?
+            .error_for_status()
+            .context("CAPTCHA provider returned an error")?
+            .json()
+            .await
+            .context("Invalid response from CAPTCHA provider")?;
+
+        Ok(response.success)
+    }
+}
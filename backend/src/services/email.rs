@@ -1,12 +1,37 @@
-use reqwest::Client;
+use reqwest::{header::RETRY_AFTER, Client};
 use serde::{Deserialize, Serialize};
 use anyhow::{Result, Context};
+use std::time::Duration;
+
+use crate::config::DEFAULT_LOCALE;
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_BASE_DELAY_MS: u64 = 500;
+// Longest provider-requested wait honored; longer ones fall back to the normal backoff
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(5);
+
+/// Accept BCP 47-style tags such as `en`, `pt-BR` or `zh-Hant-TW`
+pub fn is_valid_locale(locale: &str) -> bool {
+    (2..=35).contains(&locale.len())
+        && locale.split('-').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// The locale to render a voter's email in: their own preference, then the poll default
+pub fn email_locale(voter_locale: Option<&str>, poll_default: &str) -> String {
+    voter_locale
+        .or(Some(poll_default))
+        .filter(|locale| !locale.is_empty())
+        .unwrap_or(DEFAULT_LOCALE)
+        .to_string()
+}
 
 #[derive(Debug, Clone)]
 pub struct EmailService {
     client: Client,
     base_url: String,
     api_key: String,
+    max_attempts: u32,
+    base_delay: Duration,
 }
 
 #[derive(Debug, Serialize)]
@@ -25,6 +50,7 @@ pub struct VoterInvitationRequest {
     pub closes_at: Option<String>,
     #[serde(rename = "voterName")]
     pub voter_name: Option<String>,
+    pub locale: String,
     pub to: String,
 }
 
@@ -69,6 +95,7 @@ pub struct PollResultsRequest {
     pub voter_name: Option<String>,
     #[serde(rename = "finalRankings")]
     pub final_rankings: Vec<FinalRanking>,
+    pub locale: String,
     pub to: String,
 }
 
@@ -100,14 +127,14 @@ pub struct PasswordResetRequest {
     pub to: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct EmailResponse {
     pub success: bool,
     pub data: Option<EmailResponseData>,
     pub error: Option<EmailError>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct EmailResponseData {
     #[serde(rename = "messageId")]
     pub message_id: Option<String>,
@@ -118,7 +145,7 @@ pub struct EmailResponseData {
     pub failed_recipients: Option<Vec<String>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct EmailError {
     pub code: String,
     pub message: String,
@@ -132,40 +159,98 @@ impl EmailService {
         let api_key = std::env::var("EMAIL_SERVICE_API_KEY")
             .context("EMAIL_SERVICE_API_KEY environment variable is required")?;
 
+        let max_attempts = std::env::var("EMAIL_RETRY_MAX_ATTEMPTS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MAX_ATTEMPTS);
+        let base_delay_ms = std::env::var("EMAIL_RETRY_BASE_DELAY_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_BASE_DELAY_MS);
+
         Ok(Self {
             client: Client::new(),
             base_url,
             api_key,
+            max_attempts,
+            base_delay: Duration::from_millis(base_delay_ms),
         })
     }
 
+    /// Override how many times a request is attempted and the initial backoff delay
+    pub fn with_retry(mut self, max_attempts: u32, base_delay: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// POST to the email service, retrying connection errors and 5xx responses
+    /// with exponential backoff. A `Retry-After` header overrides the computed delay.
+    async fn post_with_retry<T: Serialize>(&self, url: &str, body: &T) -> Result<EmailResponse> {
+        let mut attempt = 1;
+
+        loop {
+            let result = self
+                .client
+                .post(url)
+                .header("X-API-Key", &self.api_key)
+                .json(body)
+                .send()
+                .await;
+
+            let backoff = self.base_delay * 2u32.saturating_pow(attempt - 1);
+            let retry_delay = match result {
+                Ok(response) if response.status().is_success() => {
+                    return response
+                        .json()
+                        .await
+                        .context("Failed to parse email service response");
+                }
+                Ok(response) if response.status().is_server_error() && attempt < self.max_attempts => {
+                    let delay = retry_after(&response).unwrap_or(backoff);
+                    tracing::warn!(
+                        "Email service returned {} (attempt {}/{}), retrying in {:?}",
+                        response.status(), attempt, self.max_attempts, delay
+                    );
+                    delay
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    let text = response.text().await.unwrap_or_default();
+                    anyhow::bail!("Email service returned error {}: {}", status, text);
+                }
+                Err(e) if (e.is_connect() || e.is_timeout()) && attempt < self.max_attempts => {
+                    tracing::warn!(
+                        "Email service request failed (attempt {}/{}), retrying in {:?}: {}",
+                        attempt, self.max_attempts, backoff, e
+                    );
+                    backoff
+                }
+                Err(e) => {
+                    return Err(e).context("Failed to send HTTP request to email service");
+                }
+            };
+
+            tokio::time::sleep(retry_delay).await;
+            attempt += 1;
+        }
+    }
+
     pub async fn send_voter_invitation(
         &self,
         request: VoterInvitationRequest,
     ) -> Result<EmailResponse> {
         let url = format!("{}/api/email/voter-invitation", self.base_url);
-        
-        let response = self
-            .client
-            .post(&url)
-            .header("X-API-Key", &self.api_key)
-            .json(&request)
-            .send()
-            .await
-            .context("Failed to send HTTP request to email service")?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Email service returned error {}: {}", status, text);
-        }
-
-        let email_response: EmailResponse = response
-            .json()
-            .await
-            .context("Failed to parse email service response")?;
+        self.post_with_retry(&url, &request).await
+    }
 
-        Ok(email_response)
+    /// Remind a voter who hasn't voted yet; reuses the invitation payload
+    pub async fn send_voter_reminder(
+        &self,
+        request: VoterInvitationRequest,
+    ) -> Result<EmailResponse> {
+        let url = format!("{}/api/email/voter-reminder", self.base_url);
+        self.post_with_retry(&url, &request).await
     }
 
     pub async fn send_bulk_voter_invitations(
@@ -173,28 +258,7 @@ impl EmailService {
         request: BulkVoterInvitationRequest,
     ) -> Result<EmailResponse> {
         let url = format!("{}/api/email/bulk-voter-invitations", self.base_url);
-        
-        let response = self
-            .client
-            .post(&url)
-            .header("X-API-Key", &self.api_key)
-            .json(&request)
-            .send()
-            .await
-            .context("Failed to send HTTP request to email service")?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Email service returned error {}: {}", status, text);
-        }
-
-        let email_response: EmailResponse = response
-            .json()
-            .await
-            .context("Failed to parse email service response")?;
-
-        Ok(email_response)
+        self.post_with_retry(&url, &request).await
     }
 
     pub async fn send_poll_results(
@@ -202,28 +266,7 @@ impl EmailService {
         request: PollResultsRequest,
     ) -> Result<EmailResponse> {
         let url = format!("{}/api/email/poll-results", self.base_url);
-        
-        let response = self
-            .client
-            .post(&url)
-            .header("X-API-Key", &self.api_key)
-            .json(&request)
-            .send()
-            .await
-            .context("Failed to send HTTP request to email service")?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Email service returned error {}: {}", status, text);
-        }
-
-        let email_response: EmailResponse = response
-            .json()
-            .await
-            .context("Failed to parse email service response")?;
-
-        Ok(email_response)
+        self.post_with_retry(&url, &request).await
     }
 
     pub async fn send_email_verification(
@@ -302,4 +345,127 @@ impl Default for EmailService {
     fn default() -> Self {
         Self::new().expect("Failed to create EmailService")
     }
-}
\ No newline at end of file
+}
+
+// Parse a `Retry-After` header given in seconds, ignoring waits beyond `MAX_RETRY_AFTER`
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_retry_after)
+}
+
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    value
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+        .filter(|delay| *delay <= MAX_RETRY_AFTER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, routing::post, Json, Router};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    async fn spawn_flaky_server(failures: usize, status: StatusCode) -> (String, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+
+        let app = Router::new().route(
+            "/api/email/poll-results",
+            post(move || {
+                let counter = counter.clone();
+                async move {
+                    if counter.fetch_add(1, Ordering::SeqCst) < failures {
+                        (status, Json(serde_json::json!({ "success": false })))
+                    } else {
+                        (StatusCode::OK, Json(serde_json::json!({ "success": true, "data": { "messageId": "msg-1" } })))
+                    }
+                }
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        (format!("http://{}", addr), calls)
+    }
+
+    fn test_service(base_url: String) -> EmailService {
+        EmailService {
+            client: Client::new(),
+            base_url,
+            api_key: "test-key".to_string(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: Duration::from_millis(DEFAULT_BASE_DELAY_MS),
+        }
+        .with_retry(3, Duration::from_millis(10))
+    }
+
+    fn results_request() -> PollResultsRequest {
+        PollResultsRequest {
+            poll_title: "Test Poll".to_string(),
+            poll_description: None,
+            winner_name: "Alice".to_string(),
+            total_votes: 1,
+            results_url: "http://localhost/results".to_string(),
+            poll_owner_name: "Owner".to_string(),
+            voter_name: None,
+            final_rankings: Vec::new(),
+            locale: DEFAULT_LOCALE.to_string(),
+            to: "voter@example.com".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_server_errors_until_success() {
+        let (base_url, calls) = spawn_flaky_server(2, StatusCode::SERVICE_UNAVAILABLE).await;
+
+        let response = test_service(base_url).send_poll_results(results_request()).await.unwrap();
+
+        assert!(response.success);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_client_errors() {
+        let (base_url, calls) = spawn_flaky_server(2, StatusCode::BAD_REQUEST).await;
+
+        let result = test_service(base_url).send_poll_results(results_request()).await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_retry_after_is_capped() {
+        assert_eq!(parse_retry_after(" 2 "), Some(Duration::from_secs(2)));
+        assert_eq!(parse_retry_after("5"), Some(MAX_RETRY_AFTER));
+        assert_eq!(parse_retry_after("600"), None);
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2026 07:28:00 GMT"), None);
+    }
+
+    #[test]
+    fn test_email_locale_prefers_voter_then_poll_default() {
+        assert_eq!(email_locale(Some("fr"), "de"), "fr");
+        assert_eq!(email_locale(None, "de"), "de");
+        assert_eq!(email_locale(None, ""), DEFAULT_LOCALE);
+    }
+
+    #[test]
+    fn test_is_valid_locale() {
+        assert!(is_valid_locale("en"));
+        assert!(is_valid_locale("pt-BR"));
+        assert!(!is_valid_locale("e"));
+        assert!(!is_valid_locale("en_US;drop"));
+        assert!(!is_valid_locale("en--US"));
+    }
+}
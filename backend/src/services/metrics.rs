@@ -0,0 +1,99 @@
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// Application metrics exposed in Prometheus text format at `/metrics`
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    tabulation_duration: HistogramVec,
+    ballots_submitted: IntCounterVec,
+    polls_created: IntCounter,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests handled, by route and status"),
+            &["method", "route", "status"],
+        )
+        .expect("valid http_requests_total metric");
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "HTTP request latency, by route"),
+            &["method", "route"],
+        )
+        .expect("valid http_request_duration_seconds metric");
+        let tabulation_duration = HistogramVec::new(
+            HistogramOpts::new("tabulation_duration_seconds", "Time spent tabulating poll results")
+                .buckets(vec![0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0]),
+            &["poll_type"],
+        )
+        .expect("valid tabulation_duration_seconds metric");
+        let ballots_submitted = IntCounterVec::new(
+            Opts::new("ballots_submitted_total", "Ballots accepted, by voter kind"),
+            &["kind"],
+        )
+        .expect("valid ballots_submitted_total metric");
+        let polls_created = IntCounter::new("polls_created_total", "Polls created")
+            .expect("valid polls_created_total metric");
+
+        registry.register(Box::new(http_requests.clone())).expect("register http_requests_total");
+        registry.register(Box::new(http_request_duration.clone())).expect("register http_request_duration_seconds");
+        registry.register(Box::new(tabulation_duration.clone())).expect("register tabulation_duration_seconds");
+        registry.register(Box::new(ballots_submitted.clone())).expect("register ballots_submitted_total");
+        registry.register(Box::new(polls_created.clone())).expect("register polls_created_total");
+
+        Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            tabulation_duration,
+            ballots_submitted,
+            polls_created,
+        }
+    }
+
+    pub fn record_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        self.http_requests
+            .with_label_values(&[method, route, &status.to_string()])
+            .inc();
+        self.http_request_duration
+            .with_label_values(&[method, route])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn record_tabulation(&self, poll_type: &str, elapsed: Duration) {
+        self.tabulation_duration
+            .with_label_values(&[poll_type])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// `kind` is either "registered" (ballot token) or "anonymous" (public poll)
+    pub fn record_ballot_submitted(&self, kind: &str) {
+        self.ballots_submitted.with_label_values(&[kind]).inc();
+    }
+
+    pub fn record_poll_created(&self) {
+        self.polls_created.inc();
+    }
+
+    /// Render every registered metric in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        if let Err(e) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            tracing::error!("Failed to encode metrics: {}", e);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+/// Process-wide metrics, created on first use
+pub fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(Metrics::new)
+}
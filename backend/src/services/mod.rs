@@ -1,4 +1,12 @@
+pub mod approval;
 pub mod auth;
+pub mod captcha;
 pub mod email;
+pub mod metrics;
+pub mod poll_closer;
 pub mod rcv;
-pub mod ses; 
\ No newline at end of file
+pub mod results;
+pub mod results_stream;
+pub mod ses;
+pub mod signing;
+pub mod stv; 
\ No newline at end of file
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::services::results::{build_poll_results, send_results_emails};
+use crate::models::{ballot::Ballot, candidate::Candidate, poll::Poll, poll_closure::CloseReason};
+use crate::services::auth::AuthService;
+use crate::services::email::EmailService;
+
+const DEFAULT_SCAN_INTERVAL_SECS: u64 = 60;
+
+/// How often to look for polls to close (`POLL_CLOSE_SCAN_INTERVAL_SECS`, 0 disables)
+pub fn scan_interval_from_env() -> Option<Duration> {
+    let secs = std::env::var("POLL_CLOSE_SCAN_INTERVAL_SECS")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_SCAN_INTERVAL_SECS);
+
+    (secs > 0).then(|| Duration::from_secs(secs))
+}
+
+/// Whether closing a poll also emails its results to voters (`POLL_CLOSE_NOTIFY_VOTERS`)
+pub fn notify_voters_from_env() -> bool {
+    std::env::var("POLL_CLOSE_NOTIFY_VOTERS").is_ok_and(|v| v == "true")
+}
+
+/// Periodically finalize polls whose close time has passed
+pub async fn run(auth_service: AuthService, interval: Duration, notify_voters: bool) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        match finalize_closed_polls(&auth_service, notify_voters).await {
+            Ok(0) => {}
+            Ok(closed) => tracing::info!("Closed {} poll(s) past their close time", closed),
+            Err(e) => tracing::error!("Failed to finalize closed polls: {}", e),
+        }
+    }
+}
+
+/// Close every poll whose `closes_at` has passed, caching its final results and
+/// optionally emailing them to voters. Returns the number of polls closed.
+pub async fn finalize_closed_polls(auth_service: &AuthService, notify_voters: bool) -> Result<usize, sqlx::Error> {
+    let pool = auth_service.pool();
+    let email_service = results_email_service(notify_voters);
+
+    let mut closed = 0;
+    for poll_id in Poll::find_due_for_close(pool).await? {
+        // One poll failing to close shouldn't hold up the rest; it's retried on the next scan
+        match finalize_poll(auth_service, poll_id, CloseReason::Scheduled, email_service.clone()).await {
+            Ok(true) => closed += 1,
+            Ok(false) => {}
+            Err(e) => tracing::error!("Failed to close poll {}: {}", poll_id, e),
+        }
+    }
+
+    Ok(closed)
+}
+
+/// Finalize a single poll right away, e.g. after its owner closed it early.
+/// Voters are emailed the results in the background when `POLL_CLOSE_NOTIFY_VOTERS` is set.
+pub async fn finalize_poll_now(auth_service: &AuthService, poll_id: Uuid) -> Result<bool, sqlx::Error> {
+    finalize_poll(auth_service, poll_id, CloseReason::Manual, results_email_service(notify_voters_from_env())).await
+}
+
+fn results_email_service(notify_voters: bool) -> Option<Arc<EmailService>> {
+    if !notify_voters {
+        return None;
+    }
+
+    match EmailService::new() {
+        Ok(email_service) => Some(Arc::new(email_service)),
+        Err(e) => {
+            tracing::error!("Email service unavailable, closing polls without notifying voters: {}", e);
+            None
+        }
+    }
+}
+
+async fn finalize_poll(
+    auth_service: &AuthService,
+    poll_id: Uuid,
+    close_reason: CloseReason,
+    email_service: Option<Arc<EmailService>>,
+) -> Result<bool, sqlx::Error> {
+    let pool = auth_service.pool();
+
+    let Some(poll) = Poll::find_by_id(pool, poll_id).await? else {
+        return Ok(false);
+    };
+    let candidates = Candidate::find_by_poll_id(pool, poll_id).await?;
+    let ballots = Ballot::find_by_poll_id(pool, poll_id).await?;
+
+    // A poll that cannot be tabulated still closes, just without cached results.
+    // Rounds are kept so the snapshot can answer any later results request.
+    let results = match build_poll_results(&poll, &candidates, ballots, true) {
+        Ok(results) => Some(results),
+        Err(e) => {
+            tracing::warn!("Closing poll {} without final results: {}", poll_id, e);
+            None
+        }
+    };
+    let final_results = results.as_ref().and_then(|results| serde_json::to_value(results).ok());
+
+    let close_reason = match &results {
+        Some(results) if !results.quorum_met => CloseReason::QuorumFailed,
+        _ => close_reason,
+    };
+
+    if !Poll::mark_closed(pool, poll_id, close_reason, final_results).await? {
+        return Ok(false);
+    }
+
+    // The poll is closed either way, so emails go out in the background and can't fail the close
+    if let (Some(email_service), Some(results)) = (email_service, results) {
+        if let Some(winner) = results.winner.clone() {
+            let pool = pool.clone();
+            let results_url = auth_service.urls().results_url(poll.id);
+            tokio::spawn(async move {
+                match send_results_emails(&pool, email_service, &poll, &results, &winner, results_url).await {
+                    Ok((sent, failed)) => {
+                        tracing::info!("Poll results for {} sent to {} voters ({} failed)", poll_id, sent, failed.len())
+                    }
+                    Err(e) => tracing::error!("Failed to email results for poll {}: {}", poll_id, e),
+                }
+            });
+        }
+    }
+
+    Ok(true)
+}
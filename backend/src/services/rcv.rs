@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
 use uuid::Uuid;
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +25,10 @@ pub struct Round {
     pub total_votes: f64,
     pub majority_threshold: f64,
     pub tiebreak_reason: Option<TieBreakReason>,
+    /// Candidates who were tied when `tiebreak_reason` decided the round, sorted by id;
+    /// empty when no tie-break was needed
+    #[serde(default)]
+    pub tied_candidates: Vec<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,9 +37,55 @@ pub struct RcvResult {
     pub winner: Option<Uuid>,
     pub total_ballots: usize,
     pub exhausted_ballots: usize,
+    /// Ballots that never named a valid candidate, e.g. every ranked candidate was
+    /// removed from the poll; these are not counted as exhausted
+    pub spoiled_ballots: usize,
+    /// Every candidate in the order they left the race, ending with the winner;
+    /// read backwards it is the final ranking
+    pub elimination_order: Vec<Uuid>,
+    /// Seats filled in a multi-winner count, in the order they were won; empty otherwise
+    #[serde(default)]
+    pub elected: Vec<ElectedCandidate>,
+    /// Votes needed to win a seat in a multi-winner count
+    #[serde(default)]
+    pub quota: Option<f64>,
 }
 
+/// A candidate who won a seat in a multi-winner count
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElectedCandidate {
+    pub candidate_id: Uuid,
+    pub round_number: usize,
+    /// Votes held in the round they were elected
+    pub votes: f64,
+    #[serde(default)]
+    pub elected_by: ElectedBy,
+}
+
+/// How a multi-winner seat was won
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ElectedBy {
+    /// Reached the quota
+    #[default]
+    Quota,
+    /// Seated without the quota because only as many candidates as open seats remained
+    Default,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TabulationError {
+    #[error("Need at least 2 candidates to tabulate, found {0}")]
+    InsufficientCandidates(usize),
+    #[error("Too many rounds - possible infinite loop detected")]
+    TooManyRounds,
+    #[error("{0}")]
+    InvalidBallot(String),
+    #[error("Unsupported poll type: {0}")]
+    UnsupportedPollType(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TieBreakMethod {
     FirstChoiceVotes,
     PriorRoundPerformance,  
@@ -43,6 +93,51 @@ pub enum TieBreakMethod {
     Random(u64),
 }
 
+impl TieBreakMethod {
+    /// Random tie-break seeded from the poll id, so each poll gets its own stable draw.
+    /// FNV-1a keeps the seed identical across builds, unlike `DefaultHasher`.
+    pub fn random_for_poll(poll_id: Uuid) -> Self {
+        let seed = poll_id.as_bytes().iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+        TieBreakMethod::Random(seed)
+    }
+}
+
+/// When a single-winner count may stop and declare a winner
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WinCondition {
+    /// A candidate needs more than half of the active votes (the default)
+    Majority,
+    /// As `Majority`, but once this many rounds have been counted the sole leader wins
+    /// without one
+    PluralityAfterRounds(usize),
+}
+
+impl WinCondition {
+    pub const NAMES: [&'static str; 2] = ["majority", "plurality_after_rounds"];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            WinCondition::Majority => Self::NAMES[0],
+            WinCondition::PluralityAfterRounds(_) => Self::NAMES[1],
+        }
+    }
+
+    /// Build from a poll's stored setting; `None` for an unknown name, or a plurality rule
+    /// without a positive round limit
+    pub fn from_settings(name: &str, round_limit: Option<i32>) -> Option<WinCondition> {
+        match name {
+            "majority" => Some(WinCondition::Majority),
+            "plurality_after_rounds" => round_limit
+                .filter(|&limit| limit >= 1)
+                .map(|limit| WinCondition::PluralityAfterRounds(limit as usize)),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TieBreakReason {
     FirstChoiceVotes,
@@ -55,6 +150,7 @@ pub struct SingleWinnerRCV {
     candidates: Vec<Candidate>,
     ballots: Vec<Ballot>,
     tie_break_method: TieBreakMethod,
+    win_condition: WinCondition,
 }
 
 impl SingleWinnerRCV {
@@ -63,25 +159,32 @@ impl SingleWinnerRCV {
             candidates,
             ballots,
             tie_break_method: TieBreakMethod::Random(42), // Default random seed
+            win_condition: WinCondition::Majority,
         }
     }
 
+    /// Engine for a specific poll, with the random tie-break seeded from its id
+    pub fn for_poll(poll_id: Uuid, candidates: Vec<Candidate>, ballots: Vec<Ballot>) -> Self {
+        Self::new(candidates, ballots).with_tie_break_method(TieBreakMethod::random_for_poll(poll_id))
+    }
+
     pub fn with_tie_break_method(mut self, method: TieBreakMethod) -> Self {
         self.tie_break_method = method;
         self
     }
 
+    pub fn with_win_condition(mut self, win_condition: WinCondition) -> Self {
+        self.win_condition = win_condition;
+        self
+    }
+
     /// Validate all ballots before tabulation
     pub fn validate_ballots(&self) -> Result<(), String> {
-        let candidate_ids: HashSet<Uuid> = self.candidates.iter().map(|c| c.id).collect();
-        
+        // Rankings of candidates no longer in the poll are skipped when counting, not rejected
         for ballot in &self.ballots {
             // Check for duplicate rankings
             let mut seen_candidates = HashSet::new();
             for &candidate_id in &ballot.rankings {
-                if !candidate_ids.contains(&candidate_id) {
-                    return Err(format!("Invalid candidate ID {} in ballot {}", candidate_id, ballot.id));
-                }
                 if !seen_candidates.insert(candidate_id) {
                     return Err(format!("Duplicate candidate ranking in ballot {}", ballot.id));
                 }
@@ -91,28 +194,31 @@ impl SingleWinnerRCV {
     }
 
     /// Perform RCV tabulation and return results
-    pub fn tabulate(&self) -> Result<RcvResult, String> {
+    pub fn tabulate(&self) -> Result<RcvResult, TabulationError> {
         // Validate ballots first
-        self.validate_ballots()?;
+        self.validate_ballots().map_err(TabulationError::InvalidBallot)?;
 
         if self.candidates.len() < 2 {
-            return Err("Need at least 2 candidates for RCV".to_string());
+            return Err(TabulationError::InsufficientCandidates(self.candidates.len()));
         }
 
         let mut rounds = Vec::new();
         let mut eliminated_candidates = HashSet::new();
+        let mut elimination_order = Vec::new();
         let mut round_number = 1;
         let total_ballots = self.ballots.len();
+        let candidate_ids: HashSet<Uuid> = self.candidates.iter().map(|c| c.id).collect();
 
         loop {
             // Count votes for active candidates
             let mut vote_counts: HashMap<Uuid, f64> = HashMap::new();
             let mut exhausted_count = 0;
 
-            for ballot in &self.ballots {
-                // Find the highest-ranked non-eliminated candidate
+            for ballot in self.ballots.iter().filter(|b| !b.rankings.is_empty()) {
+                // Find the highest-ranked candidate still in the poll and not eliminated;
+                // a ballot naming only removed candidates is exhausted from the start
                 let vote = ballot.rankings.iter()
-                    .find(|&candidate_id| !eliminated_candidates.contains(candidate_id));
+                    .find(|&candidate_id| candidate_ids.contains(candidate_id) && !eliminated_candidates.contains(candidate_id));
 
                 match vote {
                     Some(candidate_id) => {
@@ -128,31 +234,65 @@ impl SingleWinnerRCV {
             let majority_threshold = total_votes / 2.0;
 
             // Check for winner (>50% of active votes)
-            let winner = vote_counts.iter()
+            let mut winner = vote_counts.iter()
                 .find(|(_, &count)| count > majority_threshold)
                 .map(|(id, _)| *id);
 
+            // Past the round limit a plurality rule takes the leader, unless the lead is shared
+            if let WinCondition::PluralityAfterRounds(limit) = self.win_condition {
+                if winner.is_none() && round_number >= limit {
+                    winner = sole_leader(&vote_counts);
+                }
+            }
+
+            // Two candidates left with equal votes: nobody can reach a majority, so the
+            // tie-break decides the winner directly instead of an arbitrary survivor
+            if winner.is_none() && vote_counts.len() == 2 {
+                let mut finalists: Vec<Uuid> = vote_counts.keys().copied().collect();
+                finalists.sort();
+
+                if vote_counts[&finalists[0]] == vote_counts[&finalists[1]] {
+                    let (eliminated, reason) = self.break_tie_comprehensive(&finalists, &rounds);
+                    winner = finalists.iter().copied().find(|&id| id != eliminated);
+
+                    rounds.push(Round {
+                        round_number,
+                        vote_counts,
+                        eliminated: Some(eliminated),
+                        winner,
+                        exhausted_ballots: exhausted_count,
+                        total_votes,
+                        majority_threshold,
+                        tiebreak_reason: Some(reason),
+                        tied_candidates: finalists,
+                    });
+                    break;
+                }
+            }
+
             // Find candidate(s) with fewest votes for elimination
-            let (candidate_to_eliminate, tiebreak_reason) = if winner.is_none() && vote_counts.len() > 1 {
+            let (candidate_to_eliminate, tiebreak_reason, tied_candidates) = if winner.is_none() && vote_counts.len() > 1 {
                 let min_votes = vote_counts.values()
                     .min_by(|a, b| a.partial_cmp(b).unwrap())
                     .copied()
                     .unwrap_or(0.0);
 
-                let tied_candidates: Vec<Uuid> = vote_counts.iter()
+                // Sort so tie-breaks don't depend on HashMap iteration order
+                let mut tied_candidates: Vec<Uuid> = vote_counts.iter()
                     .filter(|(_, &votes)| votes == min_votes)
                     .map(|(id, _)| *id)
                     .collect();
+                tied_candidates.sort();
 
                 if tied_candidates.len() == 1 {
-                    (Some(tied_candidates[0]), None)
+                    (Some(tied_candidates[0]), None, Vec::new())
                 } else {
                     // Handle tie-breaking with comprehensive strategy
                     let (eliminated, reason) = self.break_tie_comprehensive(&tied_candidates, &rounds);
-                    (Some(eliminated), Some(reason))
+                    (Some(eliminated), Some(reason), tied_candidates)
                 }
             } else {
-                (None, None)
+                (None, None, Vec::new())
             };
 
             // Record round results
@@ -165,6 +305,7 @@ impl SingleWinnerRCV {
                 total_votes,
                 majority_threshold,
                 tiebreak_reason,
+                tied_candidates,
             };
 
             rounds.push(round);
@@ -177,13 +318,14 @@ impl SingleWinnerRCV {
             // Eliminate candidate
             if let Some(eliminated) = candidate_to_eliminate {
                 eliminated_candidates.insert(eliminated);
+                elimination_order.push(eliminated);
             }
 
             round_number += 1;
 
             // Safety check to prevent infinite loops
             if round_number > self.candidates.len() {
-                return Err("Too many rounds - possible infinite loop detected".to_string());
+                return Err(TabulationError::TooManyRounds);
             }
         }
 
@@ -202,16 +344,36 @@ impl SingleWinnerRCV {
             .map(|r| r.exhausted_ballots)
             .unwrap_or(0);
 
+        // Candidates who never held a vote were out from the start; everyone else still
+        // standing finished behind the winner in order of their final tally
+        let final_votes = rounds.last().map(|r| r.vote_counts.clone()).unwrap_or_default();
+        let (survivors, never_counted): (Vec<&Candidate>, Vec<&Candidate>) = self.candidates.iter()
+            .filter(|c| !elimination_order.contains(&c.id))
+            .partition(|c| final_votes.contains_key(&c.id) || Some(c.id) == final_winner);
+        let never_counted: HashMap<Uuid, f64> = never_counted.iter().map(|c| (c.id, 0.0)).collect();
+        let survivors: HashMap<Uuid, f64> = survivors.iter()
+            .map(|c| (c.id, final_votes.get(&c.id).copied().unwrap_or(0.0)))
+            .collect();
+        let mut elimination_order: Vec<Uuid> = order_by_votes(&never_counted, None)
+            .into_iter()
+            .chain(elimination_order)
+            .collect();
+        elimination_order.extend(order_by_votes(&survivors, final_winner));
+
         Ok(RcvResult {
             rounds,
             winner: final_winner,
             total_ballots,
             exhausted_ballots: final_exhausted,
+            spoiled_ballots: count_spoiled(&self.ballots),
+            elimination_order,
+            elected: Vec::new(),
+            quota: None,
         })
     }
 
     /// Break ties between candidates using comprehensive strategy
-    fn break_tie_comprehensive(&self, tied_candidates: &[Uuid], previous_rounds: &[Round]) -> (Uuid, TieBreakReason) {
+    pub(crate) fn break_tie_comprehensive(&self, tied_candidates: &[Uuid], previous_rounds: &[Round]) -> (Uuid, TieBreakReason) {
         // Strategy 1: First choice votes
         if let Some(winner) = self.try_first_choice_tiebreak(tied_candidates) {
             return (winner, TieBreakReason::FirstChoiceVotes);
@@ -337,6 +499,388 @@ impl SingleWinnerRCV {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct BordaResult {
+    pub points: HashMap<Uuid, f64>,
+    pub winners: Vec<Uuid>,
+    pub total_ballots: usize,
+    pub spoiled_ballots: usize,
+}
+
+impl BordaResult {
+    /// Express the count as a single round of points so it fits the RCV results shape.
+    /// A tie for the most points leaves `winner` unset.
+    pub fn into_rcv_result(self) -> RcvResult {
+        let total_points: f64 = self.points.values().sum();
+        let winner = match self.winners.as_slice() {
+            [winner] => Some(*winner),
+            _ => None,
+        };
+        let elimination_order = order_by_votes(&self.points, winner);
+
+        RcvResult {
+            rounds: vec![Round {
+                round_number: 1,
+                vote_counts: self.points,
+                eliminated: None,
+                winner,
+                exhausted_ballots: 0,
+                total_votes: total_points,
+                majority_threshold: total_points / 2.0,
+                tiebreak_reason: None,
+                tied_candidates: Vec::new(),
+            }],
+            winner,
+            total_ballots: self.total_ballots,
+            exhausted_ballots: 0,
+            spoiled_ballots: self.spoiled_ballots,
+            elimination_order,
+            elected: Vec::new(),
+            quota: None,
+        }
+    }
+}
+
+/// Borda count: with n candidates a ballot's first choice scores n-1 points, the
+/// second n-2, and so on down to 0. Candidates left off a partial ballot score nothing.
+pub struct BordaCount {
+    candidates: Vec<Candidate>,
+    ballots: Vec<Ballot>,
+}
+
+impl BordaCount {
+    pub fn new(candidates: Vec<Candidate>, ballots: Vec<Ballot>) -> Self {
+        Self { candidates, ballots }
+    }
+
+    /// Validate all ballots before counting
+    pub fn validate_ballots(&self) -> Result<(), String> {
+        for ballot in &self.ballots {
+            let mut seen_candidates = HashSet::new();
+            for &candidate_id in &ballot.rankings {
+                if !seen_candidates.insert(candidate_id) {
+                    return Err(format!("Duplicate candidate ranking in ballot {}", ballot.id));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Sum each candidate's points and return the candidate(s) with the most
+    pub fn tabulate(&self) -> Result<BordaResult, TabulationError> {
+        self.validate_ballots().map_err(TabulationError::InvalidBallot)?;
+
+        if self.candidates.len() < 2 {
+            return Err(TabulationError::InsufficientCandidates(self.candidates.len()));
+        }
+
+        // Include every candidate so unranked ones still show up in the results
+        let top_score = (self.candidates.len() - 1) as f64;
+        let mut points: HashMap<Uuid, f64> = self.candidates.iter().map(|c| (c.id, 0.0)).collect();
+        for ballot in &self.ballots {
+            // Positions are taken among the candidates still in the poll
+            let ranked: Vec<Uuid> = ballot.rankings.iter().copied().filter(|id| points.contains_key(id)).collect();
+            for (position, candidate_id) in ranked.iter().enumerate() {
+                *points.get_mut(candidate_id).unwrap() += top_score - position as f64;
+            }
+        }
+
+        let max_points = points.values().copied().fold(0.0, f64::max);
+        let mut winners: Vec<Uuid> = if max_points > 0.0 {
+            points.iter()
+                .filter(|(_, &score)| score == max_points)
+                .map(|(id, _)| *id)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        winners.sort();
+
+        Ok(BordaResult {
+            points,
+            winners,
+            total_ballots: self.ballots.len(),
+            spoiled_ballots: count_spoiled(&self.ballots),
+        })
+    }
+}
+
+/// Head-to-head counts between every pair of candidates. `wins[a][b]` is the number of
+/// ballots ranking `a` above `b`; a ranked candidate is preferred over an unranked one.
+#[derive(Debug, Clone)]
+pub struct PairwiseMatrix {
+    pub candidates: Vec<Uuid>,
+    pub wins: HashMap<Uuid, HashMap<Uuid, usize>>,
+}
+
+impl PairwiseMatrix {
+    pub fn from_ballots(candidates: &[Candidate], ballots: &[Ballot]) -> Self {
+        let candidate_ids: Vec<Uuid> = candidates.iter().map(|c| c.id).collect();
+        let mut wins: HashMap<Uuid, HashMap<Uuid, usize>> = candidate_ids.iter()
+            .map(|&a| (a, candidate_ids.iter().filter(|&&b| b != a).map(|&b| (b, 0)).collect()))
+            .collect();
+
+        for ballot in ballots {
+            let positions: HashMap<Uuid, usize> = ballot.rankings.iter()
+                .enumerate()
+                .map(|(position, &id)| (id, position))
+                .collect();
+
+            for &a in &candidate_ids {
+                let Some(&a_position) = positions.get(&a) else { continue };
+                for &b in &candidate_ids {
+                    if a != b && positions.get(&b).is_none_or(|&b_position| a_position < b_position) {
+                        *wins.get_mut(&a).unwrap().get_mut(&b).unwrap() += 1;
+                    }
+                }
+            }
+        }
+
+        Self { candidates: candidate_ids, wins }
+    }
+
+    /// Whether more ballots prefer `a` over `b` than the reverse
+    pub fn beats(&self, a: Uuid, b: Uuid) -> bool {
+        let count = |x: Uuid, y: Uuid| self.wins.get(&x).and_then(|row| row.get(&y)).copied().unwrap_or(0);
+        count(a, b) > count(b, a)
+    }
+
+    /// The candidate who beats every other candidate head-to-head, if there is one
+    pub fn condorcet_winner(&self) -> Option<Uuid> {
+        self.candidates.iter()
+            .copied()
+            .find(|&a| self.candidates.iter().all(|&b| a == b || self.beats(a, b)))
+    }
+}
+
+/// Find the shortest cycle of head-to-head defeats (e.g. A beats B, B beats C, C beats A),
+/// listed in beating order starting from the earliest candidate in the matrix.
+/// Pairwise ties are not defeats, so a tie can leave no Condorcet winner without a cycle.
+pub fn find_smallest_cycle(matrix: &PairwiseMatrix) -> Option<Vec<Uuid>> {
+    let mut smallest: Option<Vec<Uuid>> = None;
+
+    for &start in &matrix.candidates {
+        // Breadth-first search for the shortest path of defeats leading back to `start`
+        let mut previous: HashMap<Uuid, Uuid> = HashMap::new();
+        let mut frontier = vec![start];
+        let mut closing = None;
+
+        'search: while !frontier.is_empty() {
+            let mut next = Vec::new();
+            for &current in &frontier {
+                for &candidate in &matrix.candidates {
+                    if !matrix.beats(current, candidate) {
+                        continue;
+                    }
+                    if candidate == start {
+                        closing = Some(current);
+                        break 'search;
+                    }
+                    if let Entry::Vacant(entry) = previous.entry(candidate) {
+                        entry.insert(current);
+                        next.push(candidate);
+                    }
+                }
+            }
+            frontier = next;
+        }
+
+        let Some(mut current) = closing else { continue };
+        let mut cycle = vec![current];
+        while current != start {
+            current = previous[&current];
+            cycle.push(current);
+        }
+        cycle.reverse();
+
+        if smallest.as_ref().is_none_or(|found| cycle.len() < found.len()) {
+            smallest = Some(cycle);
+        }
+    }
+
+    smallest
+}
+
+#[derive(Debug, Clone)]
+pub struct SchulzeResult {
+    /// `strongest_paths[a][b]` is the strength of the strongest path of defeats from `a` to `b`
+    pub strongest_paths: HashMap<Uuid, HashMap<Uuid, usize>>,
+    /// Every candidate, best first; candidates the method cannot separate keep their input order
+    pub ranking: Vec<Uuid>,
+    pub winners: Vec<Uuid>,
+    pub total_ballots: usize,
+    pub spoiled_ballots: usize,
+}
+
+impl SchulzeResult {
+    /// Express the count as a single round scoring each candidate by the number of rivals
+    /// they beat on strongest paths, so it fits the RCV results shape.
+    /// A tie for first place leaves `winner` unset.
+    pub fn into_rcv_result(self) -> RcvResult {
+        let vote_counts: HashMap<Uuid, f64> = self.ranking.iter()
+            .map(|&a| {
+                let defeated = self.ranking.iter()
+                    .filter(|&&b| schulze_prefers(&self.strongest_paths, a, b))
+                    .count();
+                (a, defeated as f64)
+            })
+            .collect();
+        let total_votes: f64 = vote_counts.values().sum();
+        let winner = match self.winners.as_slice() {
+            [winner] => Some(*winner),
+            _ => None,
+        };
+        let elimination_order = self.ranking.iter().rev().copied().collect();
+
+        RcvResult {
+            rounds: vec![Round {
+                round_number: 1,
+                vote_counts,
+                eliminated: None,
+                winner,
+                exhausted_ballots: 0,
+                total_votes,
+                majority_threshold: total_votes / 2.0,
+                tiebreak_reason: None,
+                tied_candidates: Vec::new(),
+            }],
+            winner,
+            total_ballots: self.total_ballots,
+            exhausted_ballots: 0,
+            spoiled_ballots: self.spoiled_ballots,
+            elimination_order,
+            elected: Vec::new(),
+            quota: None,
+        }
+    }
+}
+
+/// Ballots with no rankings at all, counted apart from ballots exhausted by eliminations
+pub(crate) fn count_spoiled(ballots: &[Ballot]) -> usize {
+    ballots.iter().filter(|ballot| ballot.rankings.is_empty()).count()
+}
+
+/// Ballots ranking at least one candidate that is no longer in the poll, e.g. one deleted
+/// after votes were cast. The counts skip those rankings.
+pub(crate) fn count_stale(candidates: &[Candidate], ballots: &[Ballot]) -> usize {
+    let candidate_ids: HashSet<Uuid> = candidates.iter().map(|c| c.id).collect();
+    ballots.iter()
+        .filter(|ballot| ballot.rankings.iter().any(|id| !candidate_ids.contains(id)))
+        .count()
+}
+
+/// Order candidates from fewest to most votes with `winner` last, so the sequence reads
+/// like an elimination order. Equal tallies fall back to candidate id for stability.
+pub(crate) fn order_by_votes(vote_counts: &HashMap<Uuid, f64>, winner: Option<Uuid>) -> Vec<Uuid> {
+    let mut order: Vec<(Uuid, f64)> = vote_counts.iter()
+        .filter(|(&id, _)| Some(id) != winner)
+        .map(|(&id, &votes)| (id, votes))
+        .collect();
+    order.sort_by(|a, b| {
+        a.1.partial_cmp(&b.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| b.0.cmp(&a.0))
+    });
+
+    let mut order: Vec<Uuid> = order.into_iter().map(|(id, _)| id).collect();
+    order.extend(winner);
+    order
+}
+
+// The candidate with strictly the most votes, if one exists
+fn sole_leader(vote_counts: &HashMap<Uuid, f64>) -> Option<Uuid> {
+    let top = vote_counts.values().copied().fold(f64::NEG_INFINITY, f64::max);
+    let mut leaders = vote_counts.iter().filter(|(_, &votes)| votes == top);
+    match (leaders.next(), leaders.next()) {
+        (Some((&id, _)), None) => Some(id),
+        _ => None,
+    }
+}
+
+// Whether `a` beats `b` on strongest paths
+fn schulze_prefers(strongest_paths: &HashMap<Uuid, HashMap<Uuid, usize>>, a: Uuid, b: Uuid) -> bool {
+    let strength = |x: Uuid, y: Uuid| strongest_paths.get(&x).and_then(|row| row.get(&y)).copied().unwrap_or(0);
+    strength(a, b) > strength(b, a)
+}
+
+/// Schulze method: a Condorcet-consistent count that, when head-to-head results form a
+/// cycle, compares candidates by the strongest chain of pairwise defeats between them.
+/// A defeat's strength is the number of ballots on the winning side.
+pub struct SchulzeMethod {
+    candidates: Vec<Candidate>,
+    ballots: Vec<Ballot>,
+}
+
+impl SchulzeMethod {
+    pub fn new(candidates: Vec<Candidate>, ballots: Vec<Ballot>) -> Self {
+        Self { candidates, ballots }
+    }
+
+    /// Validate all ballots before counting
+    pub fn validate_ballots(&self) -> Result<(), String> {
+        for ballot in &self.ballots {
+            let mut seen_candidates = HashSet::new();
+            for &candidate_id in &ballot.rankings {
+                if !seen_candidates.insert(candidate_id) {
+                    return Err(format!("Duplicate candidate ranking in ballot {}", ballot.id));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Compute strongest paths from the pairwise matrix and rank every candidate
+    pub fn tabulate(&self) -> Result<SchulzeResult, TabulationError> {
+        self.validate_ballots().map_err(TabulationError::InvalidBallot)?;
+
+        if self.candidates.len() < 2 {
+            return Err(TabulationError::InsufficientCandidates(self.candidates.len()));
+        }
+
+        let matrix = PairwiseMatrix::from_ballots(&self.candidates, &self.ballots);
+        let ids = &matrix.candidates;
+
+        // Start from the direct defeats, then widen paths through each intermediate candidate
+        let mut strongest_paths: HashMap<Uuid, HashMap<Uuid, usize>> = ids.iter()
+            .map(|&a| {
+                let row = ids.iter()
+                    .filter(|&&b| b != a)
+                    .map(|&b| (b, if matrix.beats(a, b) { matrix.wins[&a][&b] } else { 0 }))
+                    .collect();
+                (a, row)
+            })
+            .collect();
+
+        for &via in ids {
+            for &a in ids.iter().filter(|&&a| a != via) {
+                for &b in ids.iter().filter(|&&b| b != via && b != a) {
+                    let through = strongest_paths[&a][&via].min(strongest_paths[&via][&b]);
+                    let direct = strongest_paths.get_mut(&a).unwrap().get_mut(&b).unwrap();
+                    *direct = (*direct).max(through);
+                }
+            }
+        }
+
+        // The strongest-path relation is transitive, so counting defeated rivals orders everyone
+        let defeated = |a: Uuid| ids.iter().filter(|&&b| schulze_prefers(&strongest_paths, a, b)).count();
+        let mut ranking = ids.clone();
+        ranking.sort_by_key(|&a| std::cmp::Reverse(defeated(a)));
+
+        let winners: Vec<Uuid> = ids.iter()
+            .copied()
+            .filter(|&a| ids.iter().all(|&b| !schulze_prefers(&strongest_paths, b, a)))
+            .collect();
+
+        Ok(SchulzeResult {
+            strongest_paths,
+            ranking,
+            winners,
+            total_ballots: self.ballots.len(),
+            spoiled_ballots: count_spoiled(&self.ballots),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -413,21 +957,7 @@ mod tests {
         let bob_id = candidates[1].id;
         let charlie_id = candidates[2].id;
 
-        // Create a scenario where Alice and Bob are tied for last place in round 1
-        // Charlie: 3 first-choice, Alice: 1 first-choice, Bob: 2 first-choice
-        // But arrange votes so Alice and Bob both get 1 vote in round 1 (tied for last)
-        let ballots = vec![
-            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![charlie_id, alice_id] },    // Charlie 1st
-            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![charlie_id, bob_id] },      // Charlie 1st
-            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![charlie_id, alice_id] },    // Charlie 1st
-            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id, charlie_id] },    // Alice 1st (1 first-choice)
-            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id, charlie_id] },      // Bob 1st  (1 first-choice)
-            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id, alice_id] },        // Bob 1st  (2 first-choice total)
-        ];
-        // Result in round 1: Charlie=3, Alice=1, Bob=2 votes
-        // No tie, so Bob should be eliminated normally without tiebreaker
-        
-        // Let's create a real tie scenario instead
+        // Create a real tie scenario: Alice and Bob tied for last place in round 1
         let ballots = vec![
             Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![charlie_id, alice_id] },    // Charlie 1st
             Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![charlie_id, bob_id] },      // Charlie 1st  
@@ -443,7 +973,7 @@ mod tests {
         let result = rcv.tabulate().unwrap();
 
         // Test passes if any of the expected tiebreaker scenarios occur
-        assert!(result.rounds.len() >= 1);
+        assert!(!result.rounds.is_empty());
         
         // Find a round with elimination that had a tiebreaker
         let had_tiebreaker = result.rounds.iter().any(|round| {
@@ -455,6 +985,36 @@ mod tests {
         assert_eq!(result.winner, Some(charlie_id));
     }
 
+    #[test]
+    fn test_tied_candidates_reported() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let charlie_id = candidates[2].id;
+
+        // Alice and Bob tie for last with one first choice each
+        let ballots = vec![
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![charlie_id, alice_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![charlie_id, bob_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id, charlie_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id, alice_id] },
+        ];
+
+        let result = SingleWinnerRCV::new(candidates, ballots).tabulate().unwrap();
+
+        let first_round = &result.rounds[0];
+        assert!(first_round.tiebreak_reason.is_some());
+        let mut expected = vec![alice_id, bob_id];
+        expected.sort();
+        assert_eq!(first_round.tied_candidates, expected);
+        assert!(expected.contains(&first_round.eliminated.unwrap()));
+
+        // A tied set is reported exactly when a tie-break was used
+        for round in &result.rounds {
+            assert_eq!(round.tiebreak_reason.is_some(), !round.tied_candidates.is_empty());
+        }
+    }
+
     #[test]
     fn test_exhausted_ballots() {
         let candidates = create_test_candidates();
@@ -508,7 +1068,35 @@ mod tests {
         let result = rcv.tabulate();
 
         assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Duplicate candidate"));
+        assert!(result.unwrap_err().to_string().contains("Duplicate candidate"));
+    }
+
+    #[test]
+    fn test_ballots_ranking_removed_candidate() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let removed_id = Uuid::new_v4();
+
+        let ballots = vec![
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id, bob_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![removed_id, bob_id] }, // Counts for Bob
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![removed_id] }, // Nothing left
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id] },
+        ];
+        assert_eq!(count_stale(&candidates, &ballots), 2);
+
+        let rcv = SingleWinnerRCV::new(candidates, ballots);
+        let result = rcv.tabulate().unwrap();
+
+        assert_eq!(result.winner, Some(alice_id));
+        assert_eq!(result.rounds[0].vote_counts[&alice_id], 3.0);
+        assert_eq!(result.rounds[0].vote_counts[&bob_id], 1.0);
+        assert!(!result.rounds[0].vote_counts.contains_key(&removed_id));
+        // The ballot that only ranked the removed candidate is exhausted, not spoiled
+        assert_eq!(result.rounds[0].exhausted_ballots, 1);
+        assert_eq!(result.spoiled_ballots, 0);
     }
 
     #[test]
@@ -536,4 +1124,371 @@ mod tests {
         // Should have multiple rounds due to eliminations
         assert!(result.rounds.len() >= 2);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_plurality_after_rounds_win_condition() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let charlie_id = candidates[2].id;
+
+        // Alice leads the first round without a majority; Charlie's voters all prefer Bob
+        let mut ballots = Vec::new();
+        for rankings in [
+            vec![alice_id], vec![alice_id], vec![alice_id], vec![alice_id],
+            vec![bob_id], vec![bob_id], vec![bob_id],
+            vec![charlie_id, bob_id], vec![charlie_id, bob_id],
+        ] {
+            ballots.push(Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings });
+        }
+
+        let majority = SingleWinnerRCV::new(candidates.clone(), ballots.clone()).tabulate().unwrap();
+        assert_eq!(majority.winner, Some(bob_id));
+        assert_eq!(majority.rounds.len(), 2);
+
+        let plurality = SingleWinnerRCV::new(candidates, ballots)
+            .with_win_condition(WinCondition::PluralityAfterRounds(1))
+            .tabulate()
+            .unwrap();
+        assert_eq!(plurality.winner, Some(alice_id));
+        assert_eq!(plurality.rounds.len(), 1);
+        assert_eq!(plurality.rounds[0].winner, Some(alice_id));
+        assert_eq!(plurality.elimination_order.last(), Some(&alice_id));
+    }
+
+    #[test]
+    fn test_final_two_way_tie_is_deterministic() {
+        let candidates: Vec<Candidate> = create_test_candidates().into_iter().take(2).collect();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+
+        // Perfectly symmetric race: every tie-break falls through to the seeded random draw
+        let ballots = vec![
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id, bob_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id, alice_id] },
+        ];
+
+        let tabulate = || {
+            SingleWinnerRCV::new(candidates.clone(), ballots.clone())
+                .with_tie_break_method(TieBreakMethod::Random(7))
+                .tabulate()
+                .unwrap()
+        };
+
+        let result = tabulate();
+        assert!(result.winner.is_some());
+        assert_eq!(result.rounds.len(), 1);
+
+        let final_round = result.rounds.last().unwrap();
+        assert_eq!(final_round.winner, result.winner);
+        assert_eq!(final_round.tiebreak_reason, Some(TieBreakReason::Random));
+        assert_ne!(final_round.eliminated, result.winner);
+
+        // Same seed, same winner, regardless of HashMap ordering
+        for _ in 0..20 {
+            assert_eq!(tabulate().winner, result.winner);
+        }
+    }
+
+    #[test]
+    fn test_elimination_order_covers_every_candidate() {
+        let mut candidates = create_test_candidates();
+        candidates.push(Candidate { id: Uuid::parse_str("00000000-0000-0000-0000-000000000004").unwrap(), name: "Dave".to_string() });
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let charlie_id = candidates[2].id;
+        let dave_id = candidates[3].id;
+
+        // Dave never receives a vote, Charlie goes out in round 1, Alice beats Bob in round 2
+        let ballots = vec![
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![charlie_id, alice_id] },
+        ];
+
+        let result = SingleWinnerRCV::new(candidates, ballots).tabulate().unwrap();
+
+        assert_eq!(result.rounds.len(), 2);
+        assert_eq!(result.rounds[0].eliminated, Some(charlie_id));
+        assert_eq!(result.rounds[1].winner, Some(alice_id));
+        assert_eq!(result.elimination_order, vec![dave_id, charlie_id, bob_id, alice_id]);
+
+        // Round eliminations appear in the same order as the rounds
+        let eliminated: Vec<Uuid> = result.rounds.iter().filter_map(|r| r.eliminated).collect();
+        let positions: Vec<usize> = eliminated.iter()
+            .map(|id| result.elimination_order.iter().position(|o| o == id).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_random_tiebreak_seed_derives_from_poll_id() {
+        let candidates: Vec<Candidate> = create_test_candidates().into_iter().take(2).collect();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let ballots = vec![
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id, bob_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id, alice_id] },
+        ];
+
+        let winner_for = |poll_id: Uuid| {
+            SingleWinnerRCV::for_poll(poll_id, candidates.clone(), ballots.clone())
+                .tabulate()
+                .unwrap()
+                .winner
+                .unwrap()
+        };
+
+        let poll_ids: Vec<Uuid> = (1..=16u128).map(Uuid::from_u128).collect();
+        assert_ne!(
+            TieBreakMethod::random_for_poll(poll_ids[0]),
+            TieBreakMethod::random_for_poll(poll_ids[1])
+        );
+
+        // Each poll reproduces its own outcome...
+        let winners: Vec<Uuid> = poll_ids.iter().map(|&id| winner_for(id)).collect();
+        for (&poll_id, &winner) in poll_ids.iter().zip(&winners) {
+            assert_eq!(winner_for(poll_id), winner);
+        }
+
+        // ...but polls no longer all share the same draw
+        assert!(winners.contains(&alice_id));
+        assert!(winners.contains(&bob_id));
+    }
+
+    #[test]
+    fn test_borda_scores_partial_ballots() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let charlie_id = candidates[2].id;
+
+        // Only listed candidates score: a lone first choice still earns n-1 points
+        let ballots = vec![
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id, bob_id, charlie_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id] },
+        ];
+
+        let result = BordaCount::new(candidates, ballots).tabulate().unwrap();
+
+        assert_eq!(result.points[&alice_id], 2.0);
+        assert_eq!(result.points[&bob_id], 3.0);
+        assert_eq!(result.points[&charlie_id], 0.0);
+        assert_eq!(result.winners, vec![bob_id]);
+
+        let rcv_result = result.into_rcv_result();
+        assert_eq!(rcv_result.winner, Some(bob_id));
+        assert_eq!(rcv_result.rounds[0].total_votes, 5.0);
+    }
+
+    #[test]
+    fn test_borda_winner_differs_from_rcv() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let charlie_id = candidates[2].id;
+
+        // Bob is few voters' favourite but almost everyone's second choice
+        let mut ballots = Vec::new();
+        for rankings in [
+            vec![alice_id, bob_id, charlie_id],
+            vec![alice_id, bob_id, charlie_id],
+            vec![alice_id, bob_id, charlie_id],
+            vec![alice_id, bob_id, charlie_id],
+            vec![charlie_id, bob_id, alice_id],
+            vec![charlie_id, bob_id, alice_id],
+            vec![charlie_id, bob_id, alice_id],
+            vec![bob_id, charlie_id, alice_id],
+            vec![bob_id, charlie_id, alice_id],
+        ] {
+            ballots.push(Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings });
+        }
+
+        // RCV eliminates Bob first and his ballots carry Charlie past Alice
+        let rcv_result = SingleWinnerRCV::new(candidates.clone(), ballots.clone()).tabulate().unwrap();
+        assert_eq!(rcv_result.winner, Some(charlie_id));
+
+        let borda_result = BordaCount::new(candidates, ballots).tabulate().unwrap();
+        assert_eq!(borda_result.points[&alice_id], 8.0);
+        assert_eq!(borda_result.points[&bob_id], 11.0);
+        assert_eq!(borda_result.points[&charlie_id], 8.0);
+        assert_eq!(borda_result.winners, vec![bob_id]);
+    }
+
+    #[test]
+    fn test_borda_tie_has_no_winner() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+
+        let ballots = vec![
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id, bob_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id, alice_id] },
+        ];
+
+        let result = BordaCount::new(candidates, ballots).tabulate().unwrap();
+        assert_eq!(result.winners, vec![alice_id, bob_id]);
+        assert_eq!(result.into_rcv_result().winner, None);
+    }
+
+    #[test]
+    fn test_pairwise_condorcet_winner() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let charlie_id = candidates[2].id;
+
+        let ballots = vec![
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id, bob_id, charlie_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id, alice_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![charlie_id, alice_id] },
+        ];
+
+        let matrix = PairwiseMatrix::from_ballots(&candidates, &ballots);
+        assert_eq!(matrix.wins[&alice_id][&bob_id], 2);
+        assert_eq!(matrix.wins[&bob_id][&charlie_id], 2);
+        assert_eq!(matrix.condorcet_winner(), Some(alice_id));
+        assert_eq!(find_smallest_cycle(&matrix), None);
+    }
+
+    #[test]
+    fn test_pairwise_three_candidate_cycle() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let charlie_id = candidates[2].id;
+
+        // The classic rock-paper-scissors electorate: each candidate beats one rival 2-1
+        let ballots = vec![
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id, bob_id, charlie_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id, charlie_id, alice_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![charlie_id, alice_id, bob_id] },
+        ];
+
+        let matrix = PairwiseMatrix::from_ballots(&candidates, &ballots);
+        assert!(matrix.beats(alice_id, bob_id));
+        assert!(matrix.beats(bob_id, charlie_id));
+        assert!(matrix.beats(charlie_id, alice_id));
+        assert_eq!(matrix.condorcet_winner(), None);
+        assert_eq!(find_smallest_cycle(&matrix), Some(vec![alice_id, bob_id, charlie_id]));
+    }
+
+    #[test]
+    fn test_pairwise_tie_is_not_a_cycle() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+
+        let ballots = vec![
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![alice_id, bob_id] },
+            Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: vec![bob_id, alice_id] },
+        ];
+
+        let matrix = PairwiseMatrix::from_ballots(&candidates, &ballots);
+        assert_eq!(matrix.condorcet_winner(), None);
+        assert_eq!(find_smallest_cycle(&matrix), None);
+    }
+
+    // Repeat a ranking for each of `count` voters
+    fn ballots_from_groups(groups: &[(usize, Vec<Uuid>)]) -> Vec<Ballot> {
+        groups.iter()
+            .flat_map(|(count, rankings)| {
+                (0..*count).map(|_| Ballot { id: Uuid::new_v4(), voter_id: Uuid::new_v4(), rankings: rankings.clone() })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_schulze_wikipedia_example() {
+        // The 45-voter, five-candidate example from the Schulze method literature: no
+        // Condorcet winner exists, yet strongest paths rank E > A > C > B > D
+        let candidates: Vec<Candidate> = ["A", "B", "C", "D", "E"].iter()
+            .enumerate()
+            .map(|(i, name)| Candidate { id: Uuid::from_u128(i as u128 + 1), name: name.to_string() })
+            .collect();
+        let [a, b, c, d, e] = [0, 1, 2, 3, 4].map(|i| candidates[i].id);
+
+        let ballots = ballots_from_groups(&[
+            (5, vec![a, c, b, e, d]),
+            (5, vec![a, d, e, c, b]),
+            (8, vec![b, e, d, a, c]),
+            (3, vec![c, a, b, e, d]),
+            (7, vec![c, a, e, b, d]),
+            (2, vec![c, b, a, d, e]),
+            (7, vec![d, c, e, b, a]),
+            (8, vec![e, b, a, d, c]),
+        ]);
+
+        let matrix = PairwiseMatrix::from_ballots(&candidates, &ballots);
+        assert_eq!(matrix.condorcet_winner(), None);
+        assert_eq!(matrix.wins[&a][&b], 20);
+        assert_eq!(matrix.wins[&e][&d], 31);
+
+        let result = SchulzeMethod::new(candidates, ballots).tabulate().unwrap();
+        assert_eq!(result.strongest_paths[&e][&a], 25);
+        assert_eq!(result.strongest_paths[&a][&e], 24);
+        assert_eq!(result.ranking, vec![e, a, c, b, d]);
+        assert_eq!(result.winners, vec![e]);
+        assert_eq!(result.into_rcv_result().winner, Some(e));
+    }
+
+    #[test]
+    fn test_schulze_breaks_three_candidate_cycle() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let charlie_id = candidates[2].id;
+
+        // Alice beats Bob 14-7, Bob beats Charlie 15-6 and Charlie beats Alice 13-8;
+        // the weakest defeat (Charlie over Alice) is the one overridden
+        let ballots = ballots_from_groups(&[
+            (8, vec![alice_id, bob_id, charlie_id]),
+            (7, vec![bob_id, charlie_id, alice_id]),
+            (6, vec![charlie_id, alice_id, bob_id]),
+        ]);
+
+        let matrix = PairwiseMatrix::from_ballots(&candidates, &ballots);
+        assert_eq!(find_smallest_cycle(&matrix), Some(vec![alice_id, bob_id, charlie_id]));
+
+        let result = SchulzeMethod::new(candidates, ballots).tabulate().unwrap();
+        assert_eq!(result.ranking, vec![alice_id, bob_id, charlie_id]);
+        assert_eq!(result.winners, vec![alice_id]);
+    }
+
+    #[test]
+    fn test_schulze_elects_condorcet_winner() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+        let charlie_id = candidates[2].id;
+
+        let ballots = ballots_from_groups(&[
+            (4, vec![charlie_id, bob_id, alice_id]),
+            (3, vec![bob_id, alice_id, charlie_id]),
+            (2, vec![alice_id, bob_id, charlie_id]),
+        ]);
+
+        let matrix = PairwiseMatrix::from_ballots(&candidates, &ballots);
+        assert_eq!(matrix.condorcet_winner(), Some(bob_id));
+
+        let result = SchulzeMethod::new(candidates, ballots).tabulate().unwrap();
+        assert_eq!(result.winners, vec![bob_id]);
+        assert_eq!(result.ranking[0], bob_id);
+    }
+
+    #[test]
+    fn test_schulze_perfect_tie_has_no_winner() {
+        let candidates = create_test_candidates();
+        let alice_id = candidates[0].id;
+        let bob_id = candidates[1].id;
+
+        let ballots = ballots_from_groups(&[(1, vec![alice_id, bob_id]), (1, vec![bob_id, alice_id])]);
+
+        // Charlie is left off both ballots, so only Alice and Bob share first place
+        let result = SchulzeMethod::new(candidates, ballots).tabulate().unwrap();
+        assert_eq!(result.winners, vec![alice_id, bob_id]);
+        assert_eq!(result.into_rcv_result().winner, None);
+    }
+}
@@ -0,0 +1,497 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::models::{
+    ballot::Voter,
+    candidate::Candidate,
+    poll::{PollResponse, PollType},
+    user::User,
+};
+use crate::services::{
+    approval::ApprovalVoting,
+    email::{email_locale, EmailService, FinalRanking as EmailFinalRanking, PollResultsRequest},
+    metrics::metrics,
+    rcv::{count_stale, BordaCount, ElectedBy, SchulzeMethod, SingleWinnerRCV, Ballot as RcvBallot, Candidate as RcvCandidate, RcvResult, Round, TabulationError},
+    stv::MultiWinnerSTV,
+};
+
+#[derive(Debug, Serialize)]
+pub struct PollResultsResponse {
+    pub poll_id: Uuid,
+    pub total_votes: usize,
+    pub status: String,
+    /// `winner`, `no_confidence` when "None of the above" prevails, or `undecided`
+    pub outcome: String,
+    /// The first of `winners`, kept for clients that expect a single winner
+    pub winner: Option<WinnerInfo>,
+    /// Every elected candidate in the order they won their seat; one for single-winner polls
+    pub winners: Vec<WinnerInfo>,
+    /// Votes needed to win a seat, for multi-winner polls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quota: Option<f64>,
+    pub final_rankings: Vec<FinalRanking>,
+    /// Round-by-round counts, only when requested with `?include_rounds=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rounds: Option<Vec<RoundInfo>>,
+    pub quorum: Option<i32>,
+    pub quorum_met: bool,
+    /// Ballots ranking a candidate since removed from the poll; those rankings were skipped
+    pub stale_ballot_count: usize,
+    pub computed_at: chrono::DateTime<chrono::Utc>,
+    /// Counting method and engine version, e.g. `single_winner_irv_v2`
+    pub algorithm: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WinnerInfo {
+    pub candidate_id: Uuid,
+    pub name: String,
+    pub final_votes: f64,
+    pub percentage: f64,
+    /// Final-round votes ahead of the runner-up
+    pub margin_over_runner_up: f64,
+    /// Won with a majority of first choices, without any transfers
+    pub won_first_round: bool,
+    /// Round in which the candidate was elected
+    pub elected_round: usize,
+    /// For multi-winner polls, whether the seat was won on the quota or by default, as one
+    /// of the last candidates standing when no more needed eliminating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elected_by: Option<ElectedBy>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FinalRanking {
+    pub position: usize,
+    pub candidate_id: Uuid,
+    pub name: String,
+    pub votes: f64,
+    pub percentage: f64,
+    pub eliminated_round: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RoundInfo {
+    pub round_number: usize,
+    pub vote_counts: HashMap<Uuid, VoteCounts>,
+    pub eliminated: Option<EliminatedCandidate>,
+    pub winner: Option<WinnerCandidate>,
+    pub exhausted_ballots: usize,
+    pub total_votes: f64,
+    pub majority_threshold: f64,
+    pub tiebreak_reason: Option<String>,
+    /// Who was tied when the tie-break decided this round
+    pub tied_candidates: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VoteCounts {
+    pub candidate_id: Uuid,
+    pub name: String,
+    pub votes: f64,
+    pub percentage: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EliminatedCandidate {
+    pub candidate_id: Uuid,
+    pub name: String,
+    pub votes: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WinnerCandidate {
+    pub candidate_id: Uuid,
+    pub name: String,
+    pub votes: f64,
+    pub percentage: f64,
+}
+
+/// Dispatch tabulation on the poll type; adding a `PollType` variant forces a decision here
+pub fn tabulate(poll: &PollResponse, candidates: Vec<RcvCandidate>, ballots: Vec<RcvBallot>) -> Result<RcvResult, TabulationError> {
+    let poll_type = poll.poll_type.as_str();
+    let start = std::time::Instant::now();
+    let result = match PollType::parse(poll_type) {
+        Some(PollType::SingleWinner) => {
+            SingleWinnerRCV::new(candidates, ballots)
+                .with_tie_break_method(poll.tie_break())
+                .with_win_condition(poll.win_condition())
+                .tabulate()
+        }
+        Some(PollType::MultiWinner) => {
+            MultiWinnerSTV::new(candidates, ballots, poll.num_winners.max(1) as usize)
+                .with_tie_break_method(poll.tie_break())
+                .tabulate()
+        }
+        Some(PollType::Approval) => {
+            ApprovalVoting::new(candidates, ballots).tabulate().map(|result| result.into_rcv_result())
+        }
+        Some(PollType::Borda) => {
+            BordaCount::new(candidates, ballots).tabulate().map(|result| result.into_rcv_result())
+        }
+        Some(PollType::Condorcet) => {
+            SchulzeMethod::new(candidates, ballots).tabulate().map(|result| result.into_rcv_result())
+        }
+        None => Err(TabulationError::UnsupportedPollType(poll_type.to_string())),
+    };
+    metrics().record_tabulation(poll_type, start.elapsed());
+    result
+}
+
+/// Identify the counting method `tabulate` uses for a poll type; bump the version when a
+/// change to an engine could alter the outcome of an existing poll
+pub fn tabulation_algorithm(poll_type: &str) -> &'static str {
+    match PollType::parse(poll_type) {
+        Some(PollType::SingleWinner) => "single_winner_irv_v2",
+        Some(PollType::MultiWinner) => "multi_winner_stv_v1",
+        Some(PollType::Approval) => "approval_v1",
+        Some(PollType::Borda) => "borda_v1",
+        Some(PollType::Condorcet) => "condorcet_schulze_v1",
+        None => "unsupported",
+    }
+}
+
+/// Convert tabulation rounds to the API format, naming each candidate
+pub fn round_infos(rounds: &[Round], candidate_map: &HashMap<Uuid, String>) -> Vec<RoundInfo> {
+    rounds.iter().map(|round| {
+        let vote_counts = round.vote_counts.iter().map(|(&candidate_id, &votes)| {
+            let name = candidate_map.get(&candidate_id).unwrap_or(&"Unknown".to_string()).clone();
+            let percentage = if round.total_votes > 0.0 {
+                (votes / round.total_votes) * 100.0
+            } else {
+                0.0
+            };
+            
+            (candidate_id, VoteCounts {
+                candidate_id,
+                name,
+                votes,
+                percentage,
+            })
+        }).collect();
+
+        let eliminated = round.eliminated.map(|candidate_id| {
+            let name = candidate_map.get(&candidate_id).unwrap_or(&"Unknown".to_string()).clone();
+            let votes = round.vote_counts.get(&candidate_id).unwrap_or(&0.0);
+            EliminatedCandidate {
+                candidate_id,
+                name,
+                votes: *votes,
+            }
+        });
+
+        let winner = round.winner.map(|candidate_id| {
+            let name = candidate_map.get(&candidate_id).unwrap_or(&"Unknown".to_string()).clone();
+            let votes = round.vote_counts.get(&candidate_id).unwrap_or(&0.0);
+            let percentage = if round.total_votes > 0.0 {
+                (votes / round.total_votes) * 100.0
+            } else {
+                0.0
+            };
+            WinnerCandidate {
+                candidate_id,
+                name,
+                votes: *votes,
+                percentage,
+            }
+        });
+
+        // Convert tiebreak reason to string
+        let tiebreak_reason = round.tiebreak_reason.as_ref().map(|reason| {
+            match reason {
+                crate::services::rcv::TieBreakReason::FirstChoiceVotes => "FirstChoiceVotes".to_string(),
+                crate::services::rcv::TieBreakReason::PriorRoundPerformance => "PriorRoundPerformance".to_string(),
+                crate::services::rcv::TieBreakReason::MostVotesToDistribute => "MostVotesToDistribute".to_string(),
+                crate::services::rcv::TieBreakReason::Random => "Random".to_string(),
+            }
+        });
+
+        RoundInfo {
+            round_number: round.round_number,
+            vote_counts,
+            eliminated,
+            winner,
+            exhausted_ballots: round.exhausted_ballots,
+            total_votes: round.total_votes,
+            majority_threshold: round.majority_threshold,
+            tiebreak_reason,
+            tied_candidates: round.tied_candidates.clone(),
+        }
+    }).collect()
+}
+
+// Whether `candidate_id` is the first choice on more than half of the non-empty ballots,
+// which counts as a rejection of the field even under methods where it doesn't win outright
+fn first_choice_majority(ballots: &[RcvBallot], candidate_id: Uuid) -> bool {
+    let counted = ballots.iter().filter(|b| !b.rankings.is_empty()).count();
+    let first_choices = ballots.iter().filter(|b| b.rankings.first() == Some(&candidate_id)).count();
+    first_choices * 2 > counted
+}
+
+/// Run RCV tabulation and summarize the outcome for a poll
+pub fn build_poll_results(
+    poll: &PollResponse,
+    candidates: &[Candidate],
+    ballots: Vec<RcvBallot>,
+    include_rounds: bool,
+) -> Result<PollResultsResponse, TabulationError> {
+    let quorum_met = poll.quorum.is_none_or(|quorum| ballots.len() >= quorum as usize);
+
+    if ballots.is_empty() {
+        return Ok(PollResultsResponse {
+            poll_id: poll.id,
+            total_votes: 0,
+            status: "no_votes".to_string(),
+            outcome: "undecided".to_string(),
+            winner: None,
+            winners: Vec::new(),
+            quota: None,
+            final_rankings: Vec::new(),
+            rounds: include_rounds.then(Vec::new),
+            quorum: poll.quorum,
+            quorum_met,
+            stale_ballot_count: 0,
+            computed_at: chrono::Utc::now(),
+            algorithm: tabulation_algorithm(&poll.poll_type).to_string(),
+        });
+    }
+
+    // Convert to RCV format
+    let rcv_candidates: Vec<RcvCandidate> = candidates.iter()
+        .map(|c| RcvCandidate {
+            id: c.id,
+            name: c.name.clone(),
+        })
+        .collect();
+
+    // Run RCV tabulation
+    let rcv_result = tabulate(poll, rcv_candidates.clone(), ballots.clone())?;
+
+    // Determine poll status
+    let now = chrono::Utc::now();
+    let is_closed = poll.closes_at.is_some_and(|closes| now > closes);
+    let status = if !quorum_met {
+        "quorum_not_met"
+    } else if is_closed {
+        "completed"
+    } else if rcv_result.winner.is_some() {
+        "winner_declared"
+    } else {
+        "in_progress"
+    };
+
+    // Get final round for results
+    let final_round = rcv_result.rounds.last();
+    
+    // Describe a winner from the round they were elected in
+    let winner_info = |candidate_id: Uuid, round: &Round, won_first_round: bool, elected_by: Option<ElectedBy>| {
+        let candidate = rcv_candidates.iter().find(|c| c.id == candidate_id)?;
+        let votes = round.vote_counts.get(&candidate_id).copied().unwrap_or(0.0);
+        let percentage = if round.total_votes > 0.0 {
+            (votes / round.total_votes) * 100.0
+        } else {
+            0.0
+        };
+
+        // Lead over the strongest other candidate counted in that round and not elected in it
+        let runner_up_votes = round.vote_counts.iter()
+            .filter(|(&id, _)| id != candidate_id)
+            .filter(|(&id, _)| !rcv_result.elected.iter().any(|e| e.candidate_id == id && e.round_number == round.round_number))
+            .map(|(_, &votes)| votes)
+            .fold(0.0, f64::max);
+
+        Some(WinnerInfo {
+            candidate_id,
+            name: candidate.name.clone(),
+            final_votes: votes,
+            percentage,
+            margin_over_runner_up: votes - runner_up_votes,
+            won_first_round,
+            elected_round: round.round_number,
+            elected_by,
+        })
+    };
+
+    let winners: Vec<WinnerInfo> = if rcv_result.elected.is_empty() {
+        rcv_result.winner.zip(final_round)
+            .and_then(|(winner_id, round)| {
+                let won_first_round = rcv_result.rounds.first()
+                    .is_some_and(|first_round| first_round.winner == Some(winner_id));
+                winner_info(winner_id, round, won_first_round, None)
+            })
+            .into_iter()
+            .collect()
+    } else {
+        rcv_result.elected.iter()
+            .filter_map(|elected| {
+                let round = rcv_result.rounds.iter().find(|r| r.round_number == elected.round_number)?;
+                winner_info(elected.candidate_id, round, elected.round_number == 1, Some(elected.elected_by))
+            })
+            .collect()
+    };
+
+    // Create final rankings: the elimination order read backwards covers every candidate,
+    // including those knocked out before the final round
+    let mut final_rankings = Vec::new();
+    for (position, candidate_id) in rcv_result.elimination_order.iter().rev().enumerate() {
+        let Some(candidate) = rcv_candidates.iter().find(|c| c.id == *candidate_id) else {
+            continue;
+        };
+
+        // Report each candidate's tally from the last round they were counted in
+        let last_counted = rcv_result.rounds.iter().rev()
+            .find(|r| r.vote_counts.contains_key(candidate_id));
+        let votes = last_counted
+            .and_then(|r| r.vote_counts.get(candidate_id))
+            .copied()
+            .unwrap_or(0.0);
+        let percentage = match last_counted {
+            Some(round) if round.total_votes > 0.0 => (votes / round.total_votes) * 100.0,
+            _ => 0.0,
+        };
+
+        // Candidates who outlasted every elimination but lost are out in the final round
+        let elected = rcv_result.winner == Some(*candidate_id)
+            || rcv_result.elected.iter().any(|e| e.candidate_id == *candidate_id);
+        let eliminated_round = rcv_result.rounds.iter()
+            .find(|r| r.eliminated == Some(*candidate_id))
+            .map(|r| r.round_number)
+            .or_else(|| {
+                final_round
+                    .filter(|_| rcv_result.winner.is_some() && !elected)
+                    .map(|r| r.round_number)
+            });
+
+        final_rankings.push(FinalRanking {
+            position: position + 1,
+            candidate_id: *candidate_id,
+            name: candidate.name.clone(),
+            votes,
+            percentage,
+            eliminated_round,
+        });
+    }
+
+    let rounds = include_rounds.then(|| {
+        let candidate_map: HashMap<Uuid, String> = candidates.iter().map(|c| (c.id, c.name.clone())).collect();
+        round_infos(&rcv_result.rounds, &candidate_map)
+    });
+
+    // Below quorum the count is informational only, so no winner is declared. A
+    // "None of the above" win means the voters rejected every real candidate.
+    let winners = if quorum_met { winners } else { Vec::new() };
+    let no_confidence = !winners.is_empty() && candidates.iter()
+        .find(|c| c.is_nota)
+        .is_some_and(|nota| rcv_result.winner == Some(nota.id) || first_choice_majority(&ballots, nota.id));
+    let (outcome, winners) = if winners.is_empty() {
+        ("undecided", winners)
+    } else if no_confidence {
+        ("no_confidence", Vec::new())
+    } else {
+        ("winner", winners)
+    };
+
+    Ok(PollResultsResponse {
+        poll_id: poll.id,
+        total_votes: ballots.len(),
+        status: status.to_string(),
+        outcome: outcome.to_string(),
+        winner: winners.first().cloned(),
+        winners,
+        quota: rcv_result.quota,
+        final_rankings,
+        rounds,
+        quorum: poll.quorum,
+        quorum_met,
+        stale_ballot_count: count_stale(&rcv_candidates, &ballots),
+        computed_at: now,
+        algorithm: tabulation_algorithm(&poll.poll_type).to_string(),
+    })
+}
+
+// Number of result emails sent concurrently
+const RESULTS_EMAIL_BATCH_SIZE: usize = 10;
+
+/// Email final results to every voter of a poll with a known address.
+/// Returns the number delivered and the recipients that failed.
+pub async fn send_results_emails(
+    pool: &sqlx::PgPool,
+    email_service: Arc<EmailService>,
+    poll: &PollResponse,
+    results: &PollResultsResponse,
+    winner: &WinnerInfo,
+    results_url: String,
+) -> Result<(usize, Vec<String>), sqlx::Error> {
+    let poll_owner_name = match User::find_by_id(pool, poll.user_id).await {
+        Ok(owner) => owner.and_then(|u| u.name),
+        Err(e) => {
+            tracing::error!("Database error finding poll owner: {}", e);
+            None
+        }
+    }
+    .unwrap_or_else(|| "Poll Organizer".to_string());
+
+    let voters = Voter::find_by_poll_id(pool, poll.id).await?;
+
+    // Anonymous voters only carry a placeholder address
+    let recipients: Vec<(String, String)> = voters
+        .into_iter()
+        .filter_map(|voter| {
+            let locale = email_locale(voter.locale.as_deref(), &poll.default_locale);
+            voter.email.map(|email| (email, locale))
+        })
+        .filter(|(email, _)| !email.starts_with("Anonymous-"))
+        .collect();
+
+    let mut sent = 0;
+    let mut failed_recipients = Vec::new();
+
+    for batch in recipients.chunks(RESULTS_EMAIL_BATCH_SIZE) {
+        let mut sends = tokio::task::JoinSet::new();
+
+        for (recipient, locale) in batch {
+            let email_service = email_service.clone();
+            let request = PollResultsRequest {
+                poll_title: poll.title.clone(),
+                poll_description: poll.description.clone(),
+                winner_name: winner.name.clone(),
+                total_votes: results.total_votes,
+                results_url: results_url.clone(),
+                poll_owner_name: poll_owner_name.clone(),
+                voter_name: None,
+                final_rankings: results.final_rankings.iter().map(|r| EmailFinalRanking {
+                    position: r.position,
+                    name: r.name.clone(),
+                    votes: r.votes,
+                    percentage: r.percentage,
+                }).collect(),
+                locale: locale.clone(),
+                to: recipient.clone(),
+            };
+
+            let to = recipient.clone();
+            sends.spawn(async move {
+                let delivered = match email_service.send_poll_results(request).await {
+                    Ok(response) => response.success,
+                    Err(e) => {
+                        tracing::error!("Failed to send poll results to {}: {}", to, e);
+                        false
+                    }
+                };
+                (to, delivered)
+            });
+        }
+
+        while let Some(outcome) = sends.join_next().await {
+            match outcome {
+                Ok((_, true)) => sent += 1,
+                Ok((recipient, false)) => failed_recipients.push(recipient),
+                Err(e) => tracing::error!("Poll results email task failed: {}", e),
+            }
+        }
+    }
+
+    Ok((sent, failed_recipients))
+}
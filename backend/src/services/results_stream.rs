@@ -0,0 +1,34 @@
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// Pending notifications a slow subscriber can fall behind by before it skips ahead
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Fan-out of "a ballot was submitted" signals to live results streams.
+/// Subscribers receive the poll id and recompute that poll's results themselves.
+#[derive(Clone)]
+pub struct ResultsEvents {
+    sender: broadcast::Sender<Uuid>,
+}
+
+impl ResultsEvents {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Uuid> {
+        self.sender.subscribe()
+    }
+
+    pub fn ballot_submitted(&self, poll_id: Uuid) {
+        // Sending only fails when nobody is listening, which is the common case
+        let _ = self.sender.send(poll_id);
+    }
+}
+
+impl Default for ResultsEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
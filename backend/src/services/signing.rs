@@ -0,0 +1,40 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::sync::Arc;
+
+/// HMAC-SHA256 signatures for values clients must not forge, such as ballot tokens and
+/// vote cookies. Keyed by `JWT_SECRET`, so rotating the secret also invalidates every
+/// outstanding ballot invitation: voters would need new links.
+#[derive(Clone)]
+pub struct TokenSigner {
+    key: Arc<[u8]>,
+}
+
+impl TokenSigner {
+    pub fn new(key: &str) -> Self {
+        Self { key: Arc::from(key.as_bytes()) }
+    }
+
+    /// Hex HMAC-SHA256 of `value`
+    pub fn sign(&self, value: &str) -> String {
+        hex::encode(self.mac(value).finalize().into_bytes())
+    }
+
+    /// Check a signature produced by `sign` in constant time
+    pub fn verify_signature(&self, value: &str, signature: &str) -> bool {
+        hex::decode(signature).is_ok_and(|bytes| self.mac(value).verify_slice(&bytes).is_ok())
+    }
+
+    /// Check a hex signature cut down to its leading characters, in constant time
+    pub fn verify_truncated_signature(&self, value: &str, signature: &str) -> bool {
+        hex::decode(signature).is_ok_and(|bytes| {
+            !bytes.is_empty() && self.mac(value).verify_truncated_left(&bytes).is_ok()
+        })
+    }
+
+    fn mac(&self, value: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(value.as_bytes());
+        mac
+    }
+}
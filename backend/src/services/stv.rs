@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use super::rcv::{
+    count_spoiled, order_by_votes, Ballot, Candidate, ElectedBy, ElectedCandidate, RcvResult, Round, SingleWinnerRCV,
+    TabulationError, TieBreakMethod,
+};
+
+/// Single transferable vote for multi-seat polls, with a Droop quota and fractional
+/// (Gregory) transfer of each winner's surplus. Elimination ties are broken the same way
+/// as in the single-winner count.
+pub struct MultiWinnerSTV {
+    candidates: Vec<Candidate>,
+    ballots: Vec<Ballot>,
+    seats: usize,
+    tie_breaker: SingleWinnerRCV,
+}
+
+impl MultiWinnerSTV {
+    pub fn new(candidates: Vec<Candidate>, ballots: Vec<Ballot>, seats: usize) -> Self {
+        let tie_breaker = SingleWinnerRCV::new(candidates.clone(), ballots.clone());
+        Self { candidates, ballots, seats, tie_breaker }
+    }
+
+    /// Engine for a specific poll, with the random tie-break seeded from its id
+    pub fn for_poll(poll_id: Uuid, candidates: Vec<Candidate>, ballots: Vec<Ballot>, seats: usize) -> Self {
+        Self::new(candidates, ballots, seats).with_tie_break_method(TieBreakMethod::random_for_poll(poll_id))
+    }
+
+    pub fn with_tie_break_method(mut self, method: TieBreakMethod) -> Self {
+        self.tie_breaker = self.tie_breaker.with_tie_break_method(method);
+        self
+    }
+
+    /// Droop quota: the fewest votes that only `seats` candidates can reach at once
+    pub fn quota(&self) -> f64 {
+        // Ballots naming only candidates no longer in the poll never count toward a seat
+        let candidate_ids: HashSet<Uuid> = self.candidates.iter().map(|c| c.id).collect();
+        let counted = self.ballots.iter()
+            .filter(|b| b.rankings.iter().any(|id| candidate_ids.contains(id)))
+            .count();
+        (counted as f64 / (self.seats + 1) as f64).floor() + 1.0
+    }
+
+    /// Fill the seats and return each winner with the round they were elected in
+    pub fn tabulate(&self) -> Result<RcvResult, TabulationError> {
+        self.tie_breaker.validate_ballots().map_err(TabulationError::InvalidBallot)?;
+
+        if self.candidates.len() < 2 {
+            return Err(TabulationError::InsufficientCandidates(self.candidates.len()));
+        }
+
+        let quota = self.quota();
+        let mut weights = vec![1.0; self.ballots.len()];
+        let mut rounds: Vec<Round> = Vec::new();
+        let mut elected: Vec<ElectedCandidate> = Vec::new();
+        let mut eliminated: HashSet<Uuid> = HashSet::new();
+        let mut elimination_order = Vec::new();
+        let mut round_number = 1;
+
+        loop {
+            let decided: HashSet<Uuid> = elected.iter().map(|e| e.candidate_id).chain(eliminated.iter().copied()).collect();
+            let mut vote_counts: HashMap<Uuid, f64> = self.candidates.iter()
+                .filter(|c| !decided.contains(&c.id))
+                .map(|c| (c.id, 0.0))
+                .collect();
+
+            // Each ballot counts, at its current weight, for its highest continuing choice
+            let mut holders: Vec<Option<Uuid>> = vec![None; self.ballots.len()];
+            let mut exhausted_count = 0;
+            for (index, ballot) in self.ballots.iter().enumerate().filter(|(_, b)| !b.rankings.is_empty()) {
+                match ballot.rankings.iter().find(|id| vote_counts.contains_key(id)) {
+                    Some(&candidate_id) => {
+                        *vote_counts.get_mut(&candidate_id).unwrap() += weights[index];
+                        holders[index] = Some(candidate_id);
+                    }
+                    None => exhausted_count += 1,
+                }
+            }
+
+            let total_votes: f64 = vote_counts.values().sum();
+            let seats_left = self.seats.saturating_sub(elected.len());
+            let mut round = Round {
+                round_number,
+                vote_counts: vote_counts.clone(),
+                eliminated: None,
+                winner: None,
+                exhausted_ballots: exhausted_count,
+                total_votes,
+                majority_threshold: quota,
+                tiebreak_reason: None,
+                tied_candidates: Vec::new(),
+            };
+
+            // As many candidates left as seats: they are all elected, those short of the
+            // quota by default
+            if vote_counts.len() <= seats_left {
+                let remaining: Vec<Uuid> = order_by_votes(&vote_counts, None).into_iter().rev().collect();
+                elected.extend(remaining.into_iter().map(|candidate_id| ElectedCandidate {
+                    candidate_id,
+                    round_number,
+                    votes: vote_counts[&candidate_id],
+                    elected_by: if vote_counts[&candidate_id] >= quota { ElectedBy::Quota } else { ElectedBy::Default },
+                }));
+                rounds.push(round);
+                break;
+            }
+
+            let reached: Vec<Uuid> = order_by_votes(&vote_counts, None).into_iter().rev()
+                .filter(|id| vote_counts[id] >= quota)
+                .take(seats_left)
+                .collect();
+
+            if reached.is_empty() {
+                let min_votes = vote_counts.values().copied().fold(f64::INFINITY, f64::min);
+                let mut tied_candidates: Vec<Uuid> = vote_counts.iter()
+                    .filter(|(_, &votes)| votes == min_votes)
+                    .map(|(&id, _)| id)
+                    .collect();
+                tied_candidates.sort();
+
+                let loser = if tied_candidates.len() == 1 {
+                    tied_candidates[0]
+                } else {
+                    let (loser, reason) = self.tie_breaker.break_tie_comprehensive(&tied_candidates, &rounds);
+                    round.tiebreak_reason = Some(reason);
+                    round.tied_candidates = tied_candidates;
+                    loser
+                };
+                round.eliminated = Some(loser);
+                eliminated.insert(loser);
+                elimination_order.push(loser);
+            } else {
+                for &candidate_id in &reached {
+                    let votes = vote_counts[&candidate_id];
+                    elected.push(ElectedCandidate { candidate_id, round_number, votes, elected_by: ElectedBy::Quota });
+
+                    // Pass on only the surplus, spread across every ballot that elected them
+                    let keep = (votes - quota) / votes;
+                    for (index, holder) in holders.iter().enumerate() {
+                        if *holder == Some(candidate_id) {
+                            weights[index] *= keep;
+                        }
+                    }
+                }
+            }
+
+            rounds.push(round);
+            if elected.len() >= self.seats {
+                break;
+            }
+
+            round_number += 1;
+
+            // Every round elects or eliminates someone, so this bounds the count
+            if round_number > self.candidates.len() * 2 {
+                return Err(TabulationError::TooManyRounds);
+            }
+        }
+
+        // Losers still standing when the seats filled go above those eliminated earlier,
+        // and the winners finish the order so the first elected comes last
+        let winner_ids: HashSet<Uuid> = elected.iter().map(|e| e.candidate_id).collect();
+        let final_votes: HashMap<Uuid, f64> = rounds.last()
+            .map(|round| round.vote_counts.iter()
+                .filter(|(id, _)| !winner_ids.contains(id))
+                .map(|(&id, &votes)| (id, votes))
+                .collect())
+            .unwrap_or_default();
+        elimination_order.extend(order_by_votes(&final_votes, None));
+        elimination_order.extend(elected.iter().rev().map(|e| e.candidate_id));
+
+        Ok(RcvResult {
+            winner: elected.first().map(|e| e.candidate_id),
+            total_ballots: self.ballots.len(),
+            exhausted_ballots: rounds.last().map(|r| r.exhausted_ballots).unwrap_or(0),
+            spoiled_ballots: count_spoiled(&self.ballots),
+            rounds,
+            elimination_order,
+            elected,
+            quota: Some(quota),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_candidates(count: usize) -> Vec<Candidate> {
+        (1..=count)
+            .map(|i| Candidate { id: Uuid::from_u128(i as u128), name: format!("Candidate {}", i) })
+            .collect()
+    }
+
+    fn ballots(orders: &[(&[usize], usize)], candidates: &[Candidate]) -> Vec<Ballot> {
+        orders.iter()
+            .flat_map(|&(order, copies)| std::iter::repeat_n(order, copies))
+            .map(|order| Ballot {
+                id: Uuid::new_v4(),
+                voter_id: Uuid::new_v4(),
+                rankings: order.iter().map(|&i| candidates[i].id).collect(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_surplus_transfer_elects_second_choice() {
+        let candidates = create_test_candidates(4);
+        let ids: Vec<Uuid> = candidates.iter().map(|c| c.id).collect();
+
+        // 13 ballots, 2 seats: quota 5. Candidate 0's surplus of 3 passes to candidate 1,
+        // who reaches the quota once candidate 3 is out and their ballots transfer too
+        let ballots = ballots(&[(&[0, 1], 8), (&[2], 3), (&[3, 1], 2)], &candidates);
+        let result = MultiWinnerSTV::new(candidates, ballots, 2).tabulate().unwrap();
+
+        assert_eq!(result.quota, Some(5.0));
+        assert_eq!(result.elected.len(), 2);
+        assert_eq!(result.elected[0].candidate_id, ids[0]);
+        assert_eq!(result.elected[0].round_number, 1);
+        assert_eq!(result.elected[1].candidate_id, ids[1]);
+        assert_eq!(result.elected[1].round_number, 3);
+        assert_eq!(result.rounds[1].vote_counts[&ids[1]], 3.0);
+        assert_eq!(result.rounds[1].eliminated, Some(ids[3]));
+        assert_eq!(result.winner, Some(ids[0]));
+        assert_eq!(result.elimination_order.last(), Some(&ids[0]));
+    }
+
+    #[test]
+    fn test_seats_filled_by_remaining_candidates() {
+        let candidates = create_test_candidates(5);
+
+        // Nobody reaches the quota of 3, so the last two standing take the seats
+        let ballots = ballots(&[(&[0], 2), (&[1], 2), (&[2], 1), (&[3], 1), (&[4], 1)], &candidates);
+        let result = MultiWinnerSTV::new(candidates.clone(), ballots, 2).tabulate().unwrap();
+
+        let elected: Vec<Uuid> = result.elected.iter().map(|e| e.candidate_id).collect();
+        assert_eq!(result.rounds.len(), 4);
+        assert!(elected.contains(&candidates[0].id) && elected.contains(&candidates[1].id));
+        assert!(result.elected.iter().all(|e| e.round_number == 4 && e.votes < 3.0));
+        assert!(result.elected.iter().all(|e| e.elected_by == ElectedBy::Default));
+    }
+
+    #[test]
+    fn test_last_seat_filled_by_default() {
+        let candidates = create_test_candidates(3);
+        let ids: Vec<Uuid> = candidates.iter().map(|c| c.id).collect();
+
+        // 8 ballots, 2 seats: quota 3. Candidate 0 reaches it; candidate 2 is eliminated and
+        // candidate 1 is seated with 2 votes as the only one left
+        let ballots = ballots(&[(&[0], 5), (&[1], 2), (&[2], 1)], &candidates);
+        let result = MultiWinnerSTV::new(candidates, ballots, 2).tabulate().unwrap();
+
+        assert_eq!(result.quota, Some(3.0));
+        assert_eq!(result.elected.len(), 2);
+        assert_eq!(result.elected[0].candidate_id, ids[0]);
+        assert_eq!(result.elected[0].elected_by, ElectedBy::Quota);
+        assert_eq!(result.elected[1].candidate_id, ids[1]);
+        assert_eq!(result.elected[1].votes, 2.0);
+        assert_eq!(result.elected[1].elected_by, ElectedBy::Default);
+    }
+}
@@ -0,0 +1,103 @@
+use axum::{
+    body::{Body, to_bytes},
+    http::{Method, Request, StatusCode},
+};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+
+mod common;
+use common::*;
+
+// Stand-in email service that records invitations and reports itself healthy
+async fn spawn_stub_email_service() -> (String, Arc<Mutex<Vec<Value>>>) {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let recorded = received.clone();
+
+    let stub = axum::Router::new()
+        .route(
+            "/api/email/voter-invitation",
+            axum::routing::post(move |axum::Json(body): axum::Json<Value>| {
+                let recorded = recorded.clone();
+                async move {
+                    let to = body["to"].clone();
+                    recorded.lock().unwrap().push(body);
+                    axum::Json(json!({"success": true, "data": {"messageId": "stub-message", "recipient": to}}))
+                }
+            }),
+        )
+        .route("/health", axum::routing::get(|| async { "ok" }));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, stub).await.unwrap();
+    });
+
+    (format!("http://{}", addr), received)
+}
+
+async fn register(app: &axum::Router, email: &str) -> String {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/auth/register")
+        .header("content-type", "application/json")
+        .body(Body::from(json!({"email": email, "password": "testpassword123", "name": "Ops"}).to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    result["data"]["token"].as_str().unwrap().to_string()
+}
+
+async fn send(app: &axum::Router, method: Method, uri: &str, token: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("authorization", format!("Bearer {}", token))
+        .header("content-type", "application/json")
+        .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[sqlx::test]
+async fn test_admin_email_test_and_health(pool: PgPool) {
+    let (email_url, received) = spawn_stub_email_service().await;
+    std::env::set_var("EMAIL_SERVICE_URL", &email_url);
+    std::env::set_var("EMAIL_SERVICE_API_KEY", "test-api-key");
+
+    let app = create_test_app(pool.clone()).await;
+    let pollster_token = register(&app, "pollster@example.com").await;
+    let admin_token = register(&app, "ops@example.com").await;
+    sqlx::query("UPDATE users SET role = 'admin' WHERE email = 'ops@example.com'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let test_email = json!({"to": "inbox@example.com"});
+
+    let (status, body) = send(&app, Method::POST, "/api/admin/email/test", &pollster_token, Some(test_email.clone())).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["error"]["code"], "FORBIDDEN");
+
+    let (status, body) = send(&app, Method::POST, "/api/admin/email/test", &admin_token, Some(test_email)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["success"], true);
+    assert_eq!(body["data"]["data"]["messageId"], "stub-message");
+
+    let sent = received.lock().unwrap().clone();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0]["to"], "inbox@example.com");
+    assert_eq!(sent[0]["pollOwnerEmail"], "ops@example.com");
+
+    let (status, body) = send(&app, Method::GET, "/api/admin/email/health", &admin_token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["status"], "ok");
+}
@@ -1,9 +1,12 @@
 use axum::{
     body::{Body, to_bytes},
+    extract::ConnectInfo,
     http::{Request, StatusCode},
 };
+use rankedchoice_api::middleware::rate_limit::DEFAULT_AUTH_RATE_LIMIT;
 use serde_json::{json, Value};
 use sqlx::PgPool;
+use std::net::SocketAddr;
 use tower::ServiceExt;
 
 mod common;
@@ -145,6 +148,101 @@ async fn test_register_invalid_data(pool: PgPool) {
     assert!(response.status() == StatusCode::BAD_REQUEST || response.status() == StatusCode::UNPROCESSABLE_ENTITY);
 }
 
+#[sqlx::test]
+async fn test_register_password_strength(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let register = |email: &str, password: &str| {
+        let user_data = json!({
+            "email": email,
+            "password": password,
+            "name": "Password User"
+        });
+        Request::builder()
+            .method("POST")
+            .uri("/api/auth/register")
+            .header("content-type", "application/json")
+            .body(Body::from(user_data.to_string()))
+            .unwrap()
+    };
+
+    for weak in ["abc", "12345678", "aaaaaaaa"] {
+        let response = app.clone().oneshot(register("weak@example.com", weak)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let response_data: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response_data["success"], false);
+        assert_eq!(response_data["error"]["code"], "WEAK_PASSWORD");
+    }
+
+    let response = app.oneshot(register("strong@example.com", "correct-horse-42")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[sqlx::test]
+async fn test_verify_email_link_marks_user_verified(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let user_data = json!({
+        "email": "verify@example.com",
+        "password": "testpassword123",
+        "name": "Verify User"
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/auth/register")
+                .header("content-type", "application/json")
+                .body(Body::from(user_data.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let response_data: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response_data["data"]["user"]["email_verified"], false);
+
+    let token: String = sqlx::query_scalar(
+        "SELECT t.token FROM auth_tokens t JOIN users u ON u.id = t.user_id WHERE u.email = $1 AND t.token_type = 'email_verification'",
+    )
+    .bind("verify@example.com")
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    let verify = |app: axum::Router| {
+        let request = Request::builder()
+            .method("GET")
+            .uri(format!("/api/auth/verify-email/{}", token))
+            .body(Body::empty())
+            .unwrap();
+        app.oneshot(request)
+    };
+
+    let response = verify(app.clone()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let verified: bool = sqlx::query_scalar("SELECT email_verified FROM users WHERE email = $1")
+        .bind("verify@example.com")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert!(verified);
+
+    // Tokens are single use
+    let response = verify(app).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let response_data: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response_data["error"]["code"], "INVALID_TOKEN");
+}
+
 #[sqlx::test]
 async fn test_login_success(pool: PgPool) {
     let app = create_test_app(pool.clone()).await;
@@ -198,6 +296,206 @@ async fn test_login_success(pool: PgPool) {
     assert!(response_data["data"]["refresh_token"].is_string());
 }
 
+async fn post_auth(app: &axum::Router, uri: &str, body: Value) -> Value {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[sqlx::test]
+async fn test_login_updates_last_login_shown_by_me(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let credentials = json!({"email": "me@example.com", "password": "testpassword123"});
+    let registered = post_auth(&app, "/api/auth/register", credentials.clone()).await;
+    assert!(registered["data"]["user"]["last_login_at"].is_null());
+
+    let logged_in = post_auth(&app, "/api/auth/login", credentials).await;
+    let last_login_at = logged_in["data"]["user"]["last_login_at"].as_str().unwrap().to_string();
+    let token = logged_in["data"]["token"].as_str().unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri("/api/auth/me")
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let me: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(me["data"]["email"], "me@example.com");
+    assert_eq!(me["data"]["last_login_at"], last_login_at);
+
+    // Without a token there's no profile to show
+    let response = app
+        .oneshot(Request::builder().method("GET").uri("/api/auth/me").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+async fn put_me(app: &axum::Router, token: &str, body: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri("/api/auth/me")
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[sqlx::test]
+async fn test_update_profile_name(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let registered = post_auth(
+        &app,
+        "/api/auth/register",
+        json!({"email": "rename@example.com", "password": "testpassword123", "name": "Old Name"}),
+    )
+    .await;
+    let token = registered["data"]["token"].as_str().unwrap();
+
+    let (status, body) = put_me(&app, token, json!({"name": "New Name"})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["name"], "New Name");
+    assert_eq!(body["data"]["email"], "rename@example.com");
+
+    let name: Option<String> = sqlx::query_scalar("SELECT name FROM users WHERE email = 'rename@example.com'")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(name.as_deref(), Some("New Name"));
+}
+
+#[sqlx::test]
+async fn test_update_profile_rejects_duplicate_email(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    post_auth(&app, "/api/auth/register", json!({"email": "taken@example.com", "password": "testpassword123"})).await;
+    let registered = post_auth(
+        &app,
+        "/api/auth/register",
+        json!({"email": "mover@example.com", "password": "testpassword123"}),
+    )
+    .await;
+    let token = registered["data"]["token"].as_str().unwrap();
+
+    let (status, body) = put_me(&app, token, json!({"email": "taken@example.com"})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "USER_ALREADY_EXISTS");
+
+    // A fresh address is accepted and has to be verified again
+    sqlx::query("UPDATE users SET email_verified = true WHERE email = 'mover@example.com'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let (status, body) = put_me(&app, token, json!({"email": "moved@example.com"})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["email"], "moved@example.com");
+    assert_eq!(body["data"]["email_verified"], false);
+}
+
+async fn delete_me(app: &axum::Router, token: &str, password: &str) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri("/api/auth/me")
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({"password": password}).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[sqlx::test]
+async fn test_delete_account_removes_polls(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let registered = post_auth(
+        &app,
+        "/api/auth/register",
+        json!({"email": "leaving@example.com", "password": "testpassword123"}),
+    )
+    .await;
+    let token = registered["data"]["token"].as_str().unwrap();
+    let user_id: uuid::Uuid = registered["data"]["user"]["id"].as_str().unwrap().parse().unwrap();
+
+    let poll_id: uuid::Uuid = sqlx::query_scalar(
+        "INSERT INTO polls (user_id, title, poll_type, num_winners) VALUES ($1, 'Doomed', 'single_winner', 1) RETURNING id",
+    )
+    .bind(user_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO candidates (poll_id, name, slug, display_order) VALUES ($1, 'A', 'a', 1)")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let (status, body) = delete_me(&app, token, "wrongpassword").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["error"]["code"], "INVALID_CREDENTIALS");
+
+    let (status, _) = delete_me(&app, token, "testpassword123").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let polls: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM polls WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    let candidates: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM candidates WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    let users: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE id = $1")
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!((polls, candidates, users), (0, 0, 0));
+}
+
 #[sqlx::test]
 async fn test_login_invalid_credentials(pool: PgPool) {
     let app = create_test_app(pool.clone()).await;
@@ -401,4 +699,39 @@ async fn test_api_response_format(pool: PgPool) {
     assert!(response_data["metadata"].is_object());
     assert!(response_data["metadata"]["timestamp"].is_string());
     assert!(response_data["metadata"]["version"].is_string());
-} 
\ No newline at end of file
+}
+
+#[sqlx::test]
+async fn test_login_rate_limited(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let login_data = json!({
+        "email": "bruteforce@example.com",
+        "password": "guess"
+    });
+    let client_addr: SocketAddr = "198.51.100.23:5000".parse().unwrap();
+
+    let mut statuses = Vec::new();
+    let mut last_body = Value::Null;
+    for _ in 0..=DEFAULT_AUTH_RATE_LIMIT {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("/api/auth/login")
+            .header("content-type", "application/json")
+            .body(Body::from(login_data.to_string()))
+            .unwrap();
+        request.extensions_mut().insert(ConnectInfo(client_addr));
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        statuses.push(response.status());
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        last_body = serde_json::from_slice(&body).unwrap();
+    }
+
+    let (allowed, rejected) = statuses.split_at(DEFAULT_AUTH_RATE_LIMIT as usize);
+    assert!(allowed.iter().all(|status| *status == StatusCode::UNAUTHORIZED));
+    assert_eq!(rejected, [StatusCode::TOO_MANY_REQUESTS]);
+    assert_eq!(last_body["success"], false);
+    assert_eq!(last_body["error"]["code"], "RATE_LIMITED");
+}
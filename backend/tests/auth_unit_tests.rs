@@ -3,12 +3,12 @@ use uuid::Uuid;
 
 use rankedchoice_api::{
     models::user::{CreateUserRequest, LoginRequest, User},
-    services::auth::{AuthError, AuthService},
+    services::auth::{resolve_jwt_secret, token_ttls, AuthError, AuthService, MIN_JWT_SECRET_LEN},
 };
 
 #[sqlx::test]
 async fn test_password_hashing(pool: PgPool) {
-    let auth_service = AuthService::new(pool);
+    let auth_service = AuthService::new(pool).unwrap();
     
     let password = "test_password_123";
     let hash1 = auth_service.hash_password(password).unwrap();
@@ -27,7 +27,7 @@ async fn test_password_hashing(pool: PgPool) {
 
 #[sqlx::test]
 async fn test_jwt_token_generation_and_verification(pool: PgPool) {
-    let auth_service = AuthService::new(pool);
+    let auth_service = AuthService::new(pool).unwrap();
     
     let user = User {
         id: Uuid::new_v4(),
@@ -35,8 +35,10 @@ async fn test_jwt_token_generation_and_verification(pool: PgPool) {
         password_hash: "hash".to_string(),
         name: Some("Test User".to_string()),
         role: "pollster".to_string(),
+        email_verified: false,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
+        last_login_at: None,
     };
     
     // Generate tokens
@@ -63,9 +65,46 @@ async fn test_jwt_token_generation_and_verification(pool: PgPool) {
     assert!(access_claims.exp < refresh_claims.exp);
 }
 
+#[sqlx::test]
+async fn test_token_lifetimes_configurable(pool: PgPool) {
+    let (access_ttl, refresh_ttl) = token_ttls(Some("15"), Some("1"));
+    let auth_service = AuthService::new(pool).unwrap().with_token_ttls(access_ttl, refresh_ttl);
+
+    let user = User {
+        id: Uuid::new_v4(),
+        email: "ttl@example.com".to_string(),
+        password_hash: "hash".to_string(),
+        name: None,
+        role: "pollster".to_string(),
+        email_verified: false,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+        last_login_at: None,
+    };
+
+    let access_claims = auth_service
+        .verify_token(&auth_service.generate_token(&user, false).unwrap())
+        .unwrap();
+    let refresh_claims = auth_service
+        .verify_token(&auth_service.generate_token(&user, true).unwrap())
+        .unwrap();
+
+    assert_eq!(access_claims.exp - access_claims.iat, 15 * 60);
+    assert_eq!(refresh_claims.exp - refresh_claims.iat, 24 * 60 * 60);
+}
+
+#[test]
+fn test_token_ttls_fall_back_to_defaults() {
+    let defaults = (chrono::Duration::hours(24), chrono::Duration::days(7));
+    assert_eq!(token_ttls(None, None), defaults);
+    assert_eq!(token_ttls(Some("0"), Some("soon")), defaults);
+    // Values too large for a Duration are ignored rather than panicking
+    assert_eq!(token_ttls(Some(&i64::MAX.to_string()), Some(&i64::MAX.to_string())), defaults);
+}
+
 #[sqlx::test]
 async fn test_invalid_jwt_token(pool: PgPool) {
-    let auth_service = AuthService::new(pool);
+    let auth_service = AuthService::new(pool).unwrap();
     
     // Test completely invalid token
     let result = auth_service.verify_token("invalid.token.here");
@@ -82,7 +121,7 @@ async fn test_invalid_jwt_token(pool: PgPool) {
 
 #[sqlx::test]
 async fn test_user_registration_service(pool: PgPool) {
-    let auth_service = AuthService::new(pool.clone());
+    let auth_service = AuthService::new(pool.clone()).unwrap();
     
     let request = CreateUserRequest {
         email: "service_test@example.com".to_string(),
@@ -115,7 +154,7 @@ async fn test_user_registration_service(pool: PgPool) {
 
 #[sqlx::test]
 async fn test_user_login_service(pool: PgPool) {
-    let auth_service = AuthService::new(pool.clone());
+    let auth_service = AuthService::new(pool.clone()).unwrap();
     
     // First register a user
     let register_request = CreateUserRequest {
@@ -146,7 +185,7 @@ async fn test_user_login_service(pool: PgPool) {
 
 #[sqlx::test]
 async fn test_duplicate_user_registration(pool: PgPool) {
-    let auth_service = AuthService::new(pool);
+    let auth_service = AuthService::new(pool).unwrap();
     
     let request1 = CreateUserRequest {
         email: "duplicate@example.com".to_string(),
@@ -170,7 +209,7 @@ async fn test_duplicate_user_registration(pool: PgPool) {
 
 #[sqlx::test]
 async fn test_login_invalid_credentials(pool: PgPool) {
-    let auth_service = AuthService::new(pool);
+    let auth_service = AuthService::new(pool).unwrap();
     
     // Register a user
     let register_request = CreateUserRequest {
@@ -202,7 +241,7 @@ async fn test_login_invalid_credentials(pool: PgPool) {
 
 #[sqlx::test]
 async fn test_refresh_token_service(pool: PgPool) {
-    let auth_service = AuthService::new(pool.clone());
+    let auth_service = AuthService::new(pool.clone()).unwrap();
     
     // Register a user and get tokens
     let register_request = CreateUserRequest {
@@ -237,7 +276,7 @@ async fn test_refresh_token_service(pool: PgPool) {
 
 #[sqlx::test]
 async fn test_refresh_token_invalid(pool: PgPool) {
-    let auth_service = AuthService::new(pool);
+    let auth_service = AuthService::new(pool).unwrap();
     
     // Test with invalid refresh token
     let result = auth_service.refresh_token("invalid.refresh.token").await;
@@ -246,4 +285,15 @@ async fn test_refresh_token_invalid(pool: PgPool) {
     // Test with empty token
     let result = auth_service.refresh_token("").await;
     assert!(result.is_err());
-} 
\ No newline at end of file
+} 
+#[test]
+fn test_short_jwt_secret_rejected() {
+    let short = "x".repeat(MIN_JWT_SECRET_LEN - 1);
+    assert!(matches!(resolve_jwt_secret(Some(&short), false), Err(AuthError::InsecureJwtSecret(_))));
+
+    // Only an explicit opt-in lets a short secret through
+    assert_eq!(resolve_jwt_secret(Some(&short), true).unwrap(), short);
+
+    let strong = "y".repeat(MIN_JWT_SECRET_LEN);
+    assert_eq!(resolve_jwt_secret(Some(&strong), false).unwrap(), strong);
+}
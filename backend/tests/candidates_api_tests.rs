@@ -6,6 +6,8 @@ use serde_json::{json, Value};
 use sqlx::PgPool;
 use tower::ServiceExt;
 use uuid::Uuid;
+use rankedchoice_api::models::ballot::Voter;
+use rankedchoice_api::models::candidate::{Candidate, CreateCandidateRequest};
 
 mod common;
 use common::*;
@@ -32,7 +34,7 @@ async fn test_add_candidate_success(pool: PgPool) {
     
     let request = Request::builder()
         .method(Method::POST)
-        .uri(&format!("/api/polls/{}/candidates", poll_id))
+        .uri(format!("/api/polls/{}/candidates", poll_id))
         .header("content-type", "application/json")
         .body(Body::from(request_data.to_string()))
         .unwrap();
@@ -65,7 +67,7 @@ async fn test_add_candidate_validation_empty_name(pool: PgPool) {
     
     let request = Request::builder()
         .method(Method::POST)
-        .uri(&format!("/api/polls/{}/candidates", poll_id))
+        .uri(format!("/api/polls/{}/candidates", poll_id))
         .header("content-type", "application/json")
         .body(Body::from(request_data.to_string()))
         .unwrap();
@@ -94,7 +96,7 @@ async fn test_add_candidate_validation_whitespace_name(pool: PgPool) {
     
     let request = Request::builder()
         .method(Method::POST)
-        .uri(&format!("/api/polls/{}/candidates", poll_id))
+        .uri(format!("/api/polls/{}/candidates", poll_id))
         .header("content-type", "application/json")
         .body(Body::from(request_data.to_string()))
         .unwrap();
@@ -117,7 +119,7 @@ async fn test_list_candidates(pool: PgPool) {
     let poll_id = get_test_poll_id();
     let request = Request::builder()
         .method(Method::GET)
-        .uri(&format!("/api/polls/{}/candidates", poll_id))
+        .uri(format!("/api/polls/{}/candidates", poll_id))
         .body(Body::empty())
         .unwrap();
 
@@ -142,7 +144,7 @@ async fn test_update_candidate_validation_empty_name(pool: PgPool) {
     
     let request = Request::builder()
         .method(Method::PUT)
-        .uri(&format!("/api/candidates/{}", candidate_id))
+        .uri(format!("/api/candidates/{}", candidate_id))
         .header("content-type", "application/json")
         .body(Body::from(request_data.to_string()))
         .unwrap();
@@ -170,7 +172,7 @@ async fn test_update_candidate_not_found(pool: PgPool) {
     
     let request = Request::builder()
         .method(Method::PUT)
-        .uri(&format!("/api/candidates/{}", candidate_id))
+        .uri(format!("/api/candidates/{}", candidate_id))
         .header("content-type", "application/json")
         .body(Body::from(request_data.to_string()))
         .unwrap();
@@ -194,7 +196,7 @@ async fn test_delete_candidate_not_found(pool: PgPool) {
     let candidate_id = get_test_candidate_id();
     let request = Request::builder()
         .method(Method::DELETE)
-        .uri(&format!("/api/candidates/{}", candidate_id))
+        .uri(format!("/api/candidates/{}", candidate_id))
         .body(Body::empty())
         .unwrap();
 
@@ -219,7 +221,7 @@ async fn test_reorder_candidates_validation_empty_list(pool: PgPool) {
     
     let request = Request::builder()
         .method(Method::PUT)
-        .uri(&format!("/api/polls/{}/candidates/order", poll_id))
+        .uri(format!("/api/polls/{}/candidates/order", poll_id))
         .header("content-type", "application/json")
         .body(Body::from(request_data.to_string()))
         .unwrap();
@@ -250,7 +252,7 @@ async fn test_reorder_candidates_with_valid_ids(pool: PgPool) {
     
     let request = Request::builder()
         .method(Method::PUT)
-        .uri(&format!("/api/polls/{}/candidates/order", poll_id))
+        .uri(format!("/api/polls/{}/candidates/order", poll_id))
         .header("content-type", "application/json")
         .body(Body::from(request_data.to_string()))
         .unwrap();
@@ -272,7 +274,7 @@ async fn test_candidate_api_response_format(pool: PgPool) {
     let poll_id = get_test_poll_id();
     let request = Request::builder()
         .method(Method::GET)
-        .uri(&format!("/api/polls/{}/candidates", poll_id))
+        .uri(format!("/api/polls/{}/candidates", poll_id))
         .body(Body::empty())
         .unwrap();
 
@@ -300,7 +302,7 @@ async fn test_candidate_json_request_parsing(pool: PgPool) {
     // Test that the endpoint properly handles JSON parsing
     let request = Request::builder()
         .method(Method::POST)
-        .uri(&format!("/api/polls/{}/candidates", poll_id))
+        .uri(format!("/api/polls/{}/candidates", poll_id))
         .header("content-type", "application/json")
         .body(Body::from("invalid json"))
         .unwrap();
@@ -309,4 +311,779 @@ async fn test_candidate_json_request_parsing(pool: PgPool) {
     
     // Should return some kind of error for invalid JSON
     assert_ne!(response.status(), StatusCode::OK);
-} 
\ No newline at end of file
+}
+
+#[sqlx::test]
+async fn test_invalid_json_uses_error_envelope(pool: PgPool) {
+    let app = create_test_app(pool).await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/polls/{}/candidates", get_test_poll_id()))
+        .header("content-type", "application/json")
+        .body(Body::from("{\"name\": "))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "INVALID_JSON");
+    assert!(result["error"]["message"].is_string());
+    assert!(result["metadata"]["timestamp"].is_string());
+}
+
+#[sqlx::test]
+async fn test_add_candidates_bulk(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = test_user_token(&pool).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    create_test_candidates(&pool, poll_id).await;
+
+    let request_data = json!([
+        {"name": "Candidate D", "description": "Description D"},
+        {"name": "Candidate E"}
+    ]);
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/polls/{}/candidates/bulk", poll_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(request_data.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    let candidates = result["data"].as_array().unwrap();
+    assert_eq!(candidates.len(), 5);
+    assert_eq!(candidates[3]["name"], "Candidate D");
+    assert_eq!(candidates[3]["display_order"], 4);
+    assert_eq!(candidates[4]["name"], "Candidate E");
+    assert_eq!(candidates[4]["display_order"], 5);
+}
+
+#[sqlx::test]
+async fn test_add_candidates_bulk_blank_name(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = test_user_token(&pool).await;
+
+    let poll_id = create_test_poll(&pool).await;
+
+    let request_data = json!([
+        {"name": "Candidate D"},
+        {"name": "   "}
+    ]);
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/polls/{}/candidates/bulk", poll_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(request_data.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+
+    // Nothing should have been inserted
+    let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM candidates WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count.0, 0);
+}
+
+#[sqlx::test]
+async fn test_add_candidates_duplicate_name(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = test_user_token(&pool).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    create_test_candidates(&pool, poll_id).await;
+
+    // Matches an existing candidate once trimmed and lowercased
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/polls/{}/candidates/bulk", poll_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!([{"name": "Candidate D"}, {"name": " candidate a"}]).to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert_eq!(result["error"]["fields"][0]["field"], "candidates[1].name");
+
+    // A single add is checked the same way
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/polls/{}/candidates", poll_id))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({"name": "CANDIDATE B"}).to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM candidates WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count.0, 3);
+}
+
+#[sqlx::test]
+async fn test_reorder_candidates_missing_id(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let request_data = json!({
+        "candidate_order": [candidate_ids[2], candidate_ids[0]]
+    });
+
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}/candidates/order", poll_id))
+        .header("content-type", "application/json")
+        .body(Body::from(request_data.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+
+    // Original ordering is untouched
+    let order: (i32,) = sqlx::query_as("SELECT display_order FROM candidates WHERE id = $1")
+        .bind(candidate_ids[2])
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(order.0, 3);
+}
+
+#[sqlx::test]
+async fn test_reorder_candidates_extra_id(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let request_data = json!({
+        "candidate_order": [candidate_ids[2], candidate_ids[1], candidate_ids[0], Uuid::new_v4()]
+    });
+
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}/candidates/order", poll_id))
+        .header("content-type", "application/json")
+        .body(Body::from(request_data.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+}
+
+#[sqlx::test]
+async fn test_reorder_candidates_full_permutation(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let request_data = json!({
+        "candidate_order": [candidate_ids[2], candidate_ids[0], candidate_ids[1]]
+    });
+
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}/candidates/order", poll_id))
+        .header("content-type", "application/json")
+        .body(Body::from(request_data.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    let candidates = result["data"].as_array().unwrap();
+    assert_eq!(candidates[0]["id"], candidate_ids[2].to_string());
+    assert_eq!(candidates[1]["id"], candidate_ids[0].to_string());
+    assert_eq!(candidates[2]["id"], candidate_ids[1].to_string());
+}
+
+#[sqlx::test]
+async fn test_candidate_image_url_shown_on_ballot(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    create_test_candidates(&pool, poll_id).await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/polls/{}/candidates", poll_id))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({
+            "name": "Candidate D",
+            "image_url": "https://cdn.example.com/candidates/d.png"
+        }).to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("ballot@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/vote/{}", voter.ballot_token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    let candidates = result["data"]["poll"]["candidates"].as_array().unwrap();
+    assert_eq!(candidates[3]["name"], "Candidate D");
+    assert_eq!(candidates[3]["image_url"], "https://cdn.example.com/candidates/d.png");
+    assert!(candidates[0]["image_url"].is_null());
+}
+
+#[sqlx::test]
+async fn test_candidate_image_url_cleared_with_null(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let poll_id = create_test_poll(&pool).await;
+
+    let (status, created) = post_candidates(
+        &app,
+        format!("/api/polls/{}/candidates", poll_id),
+        json!({"name": "Candidate D", "image_url": "https://cdn.example.com/candidates/d.png"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let candidate_uri = format!("/api/candidates/{}", created["data"]["id"].as_str().unwrap());
+
+    // Leaving the field out keeps the image; null removes it
+    for (body, expected) in [
+        (json!({"description": "Updated"}), json!("https://cdn.example.com/candidates/d.png")),
+        (json!({"image_url": null}), Value::Null),
+    ] {
+        let request = Request::builder()
+            .method(Method::PUT)
+            .uri(&candidate_uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["data"]["image_url"], expected);
+    }
+}
+
+#[sqlx::test]
+async fn test_candidate_statement_cleared_with_null_or_empty(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let poll_id = create_test_poll(&pool).await;
+
+    let (status, created) = post_candidates(
+        &app,
+        format!("/api/polls/{}/candidates", poll_id),
+        json!({"name": "Candidate D", "statement": "Fix the roads"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let candidate_uri = format!("/api/candidates/{}", created["data"]["id"].as_str().unwrap());
+
+    // Leaving the field out keeps the statement; null or an empty string removes it
+    for (body, expected) in [
+        (json!({"description": "Updated"}), json!("Fix the roads")),
+        (json!({"statement": null}), Value::Null),
+        (json!({"statement": "Fix the bridges"}), json!("Fix the bridges")),
+        (json!({"statement": ""}), Value::Null),
+    ] {
+        let request = Request::builder()
+            .method(Method::PUT)
+            .uri(&candidate_uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["data"]["statement"], expected);
+    }
+}
+
+#[sqlx::test]
+async fn test_candidate_affiliation_cleared_with_null(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let poll_id = create_test_poll(&pool).await;
+
+    let (status, created) = post_candidates(
+        &app,
+        format!("/api/polls/{}/candidates", poll_id),
+        json!({"name": "Candidate D", "affiliation": "Green"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let candidate_uri = format!("/api/candidates/{}", created["data"]["id"].as_str().unwrap());
+
+    // Leaving the field out keeps the party; null makes the candidate unaffiliated
+    for (body, expected) in [
+        (json!({"description": "Updated"}), json!("Green")),
+        (json!({"affiliation": null}), Value::Null),
+    ] {
+        let request = Request::builder()
+            .method(Method::PUT)
+            .uri(&candidate_uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["data"]["affiliation"], expected);
+    }
+}
+
+#[sqlx::test]
+async fn test_candidate_image_url_must_be_http(pool: PgPool) {
+    let app = create_test_app(pool).await;
+
+    for image_url in ["not a url", "javascript:alert(1)", "ftp://example.com/d.png"] {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/api/polls/{}/candidates", get_test_poll_id()))
+            .header("content-type", "application/json")
+            .body(Body::from(json!({"name": "Candidate", "image_url": image_url}).to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    }
+}
+
+// Fill a test poll with `count` candidates
+async fn create_poll_with_candidates(pool: &PgPool, count: i32) -> Uuid {
+    let poll_id = create_test_poll(pool).await;
+    sqlx::query(
+        "INSERT INTO candidates (poll_id, name, slug, display_order) \
+         SELECT $1, 'Candidate ' || n, 'candidate-' || n, n FROM generate_series(1, $2) AS n",
+    )
+    .bind(poll_id)
+    .bind(count)
+    .execute(pool)
+    .await
+    .unwrap();
+    poll_id
+}
+
+async fn post_candidates(app: &axum::Router, uri: String, request_data: Value) -> (StatusCode, Value) {
+    send_candidates(app, uri, None, request_data).await
+}
+
+async fn post_candidates_as(app: &axum::Router, uri: String, token: &str, request_data: Value) -> (StatusCode, Value) {
+    send_candidates(app, uri, Some(token), request_data).await
+}
+
+async fn send_candidates(app: &axum::Router, uri: String, token: Option<&str>, request_data: Value) -> (StatusCode, Value) {
+    let mut request = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header("content-type", "application/json");
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {}", token));
+    }
+    let request = request.body(Body::from(request_data.to_string())).unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[sqlx::test]
+async fn test_add_candidate_enforces_maximum(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    // The 100th candidate is allowed, the 101st is not
+    let poll_id = create_poll_with_candidates(&pool, 99).await;
+    let uri = format!("/api/polls/{}/candidates", poll_id);
+
+    let (status, result) = post_candidates(&app, uri.clone(), json!({"name": "Candidate 100"})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["success"], true);
+
+    let (status, result) = post_candidates(&app, uri, json!({"name": "Candidate 101"})).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "TOO_MANY_CANDIDATES");
+}
+
+#[sqlx::test]
+async fn test_add_candidates_bulk_requires_poll_owner(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let poll_id = create_test_poll(&pool).await;
+    let uri = format!("/api/polls/{}/candidates/bulk", poll_id);
+    let body = json!([{"name": "Intruder"}]);
+
+    let (status, _) = post_candidates(&app, uri.clone(), body.clone()).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (_, other) = post_candidates(
+        &app,
+        "/api/auth/register".to_string(),
+        json!({"email": "bulk-other@example.com", "password": "testpassword123", "name": "Other"}),
+    )
+    .await;
+    let other_token = other["data"]["token"].as_str().unwrap();
+    let (status, result) = post_candidates_as(&app, uri, other_token, body.clone()).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(result["error"]["code"], "POLL_NOT_FOUND");
+
+    let (status, _) = post_candidates_as(&app, format!("/api/polls/{}/candidates/bulk", Uuid::new_v4()), other_token, body).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM candidates WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[sqlx::test]
+async fn test_closed_poll_candidates_cannot_change(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let poll_id = create_poll_with_candidates(&pool, 3).await;
+    let candidate_id: Uuid = sqlx::query_scalar("SELECT id FROM candidates WHERE poll_id = $1 ORDER BY display_order LIMIT 1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE polls SET status = 'closed' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let (status, result) = post_candidates(&app, format!("/api/polls/{}/candidates", poll_id), json!({"name": "Late"})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(result["error"]["code"], "POLL_CLOSED");
+
+    let token = test_user_token(&pool).await;
+    let (status, result) = post_candidates_as(&app, format!("/api/polls/{}/candidates/bulk", poll_id), &token, json!([{"name": "Late"}])).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(result["error"]["code"], "POLL_CLOSED");
+
+    let request = Request::builder()
+        .method(Method::DELETE)
+        .uri(format!("/api/candidates/{}", candidate_id))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "POLL_CLOSED");
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM candidates WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 3);
+}
+
+#[sqlx::test]
+async fn test_add_candidates_bulk_enforces_maximum(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_poll_with_candidates(&pool, 98).await;
+    let uri = format!("/api/polls/{}/candidates/bulk", poll_id);
+    let token = test_user_token(&pool).await;
+
+    // 98 + 3 would pass the limit, so nothing is added
+    let (status, result) = post_candidates_as(&app, uri.clone(), &token, json!([{"name": "X"}, {"name": "Y"}, {"name": "Z"}])).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "TOO_MANY_CANDIDATES");
+
+    let (status, result) = post_candidates_as(&app, uri, &token, json!([{"name": "X"}, {"name": "Y"}])).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"].as_array().unwrap().len(), 100);
+}
+
+#[sqlx::test]
+async fn test_none_of_the_above_is_managed_by_the_poll(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_poll_with_candidates(&pool, 99).await;
+    let nota_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO candidates (poll_id, name, slug, display_order, is_nota) \
+         VALUES ($1, 'None of the above', 'none-of-the-above', 100, true) RETURNING id",
+    )
+    .bind(poll_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    // "None of the above" doesn't count toward the cap
+    let (status, _) = post_candidates(&app, format!("/api/polls/{}/candidates", poll_id), json!({"name": "Candidate 100"})).await;
+    assert_eq!(status, StatusCode::OK);
+
+    for (method, body) in [(Method::PUT, json!({"name": "Nobody"}).to_string()), (Method::DELETE, String::new())] {
+        let request = Request::builder()
+            .method(method)
+            .uri(format!("/api/candidates/{}", nota_id))
+            .header("content-type", "application/json")
+            .body(Body::from(body))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["error"]["code"], "NOTA_NOT_EDITABLE");
+    }
+
+    let name: String = sqlx::query_scalar("SELECT name FROM candidates WHERE id = $1")
+        .bind(nota_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(name, "None of the above");
+}
+
+#[sqlx::test]
+async fn test_duplicate_candidate_names_get_distinct_slugs(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let uri = format!("/api/polls/{}/candidates", poll_id);
+
+    let (status, first) = post_candidates(&app, uri.clone(), json!({"name": "John Smith"})).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, second) = post_candidates(&app, uri, json!({"name": "John  Smith!"})).await;
+    assert_eq!(status, StatusCode::OK);
+
+    assert_eq!(first["data"]["slug"], "john-smith");
+    assert_eq!(second["data"]["slug"], "john-smith-2");
+
+    // Each slug resolves to its own candidate
+    for candidate in [&first, &second] {
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(format!("/api/polls/{}/candidates/by-slug/{}", poll_id, candidate["data"]["slug"].as_str().unwrap()))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["data"]["id"], candidate["data"]["id"]);
+    }
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/candidates/by-slug/jane-doe", poll_id))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test]
+async fn test_concurrent_candidate_takes_next_slug(pool: PgPool) {
+    let poll_id = create_test_poll(&pool).await;
+
+    // Another transaction claims "alice" but hasn't committed, so the create below picks it too
+    let mut other = pool.begin().await.unwrap();
+    sqlx::query("INSERT INTO candidates (poll_id, name, slug, display_order) VALUES ($1, 'Alice', 'alice', 1)")
+        .bind(poll_id)
+        .execute(&mut *other)
+        .await
+        .unwrap();
+
+    let create = tokio::spawn({
+        let pool = pool.clone();
+        async move {
+            let req = CreateCandidateRequest {
+                name: "Alice".to_string(),
+                description: None,
+                statement: None,
+                affiliation: None,
+                image_url: None,
+            };
+            Candidate::create(&pool, poll_id, req).await
+        }
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    other.commit().await.unwrap();
+
+    let candidate = create.await.unwrap().unwrap();
+    assert_eq!(candidate.slug, "alice-2");
+}
+
+#[sqlx::test]
+async fn test_candidate_text_length_limits(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let poll_id = create_poll_with_candidates(&pool, 2).await;
+    let uri = format!("/api/polls/{}/candidates", poll_id);
+
+    // Limits apply after trimming, so surrounding whitespace doesn't count
+    let (status, _) = post_candidates(&app, uri.clone(), json!({ "name": format!("  {}  ", "n".repeat(200)) })).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, result) = post_candidates(&app, uri.clone(), json!({ "name": "n".repeat(201) })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+
+    let (status, result) = post_candidates(&app, uri.clone(), json!({ "name": "Long", "description": "d".repeat(501) })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+
+    let token = test_user_token(&pool).await;
+    let (status, result) = post_candidates_as(&app, format!("{}/bulk", uri), &token, json!([
+        { "name": "Fine" },
+        { "name": "n".repeat(201) }
+    ])).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+}
+
+#[sqlx::test]
+async fn test_candidate_text_strips_control_characters(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let poll_id = create_poll_with_candidates(&pool, 2).await;
+    let uri = format!("/api/polls/{}/candidates", poll_id);
+
+    let (status, result) = post_candidates(&app, uri.clone(), json!({
+        "name": " Ali\u{7}ce\r\n",
+        "description": "\u{0}Line one\nLine\u{1b} two  "
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["name"], "Alice");
+    assert_eq!(result["data"]["description"], "Line one\nLine two");
+    assert_eq!(result["data"]["slug"], "alice");
+
+    // A name made only of control characters is empty
+    let (status, result) = post_candidates(&app, uri, json!({ "name": "\u{7}\u{8}" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+}
+
+#[sqlx::test]
+async fn test_get_candidate(pool: PgPool) {
+    let app = create_test_app(pool).await;
+
+    let send = |method: Method, uri: String, token: Option<&str>, body: Value| {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json");
+        if let Some(token) = token {
+            builder = builder.header("authorization", format!("Bearer {}", token));
+        }
+        let app = app.clone();
+        let request = builder.body(Body::from(body.to_string())).unwrap();
+        async move {
+            let response = app.oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, serde_json::from_slice::<Value>(&body).unwrap())
+        }
+    };
+
+    let register = |email: &str| {
+        send(
+            Method::POST,
+            "/api/auth/register".to_string(),
+            None,
+            json!({"email": email, "password": "testpassword123", "name": "Owner"}),
+        )
+    };
+    let (_, owner) = register("candidate-owner@example.com").await;
+    let owner_token = owner["data"]["token"].as_str().unwrap().to_string();
+    let (_, other) = register("candidate-other@example.com").await;
+    let other_token = other["data"]["token"].as_str().unwrap().to_string();
+
+    let (status, poll) = send(
+        Method::POST,
+        "/api/polls".to_string(),
+        Some(&owner_token),
+        json!({"title": "Deep Link", "candidates": [{"name": "Alice"}, {"name": "Bob"}]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let candidate_id = poll["data"]["candidates"][1]["id"].as_str().unwrap().to_string();
+
+    let (status, result) = send(Method::GET, format!("/api/candidates/{}", candidate_id), Some(&owner_token), Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["id"], candidate_id);
+    assert_eq!(result["data"]["name"], "Bob");
+
+    // Unknown ids and other people's candidates look the same
+    let (status, result) = send(Method::GET, format!("/api/candidates/{}", Uuid::new_v4()), Some(&owner_token), Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(result["error"]["code"], "CANDIDATE_NOT_FOUND");
+
+    let (status, result) = send(Method::GET, format!("/api/candidates/{}", candidate_id), Some(&other_token), Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(result["error"]["code"], "CANDIDATE_NOT_FOUND");
+
+    let (status, _) = send(Method::GET, format!("/api/candidates/{}", candidate_id), None, Value::Null).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
@@ -1,10 +1,15 @@
+#![allow(dead_code)]
+
 use axum::{routing::{get, post, put, delete}, Router};
 use sqlx::PgPool;
 use tower_http::cors::CorsLayer;
 use uuid::Uuid;
-use serde_json::json;
 
+use rankedchoice_api::middleware::metrics::track_metrics;
+use rankedchoice_api::middleware::rate_limit::{rate_limit_middleware, RateLimiter};
+use rankedchoice_api::middleware::request_id::request_id;
 use rankedchoice_api::services::auth::AuthService;
+use rankedchoice_api::services::signing::TokenSigner;
 
 // Consistent test user ID for all tests
 pub const TEST_USER_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
@@ -37,51 +42,106 @@ pub async fn create_test_app_with_user(pool: PgPool) -> Router {
     create_test_app(pool).await
 }
 
+/// Auth service configured like the one `create_test_app` builds, for signing ballot tokens
+pub fn test_auth_service(pool: &PgPool) -> AuthService {
+    AuthService::new(pool.clone()).unwrap()
+}
+
+/// Signer keyed like the test app's auth service, for minting ballot tokens directly
+pub fn test_signer(pool: &PgPool) -> TokenSigner {
+    test_auth_service(pool).signer().clone()
+}
+
+/// Access token for the test user, who owns the polls `create_test_poll` makes
+pub async fn test_user_token(pool: &PgPool) -> String {
+    let user_id = create_test_user(pool).await;
+    let user = rankedchoice_api::models::user::User::find_by_id(pool, user_id)
+        .await
+        .unwrap()
+        .expect("test user exists");
+    test_auth_service(pool).generate_token(&user, false).unwrap()
+}
+
 pub async fn create_test_app(pool: PgPool) -> Router {
     // Initialize services
-    let auth_service = AuthService::new(pool.clone());
+    create_test_app_with_service(AuthService::new(pool).unwrap()).await
+}
+
+pub async fn create_test_app_with_service(auth_service: AuthService) -> Router {
+    let auth_rate_limit = axum::middleware::from_fn_with_state(RateLimiter::from_env(), rate_limit_middleware);
 
     // Build test app with same routes as main app
     Router::new()
-        .route("/health", get(health_handler))
+        .route("/health", get(rankedchoice_api::api::health::health))
+        .route("/ready", get(rankedchoice_api::api::health::ready))
         // Authentication routes (public)
-        .route("/api/auth/register", post(rankedchoice_api::api::auth::register))
-        .route("/api/auth/login", post(rankedchoice_api::api::auth::login))
+        .route("/api/auth/register", post(rankedchoice_api::api::auth::register).layer(auth_rate_limit.clone()))
+        .route("/api/auth/login", post(rankedchoice_api::api::auth::login).layer(auth_rate_limit))
         .route("/api/auth/refresh", post(rankedchoice_api::api::auth::refresh))
+        .route("/api/auth/verify-email/:token", get(rankedchoice_api::api::auth::verify_email_link))
+        .route("/api/auth/me", get(rankedchoice_api::api::auth::me).put(rankedchoice_api::api::auth::update_me).delete(rankedchoice_api::api::auth::delete_me))
         // Protected poll routes
         .route("/api/polls", get(rankedchoice_api::api::polls::list_polls))
         .route("/api/polls", post(rankedchoice_api::api::polls::create_poll))
         .route("/api/polls/:id", get(rankedchoice_api::api::polls::get_poll))
         .route("/api/polls/:id", put(rankedchoice_api::api::polls::update_poll))
         .route("/api/polls/:id", delete(rankedchoice_api::api::polls::delete_poll))
+        .route("/api/polls/:id/restore", post(rankedchoice_api::api::polls::restore_poll))
+        .route("/api/polls/:id/close-now", post(rankedchoice_api::api::polls::close_poll_now))
+        .route("/api/polls/:id/export", get(rankedchoice_api::api::polls::export_poll))
+        .route("/api/polls/import", post(rankedchoice_api::api::polls::import_poll))
+        .route("/api/polls/:id/reset", post(rankedchoice_api::api::polls::reset_poll))
+        .route("/api/polls/:id/transfer", post(rankedchoice_api::api::polls::transfer_poll))
         // Candidate management routes
         .route("/api/polls/:id/candidates", get(rankedchoice_api::api::candidates::list_candidates))
         .route("/api/polls/:id/candidates", post(rankedchoice_api::api::candidates::add_candidate))
+        .route("/api/polls/:id/candidates/bulk", post(rankedchoice_api::api::candidates::add_candidates_bulk))
         .route("/api/polls/:id/candidates/order", put(rankedchoice_api::api::candidates::reorder_candidates))
+        .route("/api/polls/:id/candidates/by-slug/:slug", get(rankedchoice_api::api::candidates::get_candidate_by_slug))
+        .route("/api/candidates/:id", get(rankedchoice_api::api::candidates::get_candidate))
         .route("/api/candidates/:id", put(rankedchoice_api::api::candidates::update_candidate))
         .route("/api/candidates/:id", delete(rankedchoice_api::api::candidates::delete_candidate))
         // Voter management routes
         .route("/api/polls/:id/invite", post(rankedchoice_api::api::voters::create_voter))
         .route("/api/polls/:id/voters", get(rankedchoice_api::api::voters::list_voters))
+        .route("/api/polls/:id/voters/import-template", get(rankedchoice_api::api::voters::get_voter_import_template))
+        .route("/api/polls/:id/voters/:voter_id", delete(rankedchoice_api::api::voters::delete_voter))
+        .route("/api/polls/:id/voters/:voter_id/resend", post(rankedchoice_api::api::voters::resend_invitation))
+        .route("/api/polls/:id/voters/remind", post(rankedchoice_api::api::voters::remind_voters))
+        .route("/api/polls/:id/stats", get(rankedchoice_api::api::voters::get_poll_stats))
         .route("/api/polls/:id/registration", post(rankedchoice_api::api::voters::create_registration_link))
         // Voting routes (public)
         .route("/api/vote/:token", get(rankedchoice_api::api::voting::get_ballot))
         .route("/api/vote/:token", post(rankedchoice_api::api::voting::submit_ballot))
+        .route("/api/vote/:token/draft", put(rankedchoice_api::api::voting::save_ballot_draft))
+        .route("/api/vote/:token/validate", post(rankedchoice_api::api::voting::validate_ballot))
         .route("/api/vote/:token/receipt", get(rankedchoice_api::api::voting::get_voting_receipt))
+        .route("/api/vote/:token/ballot", get(rankedchoice_api::api::voting::get_my_ballot))
+        .route("/api/verify/:receipt_code", get(rankedchoice_api::api::voting::verify_receipt))
+        .route("/api/public/polls", get(rankedchoice_api::api::polls::list_public_polls))
+        .route("/api/public/polls/:id/vote", post(rankedchoice_api::api::voting::submit_anonymous_vote))
+        .route("/api/public/polls/:id/results", get(rankedchoice_api::api::results::get_public_poll_results))
         // Results routes (protected)
         .route("/api/polls/:id/results", get(rankedchoice_api::api::results::get_poll_results))
         .route("/api/polls/:id/results/rounds", get(rankedchoice_api::api::results::get_rcv_rounds))
+        .route("/api/polls/:id/results/first-choice", get(rankedchoice_api::api::results::get_first_choice_results))
+        .route("/api/polls/:id/results/pairwise", get(rankedchoice_api::api::results::get_pairwise_results))
+        .route("/api/polls/:id/results/by-affiliation", get(rankedchoice_api::api::results::get_results_by_affiliation))
+        .route("/api/polls/:id/results/preview", post(rankedchoice_api::api::results::preview_poll_results))
+        .route("/api/polls/:id/results/stream", get(rankedchoice_api::api::results::stream_poll_results))
+        .route("/api/polls/:id/results/versions", get(rankedchoice_api::api::results::list_result_versions))
+        .route("/api/polls/:id/results/versions/:version", get(rankedchoice_api::api::results::get_result_version))
+        .route("/api/polls/:id/results/notify", post(rankedchoice_api::api::results::notify_poll_results))
+        .route("/api/admin/results/batch", post(rankedchoice_api::api::results::batch_poll_results))
+        .route("/api/admin/email/test", post(rankedchoice_api::api::admin::send_test_email))
+        .route("/api/admin/email/health", get(rankedchoice_api::api::admin::email_health))
+        .route("/metrics", get(rankedchoice_api::api::metrics::metrics_handler))
+        .route_layer(axum::middleware::from_fn(track_metrics))
         .layer(CorsLayer::permissive())
+        .layer(axum::middleware::from_fn(request_id))
         .with_state(auth_service)
 }
 
-async fn health_handler() -> axum::Json<serde_json::Value> {
-    axum::Json(serde_json::json!({
-        "status": "ok",
-        "version": env!("CARGO_PKG_VERSION")
-    }))
-}
-
 // Test helper functions
 pub async fn setup_test_user(pool: &PgPool) -> Uuid {
     create_test_user(pool).await
@@ -113,7 +173,7 @@ pub async fn create_test_poll(pool: &PgPool) -> Uuid {
 }
 
 pub async fn create_test_candidates(pool: &PgPool, poll_id: Uuid) -> Vec<Uuid> {
-    let candidates = vec![
+    let candidates = [
         ("Candidate A", "Description A"),
         ("Candidate B", "Description B"),
         ("Candidate C", "Description C"),
@@ -124,12 +184,13 @@ pub async fn create_test_candidates(pool: &PgPool, poll_id: Uuid) -> Vec<Uuid> {
     for (i, (name, description)) in candidates.iter().enumerate() {
         let candidate_id = sqlx::query!(
             r#"
-            INSERT INTO candidates (poll_id, name, description, display_order)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO candidates (poll_id, name, slug, description, display_order)
+            VALUES ($1, $2, $3, $4, $5)
             RETURNING id
             "#,
             poll_id,
             name,
+            rankedchoice_api::models::candidate::slugify(name),
             description,
             i as i32 + 1
         )
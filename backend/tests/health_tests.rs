@@ -0,0 +1,145 @@
+use axum::{
+    body::{Body, to_bytes},
+    http::{Method, Request, StatusCode},
+};
+use rankedchoice_api::services::auth::AuthService;
+use serde_json::Value;
+use sqlx::PgPool;
+use tower::ServiceExt;
+
+mod common;
+use common::*;
+
+#[sqlx::test]
+async fn test_health_reports_ok_with_healthy_pool(pool: PgPool) {
+    let app = create_test_app(pool).await;
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/health")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["status"], "ok");
+    assert_eq!(result["version"], env!("CARGO_PKG_VERSION"));
+}
+
+async fn get_status(app: &axum::Router, uri: &str) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[sqlx::test]
+async fn test_ready_reflects_pool_availability(pool: PgPool) {
+    // Tests otherwise run on the development secret, which readiness doesn't accept as configured
+    let auth_service = AuthService::new(pool.clone())
+        .unwrap()
+        .with_env_lookup(|key| Some(format!("{}-configured", key)));
+    let app = create_test_app_with_service(auth_service).await;
+
+    let (status, result) = get_status(&app, "/ready").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["status"], "ready");
+    assert_eq!(result["checks"]["database"], "ok");
+    assert_eq!(result["checks"]["environment"], "ok");
+
+    pool.close().await;
+
+    let (status, result) = get_status(&app, "/ready").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(result["status"], "not_ready");
+    assert_eq!(result["checks"]["database"], "down");
+
+    // Liveness doesn't depend on the database
+    let (status, result) = get_status(&app, "/health").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["status"], "ok");
+}
+
+#[sqlx::test]
+async fn test_ready_reports_missing_env(pool: PgPool) {
+    let auth_service = AuthService::new(pool)
+        .unwrap()
+        .with_env_lookup(|key| (key == "DATABASE_URL").then(|| "postgres://localhost/test".to_string()));
+    let app = create_test_app_with_service(auth_service).await;
+
+    let (status, result) = get_status(&app, "/ready").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(result["checks"]["database"], "ok");
+    assert_eq!(result["checks"]["environment"], "missing");
+    assert_eq!(result["checks"]["missing_env"], serde_json::json!(["JWT_SECRET"]));
+}
+
+#[sqlx::test]
+async fn test_metrics_exposes_prometheus_text(pool: PgPool) {
+    let app = create_test_app(pool).await;
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/health")
+        .body(Body::empty())
+        .unwrap();
+    app.clone().oneshot(request).await.unwrap();
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/metrics")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()["content-type"].to_str().unwrap().starts_with("text/plain"));
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let text = String::from_utf8(body.to_vec()).unwrap();
+
+    // Every sample line is "<name>{<labels>} <value>" with a numeric value
+    for line in text.lines().filter(|line| !line.starts_with('#') && !line.is_empty()) {
+        let (_, value) = line.rsplit_once(' ').unwrap();
+        assert!(value.parse::<f64>().is_ok(), "unparseable sample: {}", line);
+    }
+
+    assert!(text.contains(r#"http_requests_total{method="GET",route="/health",status="200"}"#));
+    assert!(text.contains("# TYPE http_request_duration_seconds histogram"));
+}
+
+#[sqlx::test]
+async fn test_responses_carry_request_id(pool: PgPool) {
+    let app = create_test_app(pool).await;
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/health")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let generated = response.headers().get("x-request-id").expect("missing request id");
+    assert!(uuid::Uuid::parse_str(generated.to_str().unwrap()).is_ok());
+
+    // A caller-supplied id is echoed back unchanged
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/health")
+        .header("x-request-id", "trace-abc-123")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.headers().get("x-request-id").unwrap(), "trace-abc-123");
+}
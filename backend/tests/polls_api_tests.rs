@@ -7,14 +7,20 @@ use serde_json::{json, Value};
 use sqlx::PgPool;
 use tower::ServiceExt;
 use uuid::Uuid;
+use rankedchoice_api::models::ballot::{Ballot, BallotRanking, Voter};
+use rankedchoice_api::services::auth::AuthService;
 
 mod common;
 use common::*;
 
 // Helper function to register user and get auth token
 async fn setup_authenticated_user(app: &Router) -> String {
+    register_user(app, "testuser@example.com").await
+}
+
+async fn register_user(app: &Router, email: &str) -> String {
     let user_data = json!({
-        "email": "testuser@example.com",
+        "email": email,
         "password": "testpassword123",
         "name": "Test User"
     });
@@ -173,6 +179,79 @@ async fn test_create_poll_validation_errors(pool: PgPool) {
     assert!(result["error"]["message"].as_str().unwrap().contains("title"));
 }
 
+#[sqlx::test]
+async fn test_create_poll_validation_error_fields(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let post_poll = |body: Value| {
+        Request::builder()
+            .method(Method::POST)
+            .uri("/api/polls")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+
+    // Missing title names the title field
+    let response = app
+        .clone()
+        .oneshot(post_poll(json!({"title": "  ", "candidates": [{"name": "A"}, {"name": "B"}]})))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert_eq!(result["error"]["message"], "Poll title is required");
+    assert_eq!(
+        result["error"]["fields"],
+        json!([{"field": "title", "message": "Poll title is required"}])
+    );
+
+    // A bad candidate is pointed at by its position
+    let response = app
+        .oneshot(post_poll(json!({"title": "Poll", "candidates": [{"name": "A"}, {"name": " "}]})))
+        .await
+        .unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["fields"][0]["field"], "candidates[1].name");
+}
+
+#[sqlx::test]
+async fn test_create_poll_duplicate_candidate_names(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({
+            "title": "Duplicate Names",
+            "candidates": [{"name": "Alice"}, {"name": "Bob"}, {"name": "  alice "}]
+        }).to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert_eq!(result["error"]["fields"][0]["field"], "candidates[2].name");
+
+    let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM polls")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count.0, 0);
+}
+
 #[sqlx::test]
 async fn test_create_poll_insufficient_candidates(pool: PgPool) {
     let app = create_test_app(pool).await;
@@ -231,6 +310,116 @@ async fn test_create_poll_empty_candidate_name(pool: PgPool) {
     assert!(result["error"]["message"].as_str().unwrap().contains("candidate names"));
 }
 
+#[sqlx::test]
+async fn test_create_poll_invalid_num_winners(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let cases = [
+        ("single_winner", 5, "exactly 1 winner"),
+        ("multi_winner", 1, "at least 2 winners"),
+        ("multi_winner", 3, "fewer winners than candidates"),
+        ("multi_winner", 4, "fewer winners than candidates"),
+    ];
+
+    for (poll_type, num_winners, expected_message) in cases {
+        let invalid_request = json!({
+            "title": "Test Poll",
+            "poll_type": poll_type,
+            "num_winners": num_winners,
+            "candidates": [{"name": "A"}, {"name": "B"}, {"name": "C"}]
+        });
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/polls")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(invalid_request.to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(result["success"], false);
+        assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+        assert!(result["error"]["message"].as_str().unwrap().contains(expected_message));
+    }
+}
+
+#[sqlx::test]
+async fn test_update_poll_candidates_checks_num_winners(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let poll_request = json!({
+        "title": "Board Election",
+        "poll_type": "multi_winner",
+        "num_winners": 2,
+        "allow_none_of_the_above": true,
+        "candidates": [{"name": "A"}, {"name": "B"}, {"name": "C"}, {"name": "D"}]
+    });
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_uri = format!("/api/polls/{}", result["data"]["id"].as_str().unwrap());
+
+    // "None of the above" does not count towards the candidates the seats need
+    let (status, result) = send_poll_json(
+        &app,
+        Method::PUT,
+        &poll_uri,
+        &token,
+        json!({"candidates": [{"name": "A"}, {"name": "B"}]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["fields"][0]["field"], "num_winners");
+
+    let (status, result) = send_poll_json(
+        &app,
+        Method::PUT,
+        &poll_uri,
+        &token,
+        json!({"candidates": [{"name": "A"}, {"name": "B"}, {"name": "C"}]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["candidates"].as_array().unwrap().iter().filter(|c| c["is_nota"] == false).count(), 3);
+}
+
+#[sqlx::test]
+async fn test_create_poll_unknown_poll_type(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let invalid_request = json!({
+        "title": "Test Poll",
+        "poll_type": "nonsense",
+        "candidates": [{"name": "A"}, {"name": "B"}]
+    });
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(invalid_request.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert!(result["error"]["message"].as_str().unwrap().contains("nonsense"));
+}
+
 #[sqlx::test]
 async fn test_list_polls_success(pool: PgPool) {
     let app = create_test_app(pool).await;
@@ -308,6 +497,155 @@ async fn test_list_polls_with_filters(pool: PgPool) {
     assert_eq!(result["success"], true);
 }
 
+#[sqlx::test]
+async fn test_list_polls_search(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let polls = [
+        ("Favourite Pizza Topping", "Settle it once and for all"),
+        ("Board Election", "Annual vote for the pizza committee"),
+        ("Team Offsite Location", "Where should we go this year?"),
+    ];
+
+    for (title, description) in polls {
+        let poll_request = json!({
+            "title": title,
+            "description": description,
+            "candidates": [{"name": "A"}, {"name": "B"}]
+        });
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/polls")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(poll_request.to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/api/polls?q=PIZZA&sort=title&order=asc&limit=1")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    // Matches title or description, and the total reflects the filter rather than the page
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["total"], 2);
+    assert_eq!(result["data"]["total_pages"], 2);
+    let items = result["data"]["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["title"], "Board Election");
+}
+
+#[sqlx::test]
+async fn test_list_polls_by_tag(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let polls = [
+        ("Board Election", json!([" Board ", "annual"])),
+        ("Budget Vote", json!(["board", "BOARD"])),
+        ("Team Lunch", json!(["social"])),
+    ];
+
+    let mut poll_ids = Vec::new();
+    for (title, tags) in polls {
+        let poll_request = json!({
+            "title": title,
+            "tags": tags,
+            "candidates": [{"name": "A"}, {"name": "B"}]
+        });
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/polls")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(poll_request.to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        poll_ids.push(result["data"]["id"].as_str().unwrap().to_string());
+
+        // Tags come back trimmed, lowercased and deduplicated
+        if title == "Board Election" {
+            assert_eq!(result["data"]["tags"], json!(["annual", "board"]));
+        }
+    }
+
+    let list = |uri: &str| {
+        Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(list("/api/polls?tag=Board&sort=title&order=asc")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["total"], 2);
+    let items = result["data"]["items"].as_array().unwrap();
+    assert_eq!(items[0]["title"], "Board Election");
+    assert_eq!(items[0]["tags"], json!(["annual", "board"]));
+    assert_eq!(items[1]["title"], "Budget Vote");
+
+    // The tag filter combines with text search
+    let response = app.clone().oneshot(list("/api/polls?q=budget&tag=board")).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["total"], 1);
+    assert_eq!(result["data"]["items"][0]["title"], "Budget Vote");
+
+    // Updating tags replaces the whole set
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}", poll_ids[1]))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({"tags": ["finance"]}).to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["tags"], json!(["finance"]));
+
+    let response = app.clone().oneshot(list("/api/polls?tag=board")).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["total"], 1);
+    assert_eq!(result["data"]["items"][0]["title"], "Board Election");
+
+    // Blank tags are rejected
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}", poll_ids[2]))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({"tags": ["  "]}).to_string()))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
 #[sqlx::test]
 async fn test_get_poll_not_found(pool: PgPool) {
     let app = create_test_app(pool).await;
@@ -316,7 +654,7 @@ async fn test_get_poll_not_found(pool: PgPool) {
     let random_id = Uuid::new_v4();
     let request = Request::builder()
         .method(Method::GET)
-        .uri(&format!("/api/polls/{}", random_id))
+        .uri(format!("/api/polls/{}", random_id))
         .header("authorization", format!("Bearer {}", token))
         .body(Body::empty())
         .unwrap();
@@ -344,7 +682,7 @@ async fn test_update_poll_not_found(pool: PgPool) {
     
     let request = Request::builder()
         .method(Method::PUT)
-        .uri(&format!("/api/polls/{}", random_id))
+        .uri(format!("/api/polls/{}", random_id))
         .header("content-type", "application/json")
         .header("authorization", format!("Bearer {}", token))
         .body(Body::from(update_request.to_string()))
@@ -373,7 +711,7 @@ async fn test_update_poll_validation_error(pool: PgPool) {
     
     let request = Request::builder()
         .method(Method::PUT)
-        .uri(&format!("/api/polls/{}", random_id))
+        .uri(format!("/api/polls/{}", random_id))
         .header("content-type", "application/json")
         .header("authorization", format!("Bearer {}", token))
         .body(Body::from(invalid_update.to_string()))
@@ -398,7 +736,7 @@ async fn test_delete_poll_not_found(pool: PgPool) {
     let random_id = Uuid::new_v4();
     let request = Request::builder()
         .method(Method::DELETE)
-        .uri(&format!("/api/polls/{}", random_id))
+        .uri(format!("/api/polls/{}", random_id))
         .header("authorization", format!("Bearer {}", token))
         .body(Body::empty())
         .unwrap();
@@ -468,4 +806,1076 @@ async fn test_poll_creation_workflow(pool: PgPool) {
     // subsequent GET, UPDATE, and DELETE operations will fail because 
     // they won't find polls created by different user IDs.
     // This demonstrates the need for proper authentication middleware.
-} 
\ No newline at end of file
+}
+
+#[sqlx::test]
+async fn test_update_poll_candidates(pool: PgPool) {
+    let app = create_test_app_with_user(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(create_test_poll_request().to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let create_result: Value = serde_json::from_slice(&body).unwrap();
+    let poll_id = create_result["data"]["id"].as_str().unwrap();
+    let candidates = create_result["data"]["candidates"].as_array().unwrap();
+    let rust_id = candidates[0]["id"].as_str().unwrap();
+    let python_id = candidates[1]["id"].as_str().unwrap();
+
+    // Rename Rust, keep Python, drop JavaScript and add Go in one request
+    let update_request = json!({
+        "candidates": [
+            {"id": rust_id, "name": "Rust 2024", "description": "Systems programming language"},
+            {"id": python_id, "name": "Python"},
+            {"name": "Go", "description": "Concurrent programming language"}
+        ]
+    });
+
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}", poll_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(update_request.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["title"], "Best Programming Language 2024");
+
+    let candidates = result["data"]["candidates"].as_array().unwrap();
+    assert_eq!(candidates.len(), 3);
+    assert_eq!(candidates[0]["id"], rust_id);
+    assert_eq!(candidates[0]["name"], "Rust 2024");
+    assert_eq!(candidates[1]["id"], python_id);
+    assert_eq!(candidates[1]["name"], "Python");
+    assert_eq!(candidates[2]["name"], "Go");
+    assert_eq!(candidates[2]["display_order"], 3);
+    assert!(candidates.iter().all(|c| c["name"] != "JavaScript"));
+}
+
+#[sqlx::test]
+async fn test_update_poll_candidates_unknown_id(pool: PgPool) {
+    let app = create_test_app_with_user(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(create_minimal_poll_request().to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let create_result: Value = serde_json::from_slice(&body).unwrap();
+    let poll_id = create_result["data"]["id"].as_str().unwrap();
+
+    let update_request = json!({
+        "candidates": [
+            {"id": Uuid::new_v4(), "name": "Option A"},
+            {"name": "Option C"}
+        ]
+    });
+
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}", poll_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(update_request.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+}
+
+#[sqlx::test]
+async fn test_update_poll_candidates_locked_after_votes(pool: PgPool) {
+    let app = create_test_app_with_user(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({
+            "title": "Locked Poll",
+            "candidates": [{"name": "Option A"}, {"name": "Option B"}, {"name": "Option C"}]
+        }).to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let create_result: Value = serde_json::from_slice(&body).unwrap();
+    let poll_id = Uuid::parse_str(create_result["data"]["id"].as_str().unwrap()).unwrap();
+    let candidates = create_result["data"]["candidates"].as_array().unwrap().clone();
+    let candidate_id = |index: usize| Uuid::parse_str(candidates[index]["id"].as_str().unwrap()).unwrap();
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("locked@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    Ballot::create(&pool, voter.id, poll_id, vec![BallotRanking { candidate_id: candidate_id(2), rank: 1 }], None)
+        .await
+        .expect("Failed to create ballot");
+
+    // Dropping Option C would delete the ranking on the ballot just cast
+    let update_request = json!({
+        "candidates": [
+            {"id": candidate_id(0), "name": "Option A"},
+            {"id": candidate_id(1), "name": "Option B"}
+        ]
+    });
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}", poll_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(update_request.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "CANDIDATES_LOCKED");
+
+    let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM candidates WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(remaining, 3);
+}
+
+#[sqlx::test]
+async fn test_create_poll_invalid_schedule(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let now = chrono::Utc::now();
+    let cases = [
+        (now + chrono::Duration::days(2), now + chrono::Duration::days(1), "close after it opens"),
+        (now + chrono::Duration::days(1), now + chrono::Duration::days(1), "close after it opens"),
+        (now - chrono::Duration::days(2), now - chrono::Duration::days(1), "in the future"),
+    ];
+
+    for (opens_at, closes_at, expected_message) in cases {
+        let invalid_request = json!({
+            "title": "Test Poll",
+            "opens_at": opens_at,
+            "closes_at": closes_at,
+            "candidates": [{"name": "A"}, {"name": "B"}]
+        });
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/polls")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(invalid_request.to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+        assert!(result["error"]["message"].as_str().unwrap().contains(expected_message));
+    }
+}
+
+#[sqlx::test]
+async fn test_minimum_poll_duration(pool: PgPool) {
+    let auth_service = AuthService::new(pool).unwrap().with_min_poll_duration(Some(chrono::Duration::minutes(60)));
+    let app = create_test_app_with_service(auth_service).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let opens_at = chrono::Utc::now() + chrono::Duration::days(1);
+    let mut poll_request = create_minimal_poll_request();
+    poll_request["opens_at"] = json!(opens_at);
+
+    // One minute short of the minimum is rejected
+    poll_request["closes_at"] = json!(opens_at + chrono::Duration::minutes(59));
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request.clone()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert!(result["error"]["message"].as_str().unwrap().contains("at least 60 minutes"));
+
+    // Exactly the minimum is allowed
+    poll_request["closes_at"] = json!(opens_at + chrono::Duration::minutes(60));
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_id = result["data"]["id"].as_str().unwrap().to_string();
+
+    // Shortening an existing poll below the minimum is rejected too
+    let update = json!({ "closes_at": opens_at + chrono::Duration::minutes(30) });
+    let (status, result) = send_poll_json(&app, Method::PUT, &format!("/api/polls/{}", poll_id), &token, update).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+}
+
+#[sqlx::test]
+async fn test_min_rankings_cannot_exceed_candidates(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let mut poll_request = create_minimal_poll_request();
+    let candidate_count = poll_request["candidates"].as_array().unwrap().len();
+
+    poll_request["min_rankings"] = json!(candidate_count + 1);
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request.clone()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+
+    poll_request["min_rankings"] = json!(candidate_count);
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["min_rankings"], candidate_count);
+}
+
+#[sqlx::test]
+async fn test_create_poll_skipped_rankings_policy(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    for (policy, expected_status) in [("collapse", StatusCode::OK), ("ignore", StatusCode::BAD_REQUEST)] {
+        let poll_request = json!({
+            "title": "Test Poll",
+            "skipped_rankings_policy": policy,
+            "candidates": [{"name": "A"}, {"name": "B"}]
+        });
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/polls")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(poll_request.to_string()))
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), expected_status);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        if expected_status == StatusCode::OK {
+            assert_eq!(result["data"]["skipped_rankings_policy"], "collapse");
+        } else {
+            assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+        }
+    }
+}
+
+async fn send_poll_json(app: &Router, method: Method, uri: &str, token: &str, body: Value) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(body.to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[sqlx::test]
+async fn test_create_poll_results_visibility(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, create_minimal_poll_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["results_visibility"], "private");
+
+    let mut poll_request = create_minimal_poll_request();
+    poll_request["results_visibility"] = json!("public_after_close");
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["results_visibility"], "public_after_close");
+
+    let poll_id = result["data"]["id"].as_str().unwrap();
+    let (status, result) = send_poll_json(
+        &app,
+        Method::PUT,
+        &format!("/api/polls/{}", poll_id),
+        &token,
+        json!({ "results_visibility": "public_always" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["results_visibility"], "public_always");
+
+    let mut poll_request = create_minimal_poll_request();
+    poll_request["results_visibility"] = json!("everyone");
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+}
+
+#[sqlx::test]
+async fn test_create_poll_candidate_limit(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    for (count, expected_status) in [(100, StatusCode::OK), (101, StatusCode::BAD_REQUEST)] {
+        let mut poll_request = create_minimal_poll_request();
+        poll_request["candidates"] = (1..=count).map(|n| json!({"name": format!("Candidate {}", n)})).collect();
+
+        let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+        assert_eq!(status, expected_status);
+        if expected_status == StatusCode::OK {
+            assert_eq!(result["data"]["candidates"].as_array().unwrap().len(), 100);
+        } else {
+            assert_eq!(result["error"]["code"], "TOO_MANY_CANDIDATES");
+        }
+    }
+}
+
+#[sqlx::test]
+async fn test_update_poll_closes_before_opens(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let opens_at = chrono::Utc::now() + chrono::Duration::days(3);
+    let mut poll_request = create_minimal_poll_request();
+    poll_request["opens_at"] = json!(opens_at);
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(poll_request.to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let create_result: Value = serde_json::from_slice(&body).unwrap();
+    let poll_id = create_result["data"]["id"].as_str().unwrap();
+
+    // Only closes_at is sent; it is checked against the stored opens_at
+    let update_request = json!({
+        "closes_at": opens_at - chrono::Duration::days(1)
+    });
+
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}", poll_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(update_request.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+}
+
+async fn send_poll_request(app: &Router, method: Method, uri: &str, token: &str) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[sqlx::test]
+async fn test_soft_deleted_poll_can_be_restored(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(create_minimal_poll_request().to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let create_result: Value = serde_json::from_slice(&body).unwrap();
+    let poll_id = create_result["data"]["id"].as_str().unwrap();
+
+    let (status, _) = send_poll_request(&app, Method::DELETE, &format!("/api/polls/{}", poll_id), &token).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Gone from listings and lookups
+    let (_, list) = send_poll_request(&app, Method::GET, "/api/polls", &token).await;
+    assert_eq!(list["data"]["total"], 0);
+    let (status, _) = send_poll_request(&app, Method::GET, &format!("/api/polls/{}", poll_id), &token).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, restored) = send_poll_request(&app, Method::POST, &format!("/api/polls/{}/restore", poll_id), &token).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(restored["data"]["id"], poll_id);
+    assert_eq!(restored["data"]["candidates"].as_array().unwrap().len(), 2);
+
+    let (_, list) = send_poll_request(&app, Method::GET, "/api/polls", &token).await;
+    assert_eq!(list["data"]["total"], 1);
+
+    // Restoring a poll that is not deleted is a 404
+    let (status, result) = send_poll_request(&app, Method::POST, &format!("/api/polls/{}/restore", poll_id), &token).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(result["error"]["code"], "POLL_NOT_FOUND");
+}
+
+#[sqlx::test]
+async fn test_permanently_deleted_poll_cannot_be_restored(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(create_minimal_poll_request().to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let create_result: Value = serde_json::from_slice(&body).unwrap();
+    let poll_id = create_result["data"]["id"].as_str().unwrap();
+
+    let (status, _) = send_poll_request(&app, Method::DELETE, &format!("/api/polls/{}?permanent=true", poll_id), &token).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM polls")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(remaining, 0);
+
+    let (status, _) = send_poll_request(&app, Method::POST, &format!("/api/polls/{}/restore", poll_id), &token).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test]
+async fn test_transfer_poll_ownership(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let old_owner = setup_authenticated_user(&app).await;
+    let new_owner = register_user(&app, "newowner@example.com").await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", old_owner))
+        .body(Body::from(create_minimal_poll_request().to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let create_result: Value = serde_json::from_slice(&body).unwrap();
+    let poll_id = create_result["data"]["id"].as_str().unwrap();
+
+    let transfer = |email: &str| {
+        Request::builder()
+            .method(Method::POST)
+            .uri(format!("/api/polls/{}/transfer", poll_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", old_owner))
+            .body(Body::from(json!({ "email": email }).to_string()))
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(transfer("nobody@example.com")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "USER_NOT_FOUND");
+
+    let response = app.clone().oneshot(transfer("newowner@example.com")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let (status, _) = send_poll_request(&app, Method::GET, &format!("/api/polls/{}", poll_id), &new_owner).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send_poll_request(&app, Method::GET, &format!("/api/polls/{}", poll_id), &old_owner).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let transfers: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM poll_ownership_transfers WHERE poll_id = $1::uuid")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(transfers, 1);
+}
+
+#[sqlx::test]
+async fn test_close_poll_now_blocks_voting(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let closes_at = chrono::Utc::now() + chrono::Duration::days(7);
+    let mut poll_request = create_minimal_poll_request();
+    poll_request["closes_at"] = json!(closes_at);
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(poll_request.to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let create_result: Value = serde_json::from_slice(&body).unwrap();
+    let poll_id = create_result["data"]["id"].as_str().unwrap().to_string();
+
+    let voter = Voter::create(&pool, &test_signer(&pool), Uuid::parse_str(&poll_id).unwrap(), Some("early@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let (status, closed) = send_poll_request(&app, Method::POST, &format!("/api/polls/{}/close-now", poll_id), &token).await;
+    assert_eq!(status, StatusCode::OK);
+    let closed_at: chrono::DateTime<chrono::Utc> = serde_json::from_value(closed["data"]["closes_at"].clone()).unwrap();
+    assert!(closed_at <= chrono::Utc::now());
+
+    let status: String = sqlx::query_scalar("SELECT status FROM polls WHERE id = $1")
+        .bind(Uuid::parse_str(&poll_id).unwrap())
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(status, "closed");
+
+    // The voter's ballot is refused straight away
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/vote/{}", voter.ballot_token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "POLL_CLOSED");
+
+    let (status, result) = send_poll_request(&app, Method::POST, &format!("/api/polls/{}/close-now", poll_id), &token).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(result["error"]["code"], "POLL_ALREADY_CLOSED");
+
+    // Only the owner can close a poll
+    let other_token = register_user(&app, "other@example.com").await;
+    let (status, _) = send_poll_request(&app, Method::POST, &format!("/api/polls/{}/close-now", poll_id), &other_token).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test]
+async fn test_closed_poll_rejects_schedule_and_candidate_edits(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let (status, created) = send_poll_json(&app, Method::POST, "/api/polls", &token, create_minimal_poll_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_id = created["data"]["id"].as_str().unwrap().to_string();
+
+    let (status, _) = send_poll_request(&app, Method::POST, &format!("/api/polls/{}/close-now", poll_id), &token).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let uri = format!("/api/polls/{}", poll_id);
+    let reopen = json!({ "closes_at": chrono::Utc::now() + chrono::Duration::days(7) });
+    let (status, result) = send_poll_json(&app, Method::PUT, &uri, &token, reopen).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(result["error"]["code"], "POLL_CLOSED");
+
+    let recast = json!({ "candidates": [{ "name": "Someone Else" }, { "name": "Another" }] });
+    let (status, result) = send_poll_json(&app, Method::PUT, &uri, &token, recast).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(result["error"]["code"], "POLL_CLOSED");
+
+    // Other details can still be edited
+    let (status, result) = send_poll_json(&app, Method::PUT, &uri, &token, json!({ "title": "Renamed" })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["title"], "Renamed");
+    assert_eq!(result["data"]["status"], "closed");
+}
+
+#[sqlx::test]
+async fn test_update_poll_clears_optional_settings_with_null(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let mut poll_request = create_minimal_poll_request();
+    poll_request["quorum"] = json!(3);
+    poll_request["min_rankings"] = json!(2);
+    poll_request["tie_break_seed"] = json!(42);
+    let (status, created) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::OK);
+    let uri = format!("/api/polls/{}", created["data"]["id"].as_str().unwrap());
+
+    // Omitted settings keep their values
+    let (status, result) = send_poll_json(&app, Method::PUT, &uri, &token, json!({"title": "Renamed"})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["quorum"], 3);
+    assert_eq!(result["data"]["min_rankings"], 2);
+    assert_eq!(result["data"]["tie_break_seed"], 42);
+
+    let (status, result) = send_poll_json(
+        &app,
+        Method::PUT,
+        &uri,
+        &token,
+        json!({"quorum": null, "min_rankings": null, "tie_break_seed": null}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result["data"]["quorum"].is_null());
+    assert!(result["data"]["min_rankings"].is_null());
+    assert!(result["data"]["tie_break_seed"].is_null());
+}
+
+#[sqlx::test]
+async fn test_reset_poll_clears_votes_but_keeps_voters(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let (status, created) = send_poll_json(&app, Method::POST, "/api/polls", &token, create_minimal_poll_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_id = Uuid::parse_str(created["data"]["id"].as_str().unwrap()).unwrap();
+    let candidate_id = Uuid::parse_str(created["data"]["candidates"][0]["id"].as_str().unwrap()).unwrap();
+
+    for email in ["reset1@example.com", "reset2@example.com"] {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(email.to_string()), None, None)
+            .await
+            .expect("Failed to create voter");
+        Ballot::create(&pool, voter.id, poll_id, vec![BallotRanking { candidate_id, rank: 1 }], None)
+            .await
+            .expect("Failed to create ballot");
+        Voter::mark_as_voted(&pool, voter.id).await.expect("Failed to mark voter");
+    }
+
+    // The poll is already open, so the reset has to be confirmed
+    let uri = format!("/api/polls/{}/reset", poll_id);
+    let (status, result) = send_poll_request(&app, Method::POST, &uri, &token).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(result["error"]["code"], "CONFIRMATION_REQUIRED");
+
+    let (status, result) = send_poll_request(&app, Method::POST, &format!("{}?confirm=true", uri), &token).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["ballots_deleted"], 2);
+
+    let ballots: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ballots WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(ballots, 0);
+
+    let (voters, voted): (i64, i64) = sqlx::query_as("SELECT COUNT(*), COUNT(voted_at) FROM voters WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!((voters, voted), (2, 0));
+
+    let candidates: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM candidates WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(candidates as usize, created["data"]["candidates"].as_array().unwrap().len());
+
+    // Only the owner can reset a poll
+    let other_token = register_user(&app, "reset-other@example.com").await;
+    let (status, _) = send_poll_request(&app, Method::POST, &format!("{}?confirm=true", uri), &other_token).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test]
+async fn test_reset_poll_clears_drafts_write_ins_and_closure(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let (status, created) = send_poll_json(&app, Method::POST, "/api/polls", &token, create_minimal_poll_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_id = Uuid::parse_str(created["data"]["id"].as_str().unwrap()).unwrap();
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("draft@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    sqlx::query("INSERT INTO ballot_drafts (voter_id, rankings) VALUES ($1, '[]')")
+        .bind(voter.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO candidates (poll_id, name, slug, display_order, is_write_in) VALUES ($1, 'Zed', 'zed', 10, true)")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE polls SET status = 'closed' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO poll_closures (poll_id, close_reason) VALUES ($1, 'manual')")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let uri = format!("/api/polls/{}/reset?confirm=true", poll_id);
+    let (status, _) = send_poll_request(&app, Method::POST, &uri, &token).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (drafts, write_ins, closures): (i64, i64, i64) = sqlx::query_as(
+        "SELECT (SELECT COUNT(*) FROM ballot_drafts WHERE voter_id = $2), \
+                (SELECT COUNT(*) FROM candidates WHERE poll_id = $1 AND is_write_in), \
+                (SELECT COUNT(*) FROM poll_closures WHERE poll_id = $1)",
+    )
+    .bind(poll_id)
+    .bind(voter.id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!((drafts, write_ins, closures), (0, 0, 0));
+
+    let (status, result) = send_poll_request(&app, Method::GET, &format!("/api/polls/{}", poll_id), &token).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["status"], "open");
+    assert_eq!(result["data"]["candidates"].as_array().unwrap().len(), created["data"]["candidates"].as_array().unwrap().len());
+}
+
+#[sqlx::test]
+async fn test_reset_poll_past_close_time_stays_closed(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let (status, created) = send_poll_json(&app, Method::POST, "/api/polls", &token, create_minimal_poll_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_id = Uuid::parse_str(created["data"]["id"].as_str().unwrap()).unwrap();
+    sqlx::query("UPDATE polls SET status = 'closed', closes_at = NOW() - INTERVAL '1 hour' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let uri = format!("/api/polls/{}/reset?confirm=true", poll_id);
+    let (status, _) = send_poll_request(&app, Method::POST, &uri, &token).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, result) = send_poll_request(&app, Method::GET, &format!("/api/polls/{}", poll_id), &token).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["status"], "closed");
+}
+
+#[sqlx::test]
+async fn test_poll_export_import_round_trip(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let poll_request = json!({
+        "title": "Board Election",
+        "description": "Annual vote",
+        "quorum": 2,
+        "min_rankings": 2,
+        "results_visibility": "public_always",
+        "default_locale": "es",
+        "closes_at": chrono::Utc::now() + chrono::Duration::days(7),
+        "candidates": [
+            {"name": "Alice", "description": "Incumbent"},
+            {"name": "Bob", "image_url": "https://example.com/bob.png"},
+            {"name": "Carol"}
+        ]
+    });
+    let (status, created) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_id = created["data"]["id"].as_str().unwrap();
+
+    let (status, exported) = send_poll_request(&app, Method::GET, &format!("/api/polls/{}/export", poll_id), &token).await;
+    assert_eq!(status, StatusCode::OK);
+    let document = exported["data"].clone();
+    assert_eq!(document["format_version"], 1);
+    assert!(document.get("id").is_none());
+
+    let (status, imported) = send_poll_json(&app, Method::POST, "/api/polls/import", &token, document.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    let imported_id = imported["data"]["id"].as_str().unwrap();
+    assert_ne!(imported_id, poll_id);
+
+    // Exporting the imported poll gives back the same document
+    let (status, reexported) = send_poll_request(&app, Method::GET, &format!("/api/polls/{}/export", imported_id), &token).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(reexported["data"], document);
+
+    // Malformed documents are rejected
+    let mut missing_candidates = document.clone();
+    missing_candidates.as_object_mut().unwrap().remove("candidates");
+    let mut wrong_version = document;
+    wrong_version["format_version"] = json!(99);
+    for invalid in [missing_candidates, wrong_version, json!({"title": "Nope"})] {
+        let (status, result) = send_poll_json(&app, Method::POST, "/api/polls/import", &token, invalid).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    }
+}
+
+async fn get_poll_with_etag(app: &Router, poll_id: &str, token: &str, if_none_match: Option<&str>) -> (StatusCode, String) {
+    let mut request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}", poll_id))
+        .header("authorization", format!("Bearer {}", token));
+    if let Some(etag) = if_none_match {
+        request = request.header("if-none-match", etag);
+    }
+
+    let response = app.clone().oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+    let etag = response.headers()["etag"].to_str().unwrap().to_string();
+    (response.status(), etag)
+}
+
+#[sqlx::test]
+async fn test_get_poll_conditional_request(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, create_minimal_poll_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_id = result["data"]["id"].as_str().unwrap().to_string();
+
+    let (status, etag) = get_poll_with_etag(&app, &poll_id, &token, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(etag.starts_with("W/\""));
+
+    // Unchanged poll: the cached copy is still good
+    let (status, unchanged_etag) = get_poll_with_etag(&app, &poll_id, &token, Some(&etag)).await;
+    assert_eq!(status, StatusCode::NOT_MODIFIED);
+    assert_eq!(unchanged_etag, etag);
+
+    let (status, _) = send_poll_json(
+        &app,
+        Method::PUT,
+        &format!("/api/polls/{}", poll_id),
+        &token,
+        json!({"title": "Renamed Poll"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, new_etag) = get_poll_with_etag(&app, &poll_id, &token, Some(&etag)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_ne!(new_etag, etag);
+}
+
+#[sqlx::test]
+async fn test_poll_timezone(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let mut invalid = create_minimal_poll_request();
+    invalid["timezone"] = json!("Mars/Olympus_Mons");
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, invalid).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert_eq!(result["error"]["fields"][0]["field"], "timezone");
+
+    let mut poll_request = create_minimal_poll_request();
+    poll_request["timezone"] = json!("America/New_York");
+    poll_request["closes_at"] = json!("2099-01-15T17:00:00Z");
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_id = result["data"]["id"].as_str().unwrap().to_string();
+
+    let (status, result) = send_poll_request(&app, Method::GET, &format!("/api/polls/{}", poll_id), &token).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["timezone"], "America/New_York");
+    assert_eq!(result["data"]["closes_at"], "2099-01-15T17:00:00Z");
+    assert_eq!(result["data"]["closes_at_local"], "2099-01-15T12:00:00-05:00");
+    assert!(result["data"]["opens_at_local"].is_null());
+}
+
+#[sqlx::test]
+async fn test_plurality_round_limit_cleared_when_switching_to_majority(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let mut poll_request = create_minimal_poll_request();
+    poll_request["win_condition"] = json!("plurality_after_rounds");
+    poll_request["plurality_round_limit"] = json!(2);
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_uri = format!("/api/polls/{}", result["data"]["id"].as_str().unwrap());
+
+    // Plurality after rounds still needs its limit
+    let (status, result) = send_poll_json(&app, Method::PUT, &poll_uri, &token, json!({"plurality_round_limit": null})).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["fields"][0]["field"], "plurality_round_limit");
+
+    let (status, result) = send_poll_json(
+        &app,
+        Method::PUT,
+        &poll_uri,
+        &token,
+        json!({"win_condition": "majority", "plurality_round_limit": null}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["win_condition"], "majority");
+    assert!(result["data"]["plurality_round_limit"].is_null());
+}
+
+#[sqlx::test]
+async fn test_poll_timezone_cleared_with_null(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let mut poll_request = create_minimal_poll_request();
+    poll_request["timezone"] = json!("America/New_York");
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_uri = format!("/api/polls/{}", result["data"]["id"].as_str().unwrap());
+
+    // Leaving the field out keeps the zone; null goes back to UTC
+    let (status, result) = send_poll_json(&app, Method::PUT, &poll_uri, &token, json!({"title": "Renamed"})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["timezone"], "America/New_York");
+
+    let (status, result) = send_poll_json(&app, Method::PUT, &poll_uri, &token, json!({"timezone": null})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result["data"]["timezone"].is_null());
+    assert!(result["data"]["closes_at_local"].is_null());
+}
+
+#[sqlx::test]
+async fn test_list_public_polls_only_open_public(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let mut poll_ids = std::collections::HashMap::new();
+    for (title, is_public, closes_in_days) in [
+        ("Later", true, 7),
+        ("Sooner", true, 2),
+        ("Private", false, 2),
+        ("Closed", true, 1),
+        ("Upcoming", true, 9),
+    ] {
+        let mut poll_request = create_minimal_poll_request();
+        poll_request["title"] = json!(title);
+        poll_request["is_public"] = json!(is_public);
+        poll_request["closes_at"] = json!(chrono::Utc::now() + chrono::Duration::days(closes_in_days));
+        let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+        assert_eq!(status, StatusCode::OK);
+        poll_ids.insert(title, Uuid::parse_str(result["data"]["id"].as_str().unwrap()).unwrap());
+    }
+    sqlx::query("UPDATE polls SET closes_at = NOW() - INTERVAL '1 hour' WHERE id = $1")
+        .bind(poll_ids["Closed"])
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE polls SET opens_at = NOW() + INTERVAL '1 day' WHERE id = $1")
+        .bind(poll_ids["Upcoming"])
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // No authorization header: discovery is public
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/api/public/polls")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["total"], 2);
+
+    let items = result["data"]["items"].as_array().unwrap();
+    let titles: Vec<&str> = items.iter().map(|item| item["title"].as_str().unwrap()).collect();
+    assert_eq!(titles, ["Sooner", "Later"]);
+    assert_eq!(items[0]["id"], poll_ids["Sooner"].to_string());
+    assert_eq!(items[0]["candidate_count"], 2);
+    assert!(items[0]["closes_at"].is_string());
+    assert!(items[0].get("user_id").is_none());
+}
+
+#[sqlx::test]
+async fn test_list_public_polls_counts_official_candidates(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let mut poll_request = create_minimal_poll_request();
+    poll_request["is_public"] = json!(true);
+    poll_request["allow_none_of_the_above"] = json!(true);
+    poll_request["closes_at"] = json!(chrono::Utc::now() + chrono::Duration::days(7));
+    let (status, result) = send_poll_json(&app, Method::POST, "/api/polls", &token, poll_request).await;
+    assert_eq!(status, StatusCode::OK);
+    let poll_id = Uuid::parse_str(result["data"]["id"].as_str().unwrap()).unwrap();
+    sqlx::query("INSERT INTO candidates (poll_id, name, slug, display_order, is_write_in) VALUES ($1, 'Zed', 'zed', 10, true)")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Neither "None of the above" nor the write-in counts
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/api/public/polls")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["items"][0]["candidate_count"], 2);
+}
+
+#[sqlx::test]
+async fn test_list_public_polls_huge_page(pool: PgPool) {
+    let app = create_test_app(pool).await;
+
+    // The offset for the last i32 page doesn't fit in an i32
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/public/polls?page={}&limit=100", i32::MAX))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["items"], json!([]));
+}
@@ -2,11 +2,15 @@ use axum::{
     body::{Body, to_bytes},
     http::{Method, Request, StatusCode},
 };
+use futures_util::StreamExt;
 use serde_json::{json, Value};
 use sqlx::PgPool;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tower::ServiceExt;
 use uuid::Uuid;
 use rankedchoice_api::models::ballot::{Ballot, BallotRanking, Voter};
+use rankedchoice_api::services::{auth::AuthService, poll_closer};
 
 mod common;
 use common::*;
@@ -122,7 +126,8 @@ async fn test_poll_results_no_votes(pool: PgPool) {
 #[sqlx::test]
 async fn test_rcv_rounds_no_votes(pool: PgPool) {
     let app = create_test_app(pool.clone()).await;
-    let token = setup_authenticated_user(&app).await;
+    // Rounds are only shown to the poll's owner unless results are public
+    let token = test_user_token(&pool).await;
     
     // Setup test poll without any votes
     setup_test_user(&pool).await;
@@ -146,9 +151,59 @@ async fn test_rcv_rounds_no_votes(pool: PgPool) {
     assert_eq!(result["success"], true);
     assert_eq!(result["data"]["total_ballots"], 0);
     assert_eq!(result["data"]["exhausted_ballots"], 0);
+    assert_eq!(result["data"]["spoiled_ballots"], 0);
     assert_eq!(result["data"]["rounds"].as_array().unwrap().len(), 0);
 }
 
+#[sqlx::test]
+async fn test_rcv_rounds_count_spoiled_ballots_separately(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    // Rounds are only shown to the poll's owner unless results are public
+    let token = test_user_token(&pool).await;
+
+    setup_test_user(&pool).await;
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    // Two votes for A, one for B, and one ballot ranking only C
+    for (i, candidate) in [0, 0, 1, 2].into_iter().enumerate() {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("spoiled{}@example.com", i)), None, None)
+            .await
+            .expect("Failed to create voter");
+        let rankings = vec![BallotRanking { candidate_id: candidate_ids[candidate], rank: 1 }];
+        Ballot::create(&pool, voter.id, poll_id, rankings, None)
+            .await
+            .expect("Failed to create ballot");
+    }
+
+    // Removing C leaves its only ballot without a valid candidate
+    sqlx::query("DELETE FROM candidates WHERE id = $1")
+        .bind(candidate_ids[2])
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results/rounds", poll_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["total_ballots"], 4);
+    assert_eq!(result["data"]["spoiled_ballots"], 1);
+    assert_eq!(result["data"]["exhausted_ballots"], 0);
+    assert_eq!(result["data"]["rounds"][0]["exhausted_ballots"], 0);
+    assert_eq!(result["data"]["rounds"][0]["total_votes"], 3.0);
+}
+
 #[sqlx::test]
 async fn test_results_with_votes(pool: PgPool) {
     let app = create_test_app(pool.clone()).await;
@@ -161,6 +216,7 @@ async fn test_results_with_votes(pool: PgPool) {
     // Create a voter and submit a ballot
     let voter = Voter::create(
         &pool, 
+        &test_signer(&pool),
         poll_id, 
         Some("voter@example.com".to_string()), 
         None, 
@@ -183,7 +239,8 @@ async fn test_results_with_votes(pool: PgPool) {
         .expect("Failed to create ballot");
     
     // Test getting results
-    let token = setup_authenticated_user(&app).await;
+    // Rounds are only shown to the poll's owner unless results are public
+    let token = test_user_token(&pool).await;
     let request = Request::builder()
         .method(Method::GET)
         .uri(format!("/api/polls/{}/results", poll_id))
@@ -224,4 +281,1352 @@ async fn test_results_with_votes(pool: PgPool) {
     
     let rounds = result["data"]["rounds"].as_array().unwrap();
     assert!(!rounds.is_empty());
-} 
\ No newline at end of file
+}
+
+#[sqlx::test]
+async fn test_results_conditional_request(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    setup_test_user(&pool).await;
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let get_results = |if_none_match: Option<String>| {
+        let mut request = Request::builder()
+            .method(Method::GET)
+            .uri(format!("/api/polls/{}/results", poll_id))
+            .header("authorization", format!("Bearer {}", token));
+        if let Some(etag) = if_none_match {
+            request = request.header("if-none-match", etag);
+        }
+        app.clone().oneshot(request.body(Body::empty()).unwrap())
+    };
+
+    let response = get_results(None).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers()["etag"].to_str().unwrap().to_string();
+
+    // No new ballots: 304 with an empty body
+    let response = get_results(Some(etag.clone())).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()["etag"], etag.as_str());
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(body.is_empty());
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("voter@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    Ballot::create(&pool, voter.id, poll_id, vec![BallotRanking { candidate_id: candidate_ids[0], rank: 1 }], None)
+        .await
+        .expect("Failed to create ballot");
+
+    // A new ballot changes the results, so the stale tag no longer matches
+    let response = get_results(Some(etag.clone())).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(response.headers()["etag"], etag.as_str());
+}
+
+#[sqlx::test]
+async fn test_results_etag_changes_when_poll_closes(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    setup_test_user(&pool).await;
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("voter@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    Ballot::create(&pool, voter.id, poll_id, vec![BallotRanking { candidate_id: candidate_ids[0], rank: 1 }], None)
+        .await
+        .expect("Failed to create ballot");
+    sqlx::query("UPDATE polls SET closes_at = NOW() + INTERVAL '1 second' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let get_results = |if_none_match: Option<String>| {
+        let mut request = Request::builder()
+            .method(Method::GET)
+            .uri(format!("/api/polls/{}/results", poll_id))
+            .header("authorization", format!("Bearer {}", token));
+        if let Some(etag) = if_none_match {
+            request = request.header("if-none-match", etag);
+        }
+        app.clone().oneshot(request.body(Body::empty()).unwrap())
+    };
+
+    let response = get_results(None).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers()["etag"].to_str().unwrap().to_string();
+
+    // Nothing in the database changes as the close time passes, but the status does
+    tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
+
+    let response = get_results(Some(etag.clone())).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(response.headers()["etag"], etag.as_str());
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["status"], "completed");
+}
+
+// Stand-in for the email microservice that records recipients and fails for one address
+async fn spawn_stub_email_service() -> (String, Arc<Mutex<Vec<String>>>) {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let recorded = received.clone();
+
+    let stub = axum::Router::new().route(
+        "/api/email/poll-results",
+        axum::routing::post(move |axum::Json(body): axum::Json<Value>| {
+            let recorded = recorded.clone();
+            async move {
+                let to = body["to"].as_str().unwrap_or_default().to_string();
+                recorded.lock().unwrap().push(to.clone());
+                if to == "bounce@example.com" {
+                    (StatusCode::BAD_REQUEST, axum::Json(json!({"success": false})))
+                } else {
+                    (StatusCode::OK, axum::Json(json!({"success": true, "data": {"messageId": "stub", "recipient": to}})))
+                }
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, stub).await.unwrap();
+    });
+
+    (format!("http://{}", addr), received)
+}
+
+#[sqlx::test]
+async fn test_notify_poll_results(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let (email_url, received) = spawn_stub_email_service().await;
+    std::env::set_var("EMAIL_SERVICE_URL", &email_url);
+    std::env::set_var("EMAIL_SERVICE_API_KEY", "test-api-key");
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET user_id = (SELECT id FROM users WHERE email = 'resultstest@example.com') WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let mut voters = Vec::new();
+    for email in ["first@example.com", "bounce@example.com", "Anonymous-1234"] {
+        voters.push(
+            Voter::create(&pool, &test_signer(&pool), poll_id, Some(email.to_string()), None, None)
+                .await
+                .expect("Failed to create voter"),
+        );
+    }
+
+    let rankings = vec![BallotRanking { candidate_id: candidate_ids[0], rank: 1 }];
+    Ballot::create(&pool, voters[0].id, poll_id, rankings, None)
+        .await
+        .expect("Failed to create ballot");
+
+    let notify = |app: axum::Router| {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/api/polls/{}/results/notify", poll_id))
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+        async move {
+            let response = app.oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice::<Value>(&body).unwrap()
+        }
+    };
+
+    // Open polls cannot be announced yet
+    let result = notify(app.clone()).await;
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "POLL_NOT_CLOSED");
+    assert!(received.lock().unwrap().is_empty());
+
+    sqlx::query("UPDATE polls SET opens_at = NOW() - INTERVAL '2 days', closes_at = NOW() - INTERVAL '1 day' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let result = notify(app.clone()).await;
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["sent"], 1);
+    assert_eq!(result["data"]["failed"], 1);
+    assert_eq!(result["data"]["failedRecipients"], json!(["bounce@example.com"]));
+
+    let mut recipients = received.lock().unwrap().clone();
+    recipients.sort();
+    assert_eq!(recipients, vec!["bounce@example.com", "first@example.com"]);
+}
+
+
+#[sqlx::test]
+async fn test_results_with_single_candidate(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    setup_test_user(&pool).await;
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("voter@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    let rankings = vec![BallotRanking { candidate_id: candidate_ids[0], rank: 1 }];
+    Ballot::create(&pool, voter.id, poll_id, rankings, None)
+        .await
+        .expect("Failed to create ballot");
+
+    // Leave only the candidate the ballot ranked
+    sqlx::query("DELETE FROM candidates WHERE poll_id = $1 AND id <> $2")
+        .bind(poll_id)
+        .bind(candidate_ids[0])
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Rounds are only shown to the poll's owner unless results are public
+    let token = test_user_token(&pool).await;
+    for uri in [
+        format!("/api/polls/{}/results", poll_id),
+        format!("/api/polls/{}/results/rounds", poll_id),
+    ] {
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(uri)
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(result["success"], false);
+        assert_eq!(result["error"]["code"], "INSUFFICIENT_CANDIDATES");
+    }
+}
+
+#[sqlx::test]
+async fn test_finalize_closed_polls(pool: PgPool) {
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("voter@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    let rankings = vec![BallotRanking { candidate_id: candidate_ids[1], rank: 1 }];
+    Ballot::create(&pool, voter.id, poll_id, rankings, None)
+        .await
+        .expect("Failed to create ballot");
+
+    let auth_service = AuthService::new(pool.clone()).unwrap();
+
+    // Nothing is due while the poll is still open
+    assert_eq!(poll_closer::finalize_closed_polls(&auth_service, false).await.unwrap(), 0);
+
+    sqlx::query("UPDATE polls SET opens_at = NOW() - INTERVAL '2 days', closes_at = NOW() - INTERVAL '1 minute' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(poll_closer::finalize_closed_polls(&auth_service, false).await.unwrap(), 1);
+
+    let (status, final_results): (String, Option<Value>) =
+        sqlx::query_as("SELECT status, final_results FROM polls WHERE id = $1")
+            .bind(poll_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(status, "closed");
+    let final_results = final_results.expect("final results should be cached");
+    assert_eq!(final_results["total_votes"], 1);
+    assert_eq!(final_results["winner"]["candidate_id"], candidate_ids[1].to_string());
+
+    // Already-closed polls are not finalized again
+    assert_eq!(poll_closer::finalize_closed_polls(&auth_service, false).await.unwrap(), 0);
+}
+
+#[sqlx::test]
+async fn test_closed_poll_serves_final_snapshot(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("voter@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    let rankings = vec![BallotRanking { candidate_id: candidate_ids[0], rank: 1 }];
+    Ballot::create(&pool, voter.id, poll_id, rankings, None)
+        .await
+        .expect("Failed to create ballot");
+
+    let auth_service = AuthService::new(pool.clone()).unwrap();
+    assert!(poll_closer::finalize_poll_now(&auth_service, poll_id).await.unwrap());
+
+    // Renaming the winner and removing a candidate after close leaves the declared result alone
+    sqlx::query("UPDATE candidates SET name = 'Renamed' WHERE id = $1")
+        .bind(candidate_ids[0])
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM candidates WHERE id = $1")
+        .bind(candidate_ids[2])
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let token = setup_authenticated_user(&app).await;
+    let (status, result) = get_json(&app, &token, &format!("/api/polls/{}/results", poll_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["data"]["close_reason"], "manual");
+    assert_eq!(result["data"]["winner"]["candidate_id"], candidate_ids[0].to_string());
+    assert_eq!(result["data"]["winner"]["name"], "Candidate A");
+    assert_eq!(result["data"]["final_rankings"].as_array().unwrap().len(), 3);
+    assert!(result["data"].get("rounds").is_none());
+
+    let (_, result) = get_json(&app, &token, &format!("/api/polls/{}/results?include_rounds=true", poll_id)).await;
+    assert!(result["data"]["rounds"].is_array());
+
+    // The snapshot is written once
+    assert!(!poll_closer::finalize_poll_now(&auth_service, poll_id).await.unwrap());
+    let closures: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM poll_closures WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(closures, 1);
+}
+
+#[sqlx::test]
+async fn test_results_quorum_not_met(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    setup_test_user(&pool).await;
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET quorum = 3 WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Two unanimous ballots produce a clear winner, but fall short of the quorum
+    for email in ["first@example.com", "second@example.com"] {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(email.to_string()), None, None)
+            .await
+            .expect("Failed to create voter");
+        let rankings = vec![BallotRanking { candidate_id: candidate_ids[0], rank: 1 }];
+        Ballot::create(&pool, voter.id, poll_id, rankings, None)
+            .await
+            .expect("Failed to create ballot");
+    }
+
+    let token = setup_authenticated_user(&app).await;
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results", poll_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["status"], "quorum_not_met");
+    assert_eq!(result["data"]["quorum"], 3);
+    assert_eq!(result["data"]["quorum_met"], false);
+    assert!(result["data"]["winner"].is_null());
+    assert_eq!(result["data"]["final_rankings"][0]["candidate_id"], candidate_ids[0].to_string());
+}
+
+// Read the next SSE event from a streaming body and return its JSON payload
+async fn next_sse_data(stream: &mut axum::body::BodyDataStream) -> Value {
+    let chunk = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("Timed out waiting for a results event")
+        .expect("Results stream ended")
+        .expect("Failed to read results stream");
+    let text = String::from_utf8(chunk.to_vec()).unwrap();
+
+    assert!(text.starts_with("event: results\n"), "unexpected event: {}", text);
+    let data = text.lines().find_map(|line| line.strip_prefix("data: ")).unwrap();
+    serde_json::from_str(data).unwrap()
+}
+
+#[sqlx::test]
+async fn test_results_stream_pushes_submitted_ballots(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET is_public = true, results_visibility = 'public_always' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Public results stream without authentication
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results/stream", poll_id))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "text/event-stream");
+
+    let mut stream = response.into_body().into_data_stream();
+    let snapshot = next_sse_data(&mut stream).await;
+    assert_eq!(snapshot["status"], "no_votes");
+    assert_eq!(snapshot["total_votes"], 0);
+
+    let ballot_data = json!({
+        "rankings": [
+            {"candidate_id": candidate_ids[1], "rank": 1}
+        ]
+    });
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/public/polls/{}/vote", poll_id))
+        .header("content-type", "application/json")
+        .body(Body::from(ballot_data.to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], true);
+
+    let update = next_sse_data(&mut stream).await;
+    assert_eq!(update["total_votes"], 1);
+    assert_eq!(update["winner"]["candidate_id"], candidate_ids[1].to_string());
+}
+
+#[sqlx::test]
+async fn test_results_stream_requires_auth_for_private_poll(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results/stream", poll_id))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[sqlx::test]
+async fn test_borda_poll_results(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    setup_test_user(&pool).await;
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET poll_type = 'borda' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Candidate 1 wins on first choices, but candidate 2 is the consensus second choice
+    let orders = [
+        [0, 1, 2],
+        [0, 1, 2],
+        [2, 1, 0],
+        [1, 2, 0],
+    ];
+    for (i, order) in orders.iter().enumerate() {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("borda{}@example.com", i)), None, None)
+            .await
+            .expect("Failed to create voter");
+        let rankings = order
+            .iter()
+            .enumerate()
+            .map(|(rank, &candidate)| BallotRanking { candidate_id: candidate_ids[candidate], rank: rank as i32 + 1 })
+            .collect();
+        Ballot::create(&pool, voter.id, poll_id, rankings, None)
+            .await
+            .expect("Failed to create ballot");
+    }
+
+    let token = setup_authenticated_user(&app).await;
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results", poll_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    // Points (2 for first, 1 for second): 4, 5 and 3
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["winner"]["candidate_id"], candidate_ids[1].to_string());
+    assert_eq!(result["data"]["winner"]["final_votes"], 5.0);
+    assert_eq!(result["data"]["final_rankings"][0]["candidate_id"], candidate_ids[1].to_string());
+}
+
+#[sqlx::test]
+async fn test_multi_winner_poll_results(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let mut candidate_ids = create_test_candidates(&pool, poll_id).await;
+    for (i, name) in ["Candidate D", "Candidate E"].iter().enumerate() {
+        let (id,): (Uuid,) = sqlx::query_as(
+            "INSERT INTO candidates (poll_id, name, slug, display_order) VALUES ($1, $2, $3, $4) RETURNING id",
+        )
+        .bind(poll_id)
+        .bind(name)
+        .bind(name.to_lowercase().replace(' ', "-"))
+        .bind(i as i32 + 4)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        candidate_ids.push(id);
+    }
+    sqlx::query("UPDATE polls SET poll_type = 'multi_winner', num_winners = 3 WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // 14 ballots for 3 seats: quota 4. A and C reach it at once; D takes the last seat
+    // after B (holding A's small surplus) and E are eliminated
+    let orders: [(&[usize], usize); 4] = [(&[0, 1], 5), (&[2], 4), (&[3], 3), (&[4, 3], 2)];
+    let mut voter_number = 0;
+    for (order, copies) in orders {
+        for _ in 0..copies {
+            voter_number += 1;
+            let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("stv{}@example.com", voter_number)), None, None)
+                .await
+                .expect("Failed to create voter");
+            let rankings = order
+                .iter()
+                .enumerate()
+                .map(|(rank, &candidate)| BallotRanking { candidate_id: candidate_ids[candidate], rank: rank as i32 + 1 })
+                .collect();
+            Ballot::create(&pool, voter.id, poll_id, rankings, None)
+                .await
+                .expect("Failed to create ballot");
+        }
+    }
+
+    let token = setup_authenticated_user(&app).await;
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results", poll_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let data = &serde_json::from_slice::<Value>(&body).unwrap()["data"];
+
+    assert_eq!(data["algorithm"], "multi_winner_stv_v1");
+    assert_eq!(data["quota"], 4.0);
+    let winners = data["winners"].as_array().unwrap();
+    let elected: Vec<(&str, u64)> = winners
+        .iter()
+        .map(|w| (w["candidate_id"].as_str().unwrap(), w["elected_round"].as_u64().unwrap()))
+        .collect();
+    assert_eq!(
+        elected,
+        [
+            (candidate_ids[0].to_string().as_str(), 1),
+            (candidate_ids[2].to_string().as_str(), 1),
+            (candidate_ids[3].to_string().as_str(), 4),
+        ]
+    );
+    // D reaches the quota once E's ballots transfer, so no seat is filled by default
+    assert!(winners.iter().all(|w| w["elected_by"] == "quota"));
+    assert_eq!(data["winner"]["candidate_id"], winners[0]["candidate_id"]);
+    assert_eq!(data["final_rankings"][2]["candidate_id"], candidate_ids[3].to_string());
+}
+
+#[sqlx::test]
+async fn test_results_include_rounds_only_when_requested(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    setup_test_user(&pool).await;
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("rounds@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    let rankings = vec![BallotRanking { candidate_id: candidate_ids[0], rank: 1 }];
+    Ballot::create(&pool, voter.id, poll_id, rankings, None)
+        .await
+        .expect("Failed to create ballot");
+
+    let token = setup_authenticated_user(&app).await;
+    let mut data = Vec::new();
+    for query in ["", "?include_rounds=false", "?include_rounds=true"] {
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(format!("/api/polls/{}/results{}", poll_id, query))
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["success"], true);
+        data.push(result["data"].clone());
+    }
+
+    assert!(data[0].get("rounds").is_none());
+    assert!(data[1].get("rounds").is_none());
+
+    let rounds = data[2]["rounds"].as_array().unwrap();
+    assert_eq!(rounds.len(), 1);
+    assert_eq!(rounds[0]["round_number"], 1);
+    assert_eq!(rounds[0]["winner"]["candidate_id"], candidate_ids[0].to_string());
+    assert_eq!(rounds[0]["vote_counts"][candidate_ids[0].to_string()]["votes"], 1.0);
+}
+
+// Fetch the unauthenticated results endpoint for a poll
+async fn get_public_results(app: &axum::Router, poll_id: Uuid) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/public/polls/{}/results", poll_id))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+async fn set_results_visibility(pool: &PgPool, poll_id: Uuid, visibility: &str, closed: bool) {
+    sqlx::query(
+        "UPDATE polls SET results_visibility = $1, closes_at = CASE WHEN $2 THEN NOW() - INTERVAL '1 hour' ELSE NULL END WHERE id = $3",
+    )
+    .bind(visibility)
+    .bind(closed)
+    .bind(poll_id)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[sqlx::test]
+async fn test_public_results_private_visibility(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    create_test_candidates(&pool, poll_id).await;
+
+    // Private results stay hidden even on a public, closed poll
+    sqlx::query("UPDATE polls SET is_public = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    set_results_visibility(&pool, poll_id, "private", true).await;
+
+    let (status, result) = get_public_results(&app, poll_id).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(result["error"]["code"], "RESULTS_NOT_PUBLIC");
+}
+
+#[sqlx::test]
+async fn test_public_results_public_after_close_visibility(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    create_test_candidates(&pool, poll_id).await;
+
+    set_results_visibility(&pool, poll_id, "public_after_close", false).await;
+    let (status, result) = get_public_results(&app, poll_id).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(result["error"]["code"], "RESULTS_NOT_PUBLIC");
+
+    set_results_visibility(&pool, poll_id, "public_after_close", true).await;
+    let (status, result) = get_public_results(&app, poll_id).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["poll_id"], poll_id.to_string());
+}
+
+#[sqlx::test]
+async fn test_public_results_public_always_visibility(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    // The poll itself is private (invite-only), but its tallies are published while open
+    let poll_id = create_test_poll(&pool).await;
+    create_test_candidates(&pool, poll_id).await;
+    set_results_visibility(&pool, poll_id, "public_always", false).await;
+
+    let (status, result) = get_public_results(&app, poll_id).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["status"], "no_votes");
+}
+
+#[sqlx::test]
+async fn test_public_results_not_found(pool: PgPool) {
+    let app = create_test_app(pool).await;
+
+    let (status, result) = get_public_results(&app, Uuid::new_v4()).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(result["error"]["code"], "NOT_FOUND");
+}
+
+#[sqlx::test]
+async fn test_pairwise_results_report_condorcet_cycle(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    set_results_visibility(&pool, poll_id, "public_always", false).await;
+
+    // A > B > C, B > C > A and C > A > B: every candidate loses one matchup 1-2
+    let orders = [[0, 1, 2], [1, 2, 0], [2, 0, 1]];
+    for (i, order) in orders.iter().enumerate() {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("cycle{}@example.com", i)), None, None)
+            .await
+            .expect("Failed to create voter");
+        let rankings = order
+            .iter()
+            .enumerate()
+            .map(|(rank, &candidate)| BallotRanking { candidate_id: candidate_ids[candidate], rank: rank as i32 + 1 })
+            .collect();
+        Ballot::create(&pool, voter.id, poll_id, rankings, None)
+            .await
+            .expect("Failed to create ballot");
+    }
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results/pairwise", poll_id))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    let [a, b, c] = [0, 1, 2].map(|i| candidate_ids[i].to_string());
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["total_ballots"], 3);
+    assert_eq!(result["data"]["matrix"][&a][&b], 2);
+    assert_eq!(result["data"]["matrix"][&b][&a], 1);
+    assert_eq!(result["data"]["condorcet_winner"], Value::Null);
+    assert_eq!(result["data"]["cycle"], json!([a, b, c]));
+}
+
+#[sqlx::test]
+async fn test_first_choice_results_match_first_round(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let token = setup_authenticated_user(&app).await;
+    sqlx::query("UPDATE polls SET user_id = (SELECT id FROM users WHERE email = 'resultstest@example.com') WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // First choices: A three times, B twice, C once
+    let orders: [&[usize]; 6] = [&[0, 1], &[0], &[0, 2], &[1, 0], &[1], &[2, 1]];
+    for (i, order) in orders.iter().enumerate() {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("first{}@example.com", i)), None, None)
+            .await
+            .expect("Failed to create voter");
+        let rankings = order
+            .iter()
+            .enumerate()
+            .map(|(rank, &candidate)| BallotRanking { candidate_id: candidate_ids[candidate], rank: rank as i32 + 1 })
+            .collect();
+        Ballot::create(&pool, voter.id, poll_id, rankings, None)
+            .await
+            .expect("Failed to create ballot");
+    }
+
+    let fetch = |uri: String| {
+        let app = app.clone();
+        let token = token.clone();
+        async move {
+            let request = Request::builder()
+                .method(Method::GET)
+                .uri(uri)
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap();
+            let response = app.oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice::<Value>(&body).unwrap()
+        }
+    };
+
+    let first_choice = fetch(format!("/api/polls/{}/results/first-choice", poll_id)).await;
+    let rounds = fetch(format!("/api/polls/{}/results/rounds", poll_id)).await;
+
+    assert_eq!(first_choice["success"], true);
+    assert_eq!(first_choice["data"]["total_ballots"], 6);
+    assert_eq!(first_choice["data"]["total_votes"], 6);
+
+    let counts = first_choice["data"]["candidates"].as_array().unwrap();
+    let ordered: Vec<&str> = counts.iter().map(|c| c["candidate_id"].as_str().unwrap()).collect();
+    let expected: Vec<String> = candidate_ids.iter().map(|id| id.to_string()).collect();
+    assert_eq!(ordered, expected);
+    assert_eq!(counts[0]["votes"], 3);
+    assert_eq!(counts[0]["percentage"], 50.0);
+
+    // Each candidate's first-choice count is their round-1 tally
+    let round_one = &rounds["data"]["rounds"][0]["vote_counts"];
+    for count in counts {
+        let candidate_id = count["candidate_id"].as_str().unwrap();
+        assert_eq!(round_one[candidate_id]["votes"].as_f64().unwrap(), count["votes"].as_f64().unwrap());
+    }
+}
+
+#[sqlx::test]
+async fn test_results_by_affiliation_group_candidates(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    set_results_visibility(&pool, poll_id, "public_always", false).await;
+
+    // A and C stand for the Green party, B for the Blue party
+    for (index, affiliation) in [(0, "Green"), (1, "Blue"), (2, "Green")] {
+        sqlx::query("UPDATE candidates SET affiliation = $1 WHERE id = $2")
+            .bind(affiliation)
+            .bind(candidate_ids[index])
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    // A 3, B 2, C 1 first choices; C is eliminated and transfers to A, who wins 4-2
+    let orders: [&[usize]; 6] = [&[0], &[0], &[0], &[1], &[1], &[2, 0]];
+    for (i, order) in orders.iter().enumerate() {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("party{}@example.com", i)), None, None)
+            .await
+            .expect("Failed to create voter");
+        let rankings = order
+            .iter()
+            .enumerate()
+            .map(|(rank, &candidate)| BallotRanking { candidate_id: candidate_ids[candidate], rank: rank as i32 + 1 })
+            .collect();
+        Ballot::create(&pool, voter.id, poll_id, rankings, None)
+            .await
+            .expect("Failed to create ballot");
+    }
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results/by-affiliation", poll_id))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["total_votes"], 6);
+
+    let affiliations = result["data"]["affiliations"].as_array().unwrap();
+    assert_eq!(affiliations.len(), 2);
+
+    let green = &affiliations[0];
+    assert_eq!(green["affiliation"], "Green");
+    assert_eq!(green["votes"], 4.0);
+    assert_eq!(green["seats_won"], 1);
+    let members: Vec<&str> = green["candidates"].as_array().unwrap()
+        .iter()
+        .map(|c| c["candidate_id"].as_str().unwrap())
+        .collect();
+    assert_eq!(members, vec![candidate_ids[0].to_string(), candidate_ids[2].to_string()]);
+
+    let blue = &affiliations[1];
+    assert_eq!(blue["affiliation"], "Blue");
+    assert_eq!(blue["votes"], 2.0);
+    assert_eq!(blue["seats_won"], 0);
+}
+
+// Cast one ballot per order (indexes into the test candidates) and return the results winner
+async fn winner_for_orders(pool: PgPool, orders: &[&[usize]]) -> (Vec<Uuid>, Value) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let token = setup_authenticated_user(&app).await;
+    sqlx::query("UPDATE polls SET user_id = (SELECT id FROM users WHERE email = 'resultstest@example.com') WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    for (i, order) in orders.iter().enumerate() {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("margin{}@example.com", i)), None, None)
+            .await
+            .expect("Failed to create voter");
+        let rankings = order
+            .iter()
+            .enumerate()
+            .map(|(rank, &candidate)| BallotRanking { candidate_id: candidate_ids[candidate], rank: rank as i32 + 1 })
+            .collect();
+        Ballot::create(&pool, voter.id, poll_id, rankings, None)
+            .await
+            .expect("Failed to create ballot");
+    }
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results", poll_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], true);
+
+    (candidate_ids, result["data"]["winner"].clone())
+}
+
+#[sqlx::test]
+async fn test_winner_margin_for_first_round_majority(pool: PgPool) {
+    let (candidate_ids, winner) = winner_for_orders(pool, &[&[0], &[0], &[0], &[1]]).await;
+
+    assert_eq!(winner["candidate_id"], candidate_ids[0].to_string());
+    assert_eq!(winner["won_first_round"], true);
+    assert_eq!(winner["margin_over_runner_up"], 2.0);
+}
+
+#[sqlx::test]
+async fn test_winner_margin_for_transfer_win(pool: PgPool) {
+    // B leads on first choices 4-3-2, but C's ballots transfer to A, who wins 5-4
+    let orders: [&[usize]; 9] = [&[1], &[1], &[1], &[1], &[0], &[0], &[0], &[2, 0], &[2, 0]];
+    let (candidate_ids, winner) = winner_for_orders(pool, &orders).await;
+
+    assert_eq!(winner["candidate_id"], candidate_ids[0].to_string());
+    assert_eq!(winner["won_first_round"], false);
+    assert_eq!(winner["margin_over_runner_up"], 1.0);
+    assert_eq!(winner["final_votes"], 5.0);
+}
+
+#[sqlx::test]
+async fn test_none_of_the_above_beats_the_field(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/polls")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({
+            "title": "Board election",
+            "allow_none_of_the_above": true,
+            "candidates": [{"name": "Alice"}, {"name": "Bob"}]
+        }).to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let poll: Value = serde_json::from_slice(&body).unwrap();
+
+    // The reserved candidate is listed last, after the real ones
+    let poll_id: Uuid = poll["data"]["id"].as_str().unwrap().parse().unwrap();
+    let candidates = poll["data"]["candidates"].as_array().unwrap();
+    assert_eq!(candidates.len(), 3);
+    assert_eq!(candidates[2]["name"], "None of the above");
+    assert_eq!(candidates[2]["is_nota"], true);
+    let candidate_ids: Vec<Uuid> = candidates.iter().map(|c| c["id"].as_str().unwrap().parse().unwrap()).collect();
+
+    // NOTA trails Alice on first choices 2-2-1 but picks up Bob's ballot and wins 3-2
+    let orders: [&[usize]; 5] = [&[2], &[2], &[0], &[0], &[1, 2]];
+    for (i, order) in orders.iter().enumerate() {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("nota{}@example.com", i)), None, None)
+            .await
+            .expect("Failed to create voter");
+        let rankings = order
+            .iter()
+            .enumerate()
+            .map(|(rank, &candidate)| BallotRanking { candidate_id: candidate_ids[candidate], rank: rank as i32 + 1 })
+            .collect();
+        Ballot::create(&pool, voter.id, poll_id, rankings, None)
+            .await
+            .expect("Failed to create ballot");
+    }
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results", poll_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["outcome"], "no_confidence");
+    assert!(result["data"]["winner"].is_null());
+    assert_eq!(result["data"]["final_rankings"][0]["candidate_id"], candidate_ids[2].to_string());
+}
+
+async fn post_batch_results(app: &axum::Router, token: &str, poll_ids: &[Uuid]) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/admin/results/batch")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({ "poll_ids": poll_ids }).to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[sqlx::test]
+async fn test_batch_results_for_two_polls(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    // Each poll is won by a different candidate
+    let mut polls = Vec::new();
+    for (poll_index, winner) in [0usize, 1].into_iter().enumerate() {
+        let poll_id = create_test_poll(&pool).await;
+        let candidate_ids = create_test_candidates(&pool, poll_id).await;
+        for i in 0..3 {
+            let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("batch{}-{}@example.com", poll_index, i)), None, None)
+                .await
+                .expect("Failed to create voter");
+            let rankings = vec![BallotRanking { candidate_id: candidate_ids[winner], rank: 1 }];
+            Ballot::create(&pool, voter.id, poll_id, rankings, None)
+                .await
+                .expect("Failed to create ballot");
+        }
+        polls.push((poll_id, candidate_ids[winner]));
+    }
+    let missing_poll_id = Uuid::new_v4();
+    let poll_ids = [polls[0].0, polls[1].0, missing_poll_id];
+
+    // Ordinary organizers can't use the batch endpoint
+    let (status, _) = post_batch_results(&app, &token, &poll_ids).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    sqlx::query("UPDATE users SET role = 'admin' WHERE email = 'resultstest@example.com'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let (status, result) = post_batch_results(&app, &token, &poll_ids).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["success"], true);
+
+    for (poll_id, winner_id) in &polls {
+        let poll_results = &result["data"][poll_id.to_string()];
+        assert_eq!(poll_results["poll_id"], poll_id.to_string());
+        assert_eq!(poll_results["total_votes"], 3);
+        assert_eq!(poll_results["winner"]["candidate_id"], winner_id.to_string());
+    }
+    assert_eq!(result["data"][missing_poll_id.to_string()]["error"]["code"], "NOT_FOUND");
+}
+
+#[sqlx::test]
+async fn test_condorcet_poll_results(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    setup_test_user(&pool).await;
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET poll_type = 'condorcet' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // A cycle: A beats B 5-2, B beats C 5-2 and C beats A 4-3. Schulze overrides the
+    // weakest defeat, giving A > B > C
+    let orders = [[0, 1, 2], [0, 1, 2], [0, 1, 2], [1, 2, 0], [1, 2, 0], [2, 0, 1], [2, 0, 1]];
+    for (i, order) in orders.iter().enumerate() {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("schulze{}@example.com", i)), None, None)
+            .await
+            .expect("Failed to create voter");
+        let rankings = order
+            .iter()
+            .enumerate()
+            .map(|(rank, &candidate)| BallotRanking { candidate_id: candidate_ids[candidate], rank: rank as i32 + 1 })
+            .collect();
+        Ballot::create(&pool, voter.id, poll_id, rankings, None)
+            .await
+            .expect("Failed to create ballot");
+    }
+
+    let token = setup_authenticated_user(&app).await;
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results", poll_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["winner"]["candidate_id"], candidate_ids[0].to_string());
+    let ranked: Vec<&str> = result["data"]["final_rankings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|ranking| ranking["candidate_id"].as_str().unwrap())
+        .collect();
+    let expected: Vec<String> = candidate_ids.iter().map(|id| id.to_string()).collect();
+    assert_eq!(ranked, expected);
+}
+
+#[sqlx::test]
+async fn test_results_report_computed_at_and_algorithm(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    setup_test_user(&pool).await;
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("algorithm@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    let rankings = vec![
+        BallotRanking { candidate_id: candidate_ids[0], rank: 1 },
+        BallotRanking { candidate_id: candidate_ids[1], rank: 2 },
+    ];
+    Ballot::create(&pool, voter.id, poll_id, rankings, None)
+        .await
+        .expect("Failed to create ballot");
+
+    // Rounds are only shown to the poll's owner unless results are public
+    let token = test_user_token(&pool).await;
+    for (poll_type, algorithm) in [
+        ("single_winner", "single_winner_irv_v2"),
+        ("approval", "approval_v1"),
+        ("borda", "borda_v1"),
+        ("condorcet", "condorcet_schulze_v1"),
+    ] {
+        sqlx::query("UPDATE polls SET poll_type = $1 WHERE id = $2")
+            .bind(poll_type)
+            .bind(poll_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        for path in ["results", "results/rounds"] {
+            let request = Request::builder()
+                .method(Method::GET)
+                .uri(format!("/api/polls/{}/{}", poll_id, path))
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap();
+
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let result: Value = serde_json::from_slice(&body).unwrap();
+
+            assert_eq!(result["success"], true, "{} {}", poll_type, path);
+            assert_eq!(result["data"]["algorithm"], algorithm, "{} {}", poll_type, path);
+            let computed_at = result["data"]["computed_at"].as_str().expect("computed_at missing");
+            assert!(chrono::DateTime::parse_from_rfc3339(computed_at).is_ok());
+        }
+    }
+}
+
+#[sqlx::test]
+async fn test_preview_results_with_sample_ballots(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let token = setup_authenticated_user(&app).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET user_id = (SELECT id FROM users WHERE email = 'resultstest@example.com') WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let preview = |ballots: Value| {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/api/polls/{}/results/preview", poll_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(json!({ "ballots": ballots }).to_string()))
+            .unwrap();
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, serde_json::from_slice::<Value>(&body).unwrap())
+        }
+    };
+
+    let [a, b, c] = [candidate_ids[0], candidate_ids[1], candidate_ids[2]];
+    // A ballot ranking the same candidate twice is refused
+    let (status, result) = preview(json!([
+        {"rankings": [{"candidate_id": a, "rank": 1}]},
+        {"rankings": [{"candidate_id": c, "rank": 2}, {"candidate_id": c, "rank": 1}]},
+    ]))
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(result["error"]["code"], "INVALID_BALLOT");
+
+    // C leads on first choices, but B is eliminated first and transfers to A
+    let mut ballots = Vec::new();
+    ballots.extend((0..3).map(|_| json!({"rankings": [{"candidate_id": a, "rank": 1}]})));
+    ballots.extend((0..2).map(|_| json!({"rankings": [{"candidate_id": b, "rank": 1}, {"candidate_id": a, "rank": 2}]})));
+    ballots.extend((0..4).map(|_| json!({"rankings": [{"candidate_id": c, "rank": 1}]})));
+    let (status, result) = preview(Value::Array(ballots)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["total_votes"], 9);
+    assert_eq!(result["data"]["winner"]["candidate_id"], a.to_string());
+    let rounds = result["data"]["rounds"].as_array().unwrap();
+    assert_eq!(rounds.len(), 2);
+    assert_eq!(rounds[0]["eliminated"]["candidate_id"], b.to_string());
+
+    // Nothing was recorded
+    let ballots: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ballots WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(ballots, 0);
+}
+
+async fn get_json(app: &axum::Router, token: &str, uri: &str) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[sqlx::test]
+async fn test_results_versions_track_setting_changes(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    setup_test_user(&pool).await;
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    for (i, order) in [[0, 1, 2], [0, 1, 2], [2, 1, 0], [1, 2, 0]].iter().enumerate() {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("recount{}@example.com", i)), None, None)
+            .await
+            .expect("Failed to create voter");
+        let rankings = order
+            .iter()
+            .enumerate()
+            .map(|(rank, &candidate)| BallotRanking { candidate_id: candidate_ids[candidate], rank: rank as i32 + 1 })
+            .collect();
+        Ballot::create(&pool, voter.id, poll_id, rankings, None)
+            .await
+            .expect("Failed to create ballot");
+    }
+
+    let token = test_user_token(&pool).await;
+    let results_uri = format!("/api/polls/{}/results", poll_id);
+    let versions_uri = format!("/api/polls/{}/results/versions", poll_id);
+
+    // Recounting with nothing changed doesn't add a version
+    get_json(&app, &token, &results_uri).await;
+    get_json(&app, &token, &results_uri).await;
+    let (_, versions) = get_json(&app, &token, &versions_uri).await;
+    assert_eq!(versions["data"].as_array().unwrap().len(), 1);
+
+    // Counting under a different tie-break seed does
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}", poll_id))
+        .header("authorization", format!("Bearer {}", token))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "tie_break_seed": 7 }).to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    get_json(&app, &token, &results_uri).await;
+
+    let (status, versions) = get_json(&app, &token, &versions_uri).await;
+    assert_eq!(status, StatusCode::OK);
+    let versions = versions["data"].as_array().unwrap();
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0]["version"], 1);
+    assert_ne!(versions[0]["settings"]["tie_break"], json!({ "Random": 7 }));
+    assert_eq!(versions[1]["version"], 2);
+    assert_eq!(versions[1]["settings"]["tie_break"], json!({ "Random": 7 }));
+    assert_eq!(versions[1]["total_votes"], 4);
+
+    let (status, version) = get_json(&app, &token, &format!("{}/2", versions_uri)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(version["data"]["settings"]["tie_break"], json!({ "Random": 7 }));
+    assert_eq!(version["data"]["results"]["algorithm"], "single_winner_irv_v2");
+
+    let (status, _) = get_json(&app, &token, &format!("{}/3", versions_uri)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Private results stay with the poll's owner
+    let other_token = setup_authenticated_user(&app).await;
+    for uri in [versions_uri.clone(), format!("{}/1", versions_uri)] {
+        let (status, result) = get_json(&app, &other_token, &uri).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(result["error"]["code"], "FORBIDDEN");
+    }
+}
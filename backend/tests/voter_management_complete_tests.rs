@@ -75,7 +75,7 @@ async fn test_complete_voter_management_workflow(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("GET")
-                .uri(&format!("/api/polls/{}/voters", poll_id))
+                .uri(format!("/api/polls/{}/voters", poll_id))
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
                 .unwrap(),
@@ -98,7 +98,7 @@ async fn test_complete_voter_management_workflow(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("POST")
-                .uri(&format!("/api/polls/{}/registration", poll_id))
+                .uri(format!("/api/polls/{}/registration", poll_id))
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
                 .unwrap(),
@@ -132,7 +132,7 @@ async fn test_complete_voter_management_workflow(pool: PgPool) {
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri(&format!("/api/polls/{}/invite", poll_id))
+                    .uri(format!("/api/polls/{}/invite", poll_id))
                     .header("content-type", "application/json")
                     .header("authorization", format!("Bearer {}", token))
                     .body(Body::from(voter_data.to_string()))
@@ -175,7 +175,7 @@ async fn test_complete_voter_management_workflow(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("GET")
-                .uri(&format!("/api/polls/{}/voters", poll_id))
+                .uri(format!("/api/polls/{}/voters", poll_id))
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
                 .unwrap(),
@@ -214,7 +214,7 @@ async fn test_complete_voter_management_workflow(pool: PgPool) {
             .oneshot(
                 Request::builder()
                     .method("GET")
-                    .uri(&format!("/api/vote/{}", token))
+                    .uri(format!("/api/vote/{}", token))
                     .body(Body::empty())
                     .unwrap(),
             )
@@ -243,7 +243,7 @@ async fn test_voter_management_error_cases(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("POST")
-                .uri(&format!("/api/polls/{}/invite", fake_poll_id))
+                .uri(format!("/api/polls/{}/invite", fake_poll_id))
                 .header("content-type", "application/json")
                 .body(Body::from(json!({"email": "test@example.com"}).to_string()))
                 .unwrap(),
@@ -259,7 +259,7 @@ async fn test_voter_management_error_cases(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("GET")
-                .uri(&format!("/api/polls/{}/voters", fake_poll_id))
+                .uri(format!("/api/polls/{}/voters", fake_poll_id))
                 .body(Body::empty())
                 .unwrap(),
         )
@@ -273,7 +273,7 @@ async fn test_voter_management_error_cases(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("POST")
-                .uri(&format!("/api/polls/{}/registration", fake_poll_id))
+                .uri(format!("/api/polls/{}/registration", fake_poll_id))
                 .body(Body::empty())
                 .unwrap(),
         )
@@ -348,7 +348,7 @@ async fn test_registration_link_functionality(pool: PgPool) {
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri(&format!("/api/polls/{}/registration", poll_id))
+                    .uri(format!("/api/polls/{}/registration", poll_id))
                     .header("authorization", format!("Bearer {}", token))
                     .body(Body::empty())
                     .unwrap(),
@@ -375,4 +375,67 @@ async fn test_registration_link_functionality(pool: PgPool) {
         
         println!("Registration link {}: {}", i + 1, url);
     }
-}
\ No newline at end of file
+}
+async fn send_json(app: &axum::Router, method: &str, uri: &str, token: Option<&str>, body: Option<Value>) -> Value {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {}", token));
+    }
+    let body = match body {
+        Some(body) => {
+            request = request.header("content-type", "application/json");
+            Body::from(body.to_string())
+        }
+        None => Body::empty(),
+    };
+
+    let response = app.clone().oneshot(request.body(body).unwrap()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[sqlx::test]
+async fn test_invitation_email_status_when_email_service_unavailable(pool: PgPool) {
+    // Without an API key the email service can't be reached at all
+    std::env::remove_var("EMAIL_SERVICE_API_KEY");
+    let app = create_test_app(pool.clone()).await;
+
+    let registered = send_json(&app, "POST", "/api/auth/register", None, Some(json!({
+        "email": "emailstatus@example.com",
+        "password": "testpassword123",
+        "name": "Email Status"
+    }))).await;
+    let token = registered["data"]["token"].as_str().unwrap();
+
+    let poll = send_json(&app, "POST", "/api/polls", Some(token), Some(json!({
+        "title": "Email Status Poll",
+        "pollType": "single_winner",
+        "numWinners": 1,
+        "candidates": [{"name": "Alice"}, {"name": "Bob"}]
+    }))).await;
+    let poll_id = poll["data"]["id"].as_str().unwrap();
+    let invite_uri = format!("/api/polls/{}/invite", poll_id);
+
+    let invited = send_json(&app, "POST", &invite_uri, Some(token), Some(json!({"email": "bounce@example.com"}))).await;
+    assert_eq!(invited["success"], true);
+    assert_eq!(invited["data"]["emailStatus"], "failed");
+
+    let anonymous = send_json(&app, "POST", &invite_uri, Some(token), Some(json!({}))).await;
+    assert_eq!(anonymous["data"]["emailStatus"], "skipped_anonymous");
+
+    // The outcome is stored, so the voter list shows which invitations never went out
+    let listed = send_json(&app, "GET", &format!("/api/polls/{}/voters", poll_id), Some(token), None).await;
+    let status_of = |id: &Value| {
+        listed["data"]["voters"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|voter| &voter["id"] == id)
+            .map(|voter| voter["emailStatus"].clone())
+            .unwrap()
+    };
+    assert_eq!(status_of(&invited["data"]["id"]), "failed");
+    assert_eq!(status_of(&anonymous["data"]["id"]), "skipped_anonymous");
+}
@@ -3,12 +3,127 @@ use axum::{
     http::{Request, StatusCode},
 };
 use serde_json::{json, Value};
+use rankedchoice_api::config::UrlConfig;
+use rankedchoice_api::models::ballot::{is_duplicate_voter_email, Voter};
+use rankedchoice_api::services::auth::AuthService;
 use sqlx::PgPool;
+use std::sync::{Arc, Mutex};
 use tower::ServiceExt;
 
 mod common;
 use common::*;
 
+// Register a poll owner and create a poll, returning (token, poll_id, candidate_ids)
+async fn setup_owner_with_poll(app: &axum::Router, email: &str) -> (String, String, Vec<String>) {
+    let user_data = json!({
+        "email": email,
+        "password": "testpassword123",
+        "name": "Poll Owner"
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/auth/register")
+                .header("content-type", "application/json")
+                .body(Body::from(user_data.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let register_data: Value = serde_json::from_slice(&body).unwrap();
+    let token = register_data["data"]["token"].as_str().unwrap().to_string();
+
+    let poll_data = json!({
+        "title": "Test Poll",
+        "pollType": "single_winner",
+        "numWinners": 1,
+        "candidates": [
+            {"name": "Candidate A"},
+            {"name": "Candidate B"}
+        ]
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/polls")
+                .header("content-type", "application/json")
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::from(poll_data.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let poll_result: Value = serde_json::from_slice(&body).unwrap();
+    let poll_id = poll_result["data"]["id"].as_str().unwrap().to_string();
+    let candidate_ids = poll_result["data"]["candidates"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["id"].as_str().unwrap().to_string())
+        .collect();
+
+    (token, poll_id, candidate_ids)
+}
+
+// Invite a voter by email and return the created voter record
+async fn invite_voter(app: &axum::Router, token: &str, poll_id: &str, email: &str) -> Value {
+    post_invite(app, token, poll_id, json!({ "email": email })).await
+}
+
+async fn invite_voter_with_locale(app: &axum::Router, token: &str, poll_id: &str, email: &str, locale: &str) -> Value {
+    post_invite(app, token, poll_id, json!({ "email": email, "locale": locale })).await
+}
+
+async fn post_invite(app: &axum::Router, token: &str, poll_id: &str, request: Value) -> Value {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/polls/{}/invite", poll_id))
+                .header("content-type", "application/json")
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::from(request.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    result["data"].clone()
+}
+
+// Submit a first-choice-only ballot for a voter token
+async fn cast_ballot(app: &axum::Router, ballot_token: &str, candidate_id: &str) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/vote/{}", ballot_token))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({ "rankings": [{"candidate_id": candidate_id, "rank": 1}] }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], true);
+}
+
 #[sqlx::test]
 async fn test_create_voter_with_email(pool: PgPool) {
     let app = create_test_app(pool.clone()).await;
@@ -76,7 +191,7 @@ async fn test_create_voter_with_email(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("POST")
-                .uri(&format!("/api/polls/{}/invite", poll_id))
+                .uri(format!("/api/polls/{}/invite", poll_id))
                 .header("content-type", "application/json")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::from(voter_request.to_string()))
@@ -106,6 +221,150 @@ async fn test_create_voter_with_email(pool: PgPool) {
     assert!(result["data"]["votedAt"].is_null());
 }
 
+#[sqlx::test]
+async fn test_duplicate_voter_email_rejected(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let (token, poll_id, _) = setup_owner_with_poll(&app, "unique-email@example.com").await;
+
+    let first = invite_voter(&app, &token, &poll_id, "dup@example.com").await;
+    assert!(first["id"].is_string());
+
+    // Same address with different case and padding
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/polls/{}/invite", poll_id))
+                .header("content-type", "application/json")
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::from(json!({ "email": "  DUP@Example.com " }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "ALREADY_REGISTERED");
+
+    // Anonymous voters are never treated as duplicates
+    assert!(post_invite(&app, &token, &poll_id, json!({})).await["id"].is_string());
+    assert!(post_invite(&app, &token, &poll_id, json!({})).await["id"].is_string());
+
+    let count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM voters WHERE poll_id = $1::uuid")
+        .bind(&poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count.0, 3);
+}
+
+#[sqlx::test]
+async fn test_database_rejects_duplicate_voter_email(pool: PgPool) {
+    // Invites that race past the API check still can't both land
+    let poll_id = create_test_poll(&pool).await;
+    Voter::create(&pool, &test_signer(&pool), poll_id, Some("race@example.com".to_string()), None, None)
+        .await
+        .unwrap();
+    let err = Voter::create(&pool, &test_signer(&pool), poll_id, Some(" Race@Example.com".to_string()), None, None)
+        .await
+        .unwrap_err();
+    assert!(is_duplicate_voter_email(&err));
+
+    let other_poll = create_test_poll(&pool).await;
+    Voter::create(&pool, &test_signer(&pool), other_poll, Some("race@example.com".to_string()), None, None)
+        .await
+        .unwrap();
+}
+
+#[sqlx::test]
+async fn test_candidate_statement_on_ballot(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let (token, poll_id, candidate_ids) = setup_owner_with_poll(&app, "statements@example.com").await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(format!("/api/candidates/{}", candidate_ids[0]))
+                .header("content-type", "application/json")
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::from(json!({
+                    "statement": "## Platform\n\nFix the **roads**.<script>alert('x')</script>"
+                }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let voter = invite_voter(&app, &token, &poll_id, "reader@example.com").await;
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/api/vote/{}", voter["ballotToken"].as_str().unwrap()))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    let candidates = result["data"]["poll"]["candidates"].as_array().unwrap();
+    // Voters only get the sanitized rendering of the markdown
+    assert_eq!(candidates[0]["statement_html"], "<h2>Platform</h2>\n<p>Fix the <strong>roads</strong>.</p>\n");
+    assert!(candidates[0].get("statement").is_none());
+    assert_eq!(candidates[1]["statement_html"], Value::Null);
+
+    // Statements are capped after sanitizing
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(format!("/api/candidates/{}", candidate_ids[1]))
+                .header("content-type", "application/json")
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::from(json!({ "statement": "a".repeat(10_001) }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[sqlx::test]
+async fn test_voter_import_template(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let (token, poll_id, _) = setup_owner_with_poll(&app, "template@example.com").await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/api/polls/{}/voters/import-template", poll_id))
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "text/csv; charset=utf-8");
+    assert!(response.headers()["content-disposition"].to_str().unwrap().starts_with("attachment;"));
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let csv = String::from_utf8(body.to_vec()).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("email,name"));
+    assert_eq!(lines.next().map(|row| row.split(',').count()), Some(2));
+}
+
 #[sqlx::test]
 async fn test_create_anonymous_voter(pool: PgPool) {
     let app = create_test_app(pool.clone()).await;
@@ -171,7 +430,7 @@ async fn test_create_anonymous_voter(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("POST")
-                .uri(&format!("/api/polls/{}/invite", poll_id))
+                .uri(format!("/api/polls/{}/invite", poll_id))
                 .header("content-type", "application/json")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::from(voter_request.to_string()))
@@ -255,7 +514,7 @@ async fn test_list_voters_empty(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("GET")
-                .uri(&format!("/api/polls/{}/voters", poll_id))
+                .uri(format!("/api/polls/{}/voters", poll_id))
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
                 .unwrap(),
@@ -346,7 +605,7 @@ async fn test_list_voters_with_data(pool: PgPool) {
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri(&format!("/api/polls/{}/invite", poll_id))
+                    .uri(format!("/api/polls/{}/invite", poll_id))
                     .header("content-type", "application/json")
                     .header("authorization", format!("Bearer {}", token))
                     .body(Body::from(voter_data.to_string()))
@@ -361,7 +620,7 @@ async fn test_list_voters_with_data(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("GET")
-                .uri(&format!("/api/polls/{}/voters", poll_id))
+                .uri(format!("/api/polls/{}/voters", poll_id))
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
                 .unwrap(),
@@ -407,7 +666,7 @@ async fn test_create_voter_unauthorized(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("POST")
-                .uri(&format!("/api/polls/{}/invite", poll_id))
+                .uri(format!("/api/polls/{}/invite", poll_id))
                 .header("content-type", "application/json")
                 .body(Body::from(voter_request.to_string()))
                 .unwrap(),
@@ -427,7 +686,7 @@ async fn test_list_voters_unauthorized(pool: PgPool) {
         .oneshot(
             Request::builder()
                 .method("GET")
-                .uri(&format!("/api/polls/{}/voters", poll_id))
+                .uri(format!("/api/polls/{}/voters", poll_id))
                 .body(Body::empty())
                 .unwrap(),
         )
@@ -435,4 +694,440 @@ async fn test_list_voters_unauthorized(pool: PgPool) {
         .unwrap();
     
     assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
-}
\ No newline at end of file
+}
+
+#[sqlx::test]
+async fn test_poll_stats(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let (token, poll_id, candidate_ids) = setup_owner_with_poll(&app, "stats@example.com").await;
+
+    let voter = invite_voter(&app, &token, &poll_id, "voted@example.com").await;
+    invite_voter(&app, &token, &poll_id, "pending@example.com").await;
+    cast_ballot(&app, voter["ballotToken"].as_str().unwrap(), &candidate_ids[0]).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/api/polls/{}/stats", poll_id))
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["invitedVoters"], 2);
+    assert_eq!(result["data"]["ballotsCast"], 1);
+    assert_eq!(result["data"]["anonymousBallots"], 0);
+    assert_eq!(result["data"]["turnoutPercentage"], 50.0);
+    assert!(result["data"]["firstBallotAt"].is_string());
+    assert_eq!(result["data"]["dailySubmissions"].as_array().unwrap().len(), 1);
+    assert_eq!(result["data"]["dailySubmissions"][0]["ballots"], 1);
+}
+
+#[sqlx::test]
+async fn test_poll_stats_submission_series(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let (token, poll_id, candidate_ids) = setup_owner_with_poll(&app, "series@example.com").await;
+
+    // Two ballots between 10:00 and 11:00, none the next hour, one after 12:00
+    let submitted_at = ["2026-03-02T10:15:00Z", "2026-03-02T10:45:00Z", "2026-03-02T12:05:00Z"];
+    for (i, at) in submitted_at.iter().enumerate() {
+        let voter = invite_voter(&app, &token, &poll_id, &format!("series{}@example.com", i)).await;
+        cast_ballot(&app, voter["ballotToken"].as_str().unwrap(), &candidate_ids[0]).await;
+        sqlx::query("UPDATE ballots SET submitted_at = $1::timestamptz WHERE voter_id = $2::uuid")
+            .bind(at)
+            .bind(voter["id"].as_str().unwrap())
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    let get_stats = |uri: String| {
+        let app = app.clone();
+        let token = token.clone();
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .method("GET")
+                        .uri(uri)
+                        .header("authorization", format!("Bearer {}", token))
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice::<Value>(&body).unwrap()["data"].clone()
+        }
+    };
+
+    // A poll without a schedule has only been open briefly, so buckets are hourly
+    let stats = get_stats(format!("/api/polls/{}/stats?split=true", poll_id)).await;
+    assert_eq!(stats["submissionInterval"], "hour");
+    let series = stats["submissionSeries"].as_array().unwrap();
+    let buckets: Vec<(&str, i64)> = series
+        .iter()
+        .map(|b| (b["bucketStart"].as_str().unwrap(), b["ballots"].as_i64().unwrap()))
+        .collect();
+    assert_eq!(
+        buckets,
+        [("2026-03-02T10:00:00+00:00", 2), ("2026-03-02T11:00:00+00:00", 0), ("2026-03-02T12:00:00+00:00", 1)]
+    );
+    assert_eq!(series[0]["registeredBallots"], 2);
+    assert_eq!(series[0]["anonymousBallots"], 0);
+
+    // A two-week voting window is bucketed by day, and the split is opt-in
+    sqlx::query("UPDATE polls SET opens_at = '2026-03-01T00:00:00Z', closes_at = '2026-03-15T00:00:00Z' WHERE id = $1::uuid")
+        .bind(&poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let stats = get_stats(format!("/api/polls/{}/stats", poll_id)).await;
+    assert_eq!(stats["submissionInterval"], "day");
+    assert_eq!(
+        stats["submissionSeries"],
+        json!([{"bucketStart": "2026-03-02T00:00:00+00:00", "ballots": 3}])
+    );
+}
+
+#[sqlx::test]
+async fn test_resend_invitation_rejected_for_voted_voter(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let (token, poll_id, candidate_ids) = setup_owner_with_poll(&app, "resend@example.com").await;
+
+    let voter = invite_voter(&app, &token, &poll_id, "voted@example.com").await;
+    cast_ballot(&app, voter["ballotToken"].as_str().unwrap(), &candidate_ids[0]).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/polls/{}/voters/{}/resend", poll_id, voter["id"].as_str().unwrap()))
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "ALREADY_VOTED");
+}
+
+// Tests that point EMAIL_SERVICE_URL at their own stub take turns, since the variable is process-wide
+static EMAIL_SERVICE_ENV: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+// Stand-in for the email microservice that records what `record` takes from each reminder and accepts everything else
+async fn spawn_recording_email_service<T: Send + 'static>(record: fn(&Value) -> T) -> (String, Arc<Mutex<Vec<T>>>) {
+    let reminded = Arc::new(Mutex::new(Vec::new()));
+    let recorded = reminded.clone();
+
+    let stub = axum::Router::new()
+        .route(
+            "/api/email/voter-reminder",
+            axum::routing::post(move |axum::Json(body): axum::Json<Value>| {
+                let recorded = recorded.clone();
+                async move {
+                    let to = body["to"].as_str().unwrap_or_default().to_string();
+                    recorded.lock().unwrap().push(record(&body));
+                    axum::Json(json!({"success": true, "data": {"messageId": "stub", "recipient": to}}))
+                }
+            }),
+        )
+        .fallback(|| async { axum::Json(json!({"success": true})) });
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, stub).await.unwrap();
+    });
+
+    (format!("http://{}", addr), reminded)
+}
+
+// Stand-in for the email microservice that records reminder recipients and accepts everything else
+async fn spawn_stub_email_service() -> (String, Arc<Mutex<Vec<String>>>) {
+    spawn_recording_email_service(|body| body["to"].as_str().unwrap_or_default().to_string()).await
+}
+
+#[sqlx::test]
+async fn test_remind_voters_excludes_voted_voters(pool: PgPool) {
+    let _email_env = EMAIL_SERVICE_ENV.lock().await;
+    let app = create_test_app(pool.clone()).await;
+
+    let (email_url, reminded) = spawn_stub_email_service().await;
+    std::env::set_var("EMAIL_SERVICE_URL", &email_url);
+    std::env::set_var("EMAIL_SERVICE_API_KEY", "test-api-key");
+
+    let (token, poll_id, candidate_ids) = setup_owner_with_poll(&app, "remind@example.com").await;
+
+    let voted = invite_voter(&app, &token, &poll_id, "voted@example.com").await;
+    invite_voter(&app, &token, &poll_id, "pending@example.com").await;
+    cast_ballot(&app, voted["ballotToken"].as_str().unwrap(), &candidate_ids[0]).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/polls/{}/voters/remind", poll_id))
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["sent"], 1);
+    assert_eq!(result["data"]["skipped"], 1);
+    assert_eq!(result["data"]["failed"], 0);
+    assert_eq!(*reminded.lock().unwrap(), vec!["pending@example.com"]);
+}
+
+#[sqlx::test]
+async fn test_remind_voters_sends_each_voter_locale(pool: PgPool) {
+    let _email_env = EMAIL_SERVICE_ENV.lock().await;
+    let app = create_test_app(pool.clone()).await;
+
+    let (email_url, reminded) = spawn_recording_email_service(|body| {
+        (body["to"].as_str().unwrap_or_default().to_string(), body["locale"].as_str().unwrap_or_default().to_string())
+    })
+    .await;
+    std::env::set_var("EMAIL_SERVICE_URL", &email_url);
+    std::env::set_var("EMAIL_SERVICE_API_KEY", "test-api-key");
+
+    let (token, poll_id, _) = setup_owner_with_poll(&app, "remind-locale@example.com").await;
+
+    invite_voter(&app, &token, &poll_id, "pending@example.com").await;
+    invite_voter_with_locale(&app, &token, &poll_id, "pendant@example.com", "fr").await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/polls/{}/voters/remind", poll_id))
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Each payload carries the voter's locale, falling back to the poll default
+    let mut reminded = reminded.lock().unwrap().clone();
+    reminded.sort();
+    assert_eq!(
+        reminded,
+        vec![
+            ("pendant@example.com".to_string(), "fr".to_string()),
+            ("pending@example.com".to_string(), "en".to_string()),
+        ]
+    );
+}
+
+async fn delete_voter(app: &axum::Router, token: &str, poll_id: &str, voter_id: &str, force: bool) -> Value {
+    let uri = if force {
+        format!("/api/polls/{}/voters/{}?force=true", poll_id, voter_id)
+    } else {
+        format!("/api/polls/{}/voters/{}", poll_id, voter_id)
+    };
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(uri)
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[sqlx::test]
+async fn test_delete_voter(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let (token, poll_id, _) = setup_owner_with_poll(&app, "delete@example.com").await;
+
+    let voter = invite_voter(&app, &token, &poll_id, "mistake@example.com").await;
+    let voter_id = voter["id"].as_str().unwrap();
+
+    let result = delete_voter(&app, &token, &poll_id, voter_id, false).await;
+    assert_eq!(result["success"], true);
+
+    let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM voters WHERE id = $1::uuid")
+        .bind(voter_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(remaining, 0);
+}
+
+#[sqlx::test]
+async fn test_delete_voted_voter_requires_force(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+    let (token, poll_id, candidate_ids) = setup_owner_with_poll(&app, "deletevoted@example.com").await;
+
+    let voter = invite_voter(&app, &token, &poll_id, "voted@example.com").await;
+    let voter_id = voter["id"].as_str().unwrap();
+    cast_ballot(&app, voter["ballotToken"].as_str().unwrap(), &candidate_ids[0]).await;
+
+    let result = delete_voter(&app, &token, &poll_id, voter_id, false).await;
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VOTER_HAS_VOTED");
+
+    let result = delete_voter(&app, &token, &poll_id, voter_id, true).await;
+    assert_eq!(result["success"], true);
+
+    let ballots: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ballots WHERE poll_id = $1::uuid")
+        .bind(&poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(ballots, 0);
+
+    let rankings: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM rankings")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(rankings, 0);
+}
+
+#[sqlx::test]
+async fn test_configured_base_urls_used_in_links(pool: PgPool) {
+    let auth_service = AuthService::new(pool).unwrap()
+        .with_urls(UrlConfig::new("https://polls.example.org", "https://vote.example.org/"));
+    let app = create_test_app_with_service(auth_service).await;
+    let (token, poll_id, candidate_ids) = setup_owner_with_poll(&app, "urls@example.com").await;
+
+    let voter = invite_voter(&app, &token, &poll_id, "linked@example.com").await;
+    assert_eq!(
+        voter["votingUrl"],
+        format!("https://vote.example.org/vote/{}", voter["ballotToken"].as_str().unwrap())
+    );
+
+    let ballot_token = voter["ballotToken"].as_str().unwrap();
+    cast_ballot(&app, ballot_token, &candidate_ids[0]).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/api/vote/{}/receipt", ballot_token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert!(result["data"]["verification_url"]
+        .as_str()
+        .unwrap()
+        .starts_with("https://vote.example.org/verify/VOTE-"));
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/api/polls/{}/registration", poll_id))
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert!(result["data"]["registrationUrl"]
+        .as_str()
+        .unwrap()
+        .starts_with("https://vote.example.org/register/"));
+}
+
+async fn search_voters(app: &axum::Router, token: &str, poll_id: &str, q: &str) -> Value {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("GET")
+                .uri(format!("/api/polls/{}/voters?q={}", poll_id, q))
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], true);
+    result["data"].clone()
+}
+
+#[sqlx::test]
+async fn test_list_voters_search_by_email(pool: PgPool) {
+    let app = create_test_app(pool).await;
+    let (token, poll_id, candidate_ids) = setup_owner_with_poll(&app, "search@example.com").await;
+
+    let alice = invite_voter(&app, &token, &poll_id, "alice@example.com").await;
+    invite_voter(&app, &token, &poll_id, "ALICIA@other.org").await;
+    invite_voter(&app, &token, &poll_id, "bob@example.com").await;
+    post_invite(&app, &token, &poll_id, json!({})).await;
+    cast_ballot(&app, alice["ballotToken"].as_str().unwrap(), &candidate_ids[0]).await;
+
+    let data = search_voters(&app, &token, &poll_id, "ALI").await;
+    let mut emails: Vec<&str> = data["voters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|voter| voter["email"].as_str().unwrap())
+        .collect();
+    emails.sort();
+    assert_eq!(emails, vec!["ALICIA@other.org", "alice@example.com"]);
+    assert_eq!(data["total"], 2);
+    assert_eq!(data["votedCount"], 1);
+    assert_eq!(data["pendingCount"], 1);
+
+    // Placeholder addresses for anonymous invites only match an "anon" search
+    let data = search_voters(&app, &token, &poll_id, "nymous").await;
+    assert_eq!(data["total"], 0);
+    assert_eq!(data["voters"].as_array().unwrap().len(), 0);
+
+    let data = search_voters(&app, &token, &poll_id, "anon").await;
+    assert_eq!(data["total"], 1);
+    assert!(data["voters"][0]["email"].as_str().unwrap().starts_with("Anonymous-"));
+}
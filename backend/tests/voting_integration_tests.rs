@@ -1,12 +1,15 @@
 use axum::{
     body::{Body, to_bytes},
+    extract::ConnectInfo,
     http::{Method, Request, StatusCode},
 };
 use serde_json::{json, Value};
 use sqlx::PgPool;
+use std::net::SocketAddr;
 use tower::ServiceExt;
 use uuid::Uuid;
 use rankedchoice_api::models::ballot::Voter;
+use rankedchoice_api::services::captcha::CaptchaVerifier;
 
 mod common;
 use common::*;
@@ -23,7 +26,7 @@ async fn test_get_ballot_invalid_token(pool: PgPool) {
 
     let response = app.oneshot(request).await.unwrap();
     
-    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
     
     let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
     let result: Value = serde_json::from_slice(&body).unwrap();
@@ -51,7 +54,7 @@ async fn test_submit_ballot_invalid_token(pool: PgPool) {
 
     let response = app.oneshot(request).await.unwrap();
     
-    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
     
     let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
     let result: Value = serde_json::from_slice(&body).unwrap();
@@ -72,7 +75,7 @@ async fn test_voting_receipt_invalid_token(pool: PgPool) {
 
     let response = app.oneshot(request).await.unwrap();
     
-    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
     
     let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
     let result: Value = serde_json::from_slice(&body).unwrap();
@@ -93,6 +96,7 @@ async fn test_voting_workflow_with_valid_voter(pool: PgPool) {
     // Create a voter for the poll
     let voter = Voter::create(
         &pool, 
+        &test_signer(&pool),
         poll_id, 
         Some("voter@example.com".to_string()), 
         None, 
@@ -140,4 +144,1374 @@ async fn test_voting_workflow_with_valid_voter(pool: PgPool) {
     assert_eq!(result["success"], true);
     assert!(result["data"]["ballot"]["id"].is_string());
     assert!(result["data"]["receipt"]["receipt_code"].is_string());
-} 
\ No newline at end of file
+}
+
+async fn submit_rankings(app: &axum::Router, token: &str, rankings: Value) -> Value {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/vote/{}", token))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "rankings": rankings }).to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[sqlx::test]
+async fn test_full_ranking_required_rejects_partial_ballot(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET require_full_ranking = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("partial@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 2}
+    ])).await;
+
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert!(result["error"]["message"].as_str().unwrap().contains("1 missing"));
+}
+
+#[sqlx::test]
+async fn test_full_ranking_required_accepts_complete_ballot(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET require_full_ranking = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("complete@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 2},
+        {"candidate_id": candidate_ids[2], "rank": 3}
+    ])).await;
+
+    assert_eq!(result["success"], true);
+    assert!(result["data"]["ballot"]["id"].is_string());
+}
+
+#[sqlx::test]
+async fn test_min_rankings_rejects_short_ballots(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET min_rankings = 2, is_public = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("short@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let rankings = json!([{"candidate_id": candidate_ids[0], "rank": 1}]);
+    let result = submit_rankings(&app, &voter.ballot_token, rankings.clone()).await;
+
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert!(result["error"]["message"].as_str().unwrap().contains("at least 2 candidates"));
+
+    // Anonymous public ballots are held to the same minimum
+    let mut request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/public/polls/{}/vote", poll_id))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "rankings": rankings }).to_string()))
+        .unwrap();
+    request.extensions_mut().insert(ConnectInfo("203.0.113.9:4000".parse::<SocketAddr>().unwrap()));
+
+    let response = app.oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert!(result["error"]["message"].as_str().unwrap().contains("at least 2 candidates"));
+}
+
+#[sqlx::test]
+async fn test_min_rankings_accepts_exact_minimum(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET min_rankings = 2 WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("exact@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 2}
+    ])).await;
+
+    assert_eq!(result["success"], true);
+    assert!(result["data"]["ballot"]["id"].is_string());
+}
+
+#[sqlx::test]
+async fn test_poll_without_candidates_is_not_ready(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("early@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/vote/{}", voter.ballot_token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "POLL_NOT_READY");
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/vote/{}", voter.ballot_token))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "rankings": [] }).to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "POLL_NOT_READY");
+}
+
+#[sqlx::test]
+async fn test_approval_ballots_ignore_rank_order(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET poll_type = 'approval' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Overlapping approval sets; every approved candidate is sent with rank 1
+    let approval_sets = [
+        vec![candidate_ids[0], candidate_ids[1]],
+        vec![candidate_ids[1], candidate_ids[2]],
+        vec![candidate_ids[1]],
+    ];
+
+    for (i, approved) in approval_sets.iter().enumerate() {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(format!("approver{}@example.com", i)), None, None)
+            .await
+            .expect("Failed to create voter");
+
+        let rankings: Vec<Value> = approved.iter()
+            .map(|id| json!({"candidate_id": id, "rank": 1}))
+            .collect();
+        let result = submit_rankings(&app, &voter.ballot_token, json!(rankings)).await;
+        assert_eq!(result["success"], true);
+    }
+
+    let register = Request::builder()
+        .method(Method::POST)
+        .uri("/api/auth/register")
+        .header("content-type", "application/json")
+        .body(Body::from(json!({
+            "email": "approval-owner@example.com",
+            "password": "testpassword123",
+            "name": "Approval Owner"
+        }).to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(register).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let registered: Value = serde_json::from_slice(&body).unwrap();
+    let token = registered["data"]["token"].as_str().unwrap().to_string();
+
+    sqlx::query("UPDATE polls SET user_id = (SELECT id FROM users WHERE email = 'approval-owner@example.com') WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/polls/{}/results", poll_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["total_votes"], 3);
+    assert_eq!(result["data"]["winner"]["candidate_id"], candidate_ids[1].to_string());
+    assert_eq!(result["data"]["winner"]["final_votes"], 3.0);
+    assert_eq!(result["data"]["final_rankings"].as_array().unwrap().len(), 3);
+}
+
+#[sqlx::test]
+async fn test_verify_receipt_code(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("receipt@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1}
+    ])).await;
+    let receipt_code = result["data"]["receipt"]["receipt_code"].as_str().unwrap().to_string();
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/verify/{}", receipt_code))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["receipt_code"], receipt_code);
+    assert_eq!(result["data"]["counted"], true);
+    assert_eq!(result["data"]["poll_title"], "Test Poll");
+    assert!(result["data"]["submitted_at"].is_string());
+    assert!(result["data"].get("rankings").is_none());
+    assert!(result["data"].get("voter_id").is_none());
+}
+
+#[sqlx::test]
+async fn test_custom_receipt_prefix(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET receipt_prefix = 'ACME' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("branded@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1}
+    ])).await;
+    let receipt_code = result["data"]["receipt"]["receipt_code"].as_str().unwrap().to_string();
+    let ballot_id = result["data"]["ballot"]["id"].as_str().unwrap();
+    assert!(receipt_code.starts_with("ACME-"));
+    assert!(receipt_code.ends_with(ballot_id.split('-').next().unwrap()));
+
+    // Looking the receipt up later rebuilds the same code
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/vote/{}/receipt", voter.ballot_token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["receipt_code"], receipt_code);
+
+    // And the branded code still verifies
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/verify/{}", receipt_code))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["counted"], true);
+}
+
+#[sqlx::test]
+async fn test_receipt_keeps_prefix_after_poll_clears_it(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET receipt_prefix = 'ACME' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("early@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1}
+    ])).await;
+    let receipt_code = result["data"]["receipt"]["receipt_code"].as_str().unwrap().to_string();
+    assert!(receipt_code.starts_with("ACME-"));
+
+    // An explicit null clears the prefix
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/polls/{}", poll_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", test_user_token(&pool).await))
+        .body(Body::from(json!({ "receipt_prefix": null }).to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert!(result["data"]["receipt_prefix"].is_null());
+
+    // The receipt already issued is rebuilt and verified under its original prefix
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/vote/{}/receipt", voter.ballot_token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["receipt_code"], receipt_code);
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/verify/{}", receipt_code))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["data"]["counted"], true, "{}", result);
+
+    // New ballots get the built-in prefix
+    let late_voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("late@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    let result = submit_rankings(&app, &late_voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[1], "rank": 1}
+    ])).await;
+    assert!(result["data"]["receipt"]["receipt_code"].as_str().unwrap().starts_with("VOTE-"));
+}
+
+#[sqlx::test]
+async fn test_verify_unknown_receipt_code(pool: PgPool) {
+    let app = create_test_app(pool).await;
+
+    for receipt_code in ["VOTE-2025-00000000", "not-a-receipt"] {
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(format!("/api/verify/{}", receipt_code))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(result["success"], false);
+        assert_eq!(result["error"]["code"], "NOT_FOUND");
+    }
+}
+
+#[sqlx::test]
+async fn test_anonymous_vote_rate_limited_by_ip(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET is_public = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let ballot_data = json!({
+        "rankings": [
+            {"candidate_id": candidate_ids[0], "rank": 1}
+        ]
+    });
+    let client_addr: SocketAddr = "203.0.113.7:4000".parse().unwrap();
+
+    let vote_from = |addr: SocketAddr| {
+        let mut request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/api/public/polls/{}/vote", poll_id))
+            .header("content-type", "application/json")
+            .body(Body::from(ballot_data.to_string()))
+            .unwrap();
+        request.extensions_mut().insert(ConnectInfo(addr));
+
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice::<Value>(&body).unwrap()
+        }
+    };
+
+    let mut results = Vec::new();
+    for _ in 0..10 {
+        results.push(vote_from(client_addr).await);
+    }
+    assert!(results.iter().all(|result| result["success"] == true));
+
+    // The limit doesn't depend on the stored address, which IP_STORAGE_MODE may drop
+    sqlx::query("UPDATE ballots SET ip_address = NULL WHERE poll_id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let result = vote_from(client_addr).await;
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "RATE_LIMITED");
+
+    // Other addresses are unaffected
+    let result = vote_from("203.0.113.8:4000".parse().unwrap()).await;
+    assert_eq!(result["success"], true, "{}", result);
+}
+
+#[sqlx::test]
+async fn test_ballot_draft_round_trip(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("draft@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    // Save a partial draft
+    let draft_data = json!({
+        "rankings": [
+            {"candidate_id": candidate_ids[1], "rank": 1}
+        ]
+    });
+
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/vote/{}/draft", voter.ballot_token))
+        .header("content-type", "application/json")
+        .body(Body::from(draft_data.to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], true);
+
+    // The draft comes back with the ballot and the voter is still unvoted
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/vote/{}", voter.ballot_token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["voter"]["has_voted"], false);
+    assert_eq!(result["data"]["draft"][0]["candidate_id"], candidate_ids[1].to_string());
+    assert_eq!(result["data"]["draft"][0]["rank"], 1);
+
+    // Submitting the ballot clears the draft
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[1], "rank": 1},
+        {"candidate_id": candidate_ids[0], "rank": 2}
+    ])).await;
+    assert_eq!(result["success"], true);
+
+    let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ballot_drafts WHERE voter_id = $1")
+        .bind(voter.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(remaining, 0);
+}
+
+#[sqlx::test]
+async fn test_ballot_draft_refused_after_close(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("latedraft@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    sqlx::query("UPDATE polls SET closes_at = NOW() - INTERVAL '1 hour' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let draft_data = json!({
+        "rankings": [
+            {"candidate_id": candidate_ids[0], "rank": 1}
+        ]
+    });
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/api/vote/{}/draft", voter.ballot_token))
+        .header("content-type", "application/json")
+        .body(Body::from(draft_data.to_string()))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "POLL_CLOSED");
+
+    let drafts: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ballot_drafts WHERE voter_id = $1")
+        .bind(voter.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(drafts, 0);
+}
+
+async fn get_my_ballot(app: &axum::Router, token: &str) -> Value {
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/vote/{}/ballot", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[sqlx::test]
+async fn test_my_ballot_round_trip(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("myballot@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let result = get_my_ballot(&app, &voter.ballot_token).await;
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "NOT_VOTED");
+
+    let submitted = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[2], "rank": 1},
+        {"candidate_id": candidate_ids[0], "rank": 2}
+    ])).await;
+    assert_eq!(submitted["success"], true);
+
+    let result = get_my_ballot(&app, &voter.ballot_token).await;
+    assert_eq!(result["success"], true);
+    assert_eq!(result["data"]["poll_id"], poll_id.to_string());
+    assert_eq!(result["data"]["ballot_id"], submitted["data"]["ballot"]["id"]);
+    assert_eq!(
+        result["data"]["rankings"],
+        json!([
+            {"rank": 1, "candidate_id": candidate_ids[2], "name": "Candidate C"},
+            {"rank": 2, "candidate_id": candidate_ids[0], "name": "Candidate A"}
+        ])
+    );
+}
+
+#[sqlx::test]
+async fn test_submitted_ballot_records_client_ip(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("ip@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    // Serve over a real socket, the same way main.rs does, so ConnectInfo is populated
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+            .await
+            .unwrap();
+    });
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/api/vote/{}", addr, voter.ballot_token))
+        .json(&json!({
+            "rankings": [
+                {"candidate_id": candidate_ids[0], "rank": 1}
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let result: Value = response.json().await.unwrap();
+    assert_eq!(result["success"], true);
+
+    let ip_address: Option<String> = sqlx::query_scalar("SELECT host(ip_address) FROM ballots WHERE voter_id = $1")
+        .bind(voter.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(ip_address.as_deref(), Some("127.0.0.1"));
+}
+
+#[sqlx::test]
+async fn test_identical_write_ins_share_a_candidate(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET allow_write_ins = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    for (email, write_in) in [("first@example.com", "Jane Doe"), ("second@example.com", "  jane   DOE ")] {
+        let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some(email.to_string()), None, None)
+            .await
+            .expect("Failed to create voter");
+
+        let result = submit_rankings(&app, &voter.ballot_token, json!([
+            {"write_in_name": write_in, "rank": 1},
+            {"candidate_id": candidate_ids[0], "rank": 2}
+        ])).await;
+        assert_eq!(result["success"], true, "{}", result);
+    }
+
+    let write_ins: Vec<(Uuid, String)> = sqlx::query_as(
+        "SELECT id, name FROM candidates WHERE poll_id = $1 AND is_write_in"
+    )
+    .bind(poll_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(write_ins.len(), 1);
+    assert_eq!(write_ins[0].1, "Jane Doe");
+
+    let first_choices: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM rankings WHERE candidate_id = $1 AND rank = 1")
+        .bind(write_ins[0].0)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(first_choices, 2);
+}
+
+#[sqlx::test]
+async fn test_write_ins_rejected_when_not_allowed(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    create_test_candidates(&pool, poll_id).await;
+
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("writein@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"write_in_name": "Jane Doe", "rank": 1}
+    ])).await;
+
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+}
+
+#[sqlx::test]
+async fn test_write_in_checks(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET allow_write_ins = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("checks@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    // The same name written in twice, differing only in case
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"write_in_name": "Bob", "rank": 1},
+        {"write_in_name": "bob", "rank": 2}
+    ])).await;
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"write_in_name": "x".repeat(201), "rank": 1}
+    ])).await;
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+
+    // Control characters are dropped before the length is checked
+    let result = validate_rankings(&app, &voter.ballot_token, json!([
+        {"write_in_name": format!("{}\u{7}", "x".repeat(200)), "rank": 1}
+    ])).await;
+    assert_eq!(result["accepted"], true, "{}", result);
+
+    // A poll at the candidate maximum takes no new write-ins
+    sqlx::query(
+        "INSERT INTO candidates (poll_id, name, slug, display_order) \
+         SELECT $1, 'Filler ' || n, 'filler-' || n, 100 + n FROM generate_series(1, 97) AS n"
+    )
+    .bind(poll_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"write_in_name": "Jane Doe", "rank": 1}
+    ])).await;
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+
+    // None of the rejected ballots left a write-in behind, and the voter can still vote
+    let write_ins: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM candidates WHERE poll_id = $1 AND is_write_in")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(write_ins, 0);
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1}
+    ])).await;
+    assert_eq!(result["success"], true, "{}", result);
+}
+
+// Dry-run a ballot and return its `ballot_validation` report
+async fn validate_rankings(app: &axum::Router, token: &str, rankings: Value) -> Value {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/vote/{}/validate", token))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "rankings": rankings }).to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], true);
+    result["data"]["ballot_validation"].clone()
+}
+
+async fn create_validation_voter(pool: &PgPool, poll_id: Uuid) -> Voter {
+    Voter::create(pool, &test_signer(pool), poll_id, Some("validate@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter")
+}
+
+#[sqlx::test]
+async fn test_validate_ballot_accepts_valid_ballot_without_recording(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = create_validation_voter(&pool, poll_id).await;
+
+    let report = validate_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 2}
+    ])).await;
+
+    assert_eq!(report["accepted"], true);
+    assert_eq!(report["issues"], json!([]));
+
+    let ballots: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ballots WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(ballots, 0);
+
+    // Validating doesn't use up the voter's ballot
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1}
+    ])).await;
+    assert_eq!(result["success"], true);
+}
+
+#[sqlx::test]
+async fn test_validate_ballot_reports_overvote(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = create_validation_voter(&pool, poll_id).await;
+
+    let report = validate_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 1},
+        {"candidate_id": candidate_ids[2], "rank": 2}
+    ])).await;
+
+    assert_eq!(report["accepted"], false);
+    assert_eq!(report["issues"].as_array().unwrap().len(), 1);
+    assert_eq!(report["issues"][0]["kind"], "overvote");
+    assert_eq!(report["issues"][0]["rank"], 1);
+    assert_eq!(
+        report["issues"][0]["candidate_ids"],
+        json!([candidate_ids[0], candidate_ids[1]])
+    );
+}
+
+#[sqlx::test]
+async fn test_validate_ballot_reports_undervote(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = create_validation_voter(&pool, poll_id).await;
+
+    let report = validate_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 3}
+    ])).await;
+
+    assert_eq!(report["accepted"], false);
+    assert_eq!(report["issues"].as_array().unwrap().len(), 1);
+    assert_eq!(report["issues"][0]["kind"], "undervote");
+    assert_eq!(report["issues"][0]["rank"], 2);
+}
+
+#[sqlx::test]
+async fn test_validate_ballot_reports_invalid_rank_and_entry(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = create_validation_voter(&pool, poll_id).await;
+
+    let report = validate_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 0},
+        {"candidate_id": candidate_ids[1], "rank": 1}
+    ])).await;
+    assert_eq!(report["accepted"], false);
+    assert_eq!(report["issues"][0]["kind"], "invalid_rank");
+    assert_eq!(report["issues"][0]["rank"], 0);
+
+    let report = validate_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": Uuid::new_v4(), "rank": 1}
+    ])).await;
+    assert_eq!(report["accepted"], false);
+    assert_eq!(report["issues"][0]["kind"], "invalid_entry");
+
+    let report = validate_rankings(&app, &voter.ballot_token, json!([])).await;
+    assert_eq!(report["accepted"], false);
+    assert_eq!(report["issues"][0]["kind"], "empty_ballot");
+}
+
+#[sqlx::test]
+async fn test_validate_ballot_reports_incomplete_ranking(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET require_full_ranking = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let voter = create_validation_voter(&pool, poll_id).await;
+
+    let report = validate_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 2}
+    ])).await;
+
+    assert_eq!(report["accepted"], false);
+    assert_eq!(report["issues"][0]["kind"], "incomplete_ranking");
+    assert!(report["issues"][0]["message"].as_str().unwrap().contains("1 missing"));
+}
+
+#[sqlx::test]
+async fn test_validate_ballot_reports_duplicate_approval(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET poll_type = 'approval' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let voter = create_validation_voter(&pool, poll_id).await;
+
+    let report = validate_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[0], "rank": 2}
+    ])).await;
+
+    assert_eq!(report["accepted"], false);
+    assert_eq!(report["issues"][0]["kind"], "duplicate_candidate");
+    assert_eq!(report["issues"][0]["candidate_ids"], json!([candidate_ids[0]]));
+}
+
+#[sqlx::test]
+async fn test_ranked_ballot_rejects_duplicate_candidate(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = create_validation_voter(&pool, poll_id).await;
+    let rankings = json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[0], "rank": 2}
+    ]);
+
+    let report = validate_rankings(&app, &voter.ballot_token, rankings.clone()).await;
+    assert_eq!(report["accepted"], false);
+    assert_eq!(report["issues"][0]["kind"], "duplicate_candidate");
+    assert_eq!(report["issues"][0]["candidate_ids"], json!([candidate_ids[0]]));
+
+    let result = submit_rankings(&app, &voter.ballot_token, rankings).await;
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+}
+
+async fn stored_ranks(pool: &PgPool, poll_id: Uuid) -> Vec<i32> {
+    sqlx::query_scalar(
+        "SELECT r.rank FROM rankings r JOIN ballots b ON b.id = r.ballot_id WHERE b.poll_id = $1 ORDER BY r.rank",
+    )
+    .bind(poll_id)
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+#[sqlx::test]
+async fn test_skipped_ranks_rejected_by_default(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("gap@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 2},
+        {"candidate_id": candidate_ids[2], "rank": 4}
+    ])).await;
+
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert!(stored_ranks(&pool, poll_id).await.is_empty());
+}
+
+#[sqlx::test]
+async fn test_skipped_ranks_collapsed_when_configured(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET skipped_rankings_policy = 'collapse', is_public = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("gap@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    // A dry run no longer flags the gap
+    let report = validate_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 2},
+        {"candidate_id": candidate_ids[1], "rank": 5}
+    ])).await;
+    assert_eq!(report["accepted"], true);
+
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 2},
+        {"candidate_id": candidate_ids[2], "rank": 4}
+    ])).await;
+    assert_eq!(result["success"], true);
+    assert_eq!(stored_ranks(&pool, poll_id).await, vec![1, 2, 3]);
+
+    // Anonymous ballots are collapsed the same way
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/public/polls/{}/vote", poll_id))
+        .header("content-type", "application/json")
+        .body(Body::from(json!({
+            "rankings": [
+                {"candidate_id": candidate_ids[2], "rank": 3},
+                {"candidate_id": candidate_ids[0], "rank": 7}
+            ]
+        }).to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], true);
+
+    let anonymous_ranks: Vec<(Uuid, i32)> = sqlx::query_as(
+        "SELECT r.candidate_id, r.rank FROM rankings r JOIN ballots b ON b.id = r.ballot_id
+         WHERE b.poll_id = $1 AND b.voter_id IS NULL ORDER BY r.rank",
+    )
+    .bind(poll_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(anonymous_ranks, vec![(candidate_ids[2], 1), (candidate_ids[0], 2)]);
+
+    // Overvotes are still rejected under the collapse policy
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("overvote@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 1}
+    ])).await;
+    assert_eq!(result["success"], false);
+}
+
+#[sqlx::test]
+async fn test_rank_beyond_candidate_count_rejected(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("range@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let out_of_range = json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 999}
+    ]);
+    let report = validate_rankings(&app, &voter.ballot_token, out_of_range.clone()).await;
+    assert_eq!(report["accepted"], false);
+    assert_eq!(report["issues"][0]["kind"], "invalid_rank");
+    assert_eq!(report["issues"][0]["rank"], 999);
+
+    let result = submit_rankings(&app, &voter.ballot_token, out_of_range).await;
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert!(result["error"]["message"].as_str().unwrap().contains("between 1 and 3"));
+
+    // Collapsing gaps can't sneak a fourth rank onto a three-candidate poll
+    sqlx::query("UPDATE polls SET skipped_rankings_policy = 'collapse' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let result = submit_rankings(&app, &voter.ballot_token, json!([
+        {"candidate_id": candidate_ids[0], "rank": 1},
+        {"candidate_id": candidate_ids[1], "rank": 2},
+        {"candidate_id": candidate_ids[2], "rank": 3},
+        {"candidate_id": candidate_ids[0], "rank": 9}
+    ])).await;
+    assert_eq!(result["success"], false);
+    assert_eq!(result["error"]["code"], "VALIDATION_ERROR");
+    assert!(stored_ranks(&pool, poll_id).await.is_empty());
+}
+
+#[sqlx::test]
+async fn test_voting_errors_use_http_status_codes(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("status@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let ballot_data = json!({
+        "rankings": [
+            {"candidate_id": candidate_ids[0], "rank": 1}
+        ]
+    });
+    let submit = |app: axum::Router| {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/api/vote/{}", voter.ballot_token))
+            .header("content-type", "application/json")
+            .body(Body::from(ballot_data.to_string()))
+            .unwrap();
+        app.oneshot(request)
+    };
+
+    // Voting again conflicts with the ballot already cast
+    let response = submit(app.clone()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = submit(app.clone()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "ALREADY_VOTED");
+
+    // A closed poll refuses ballots outright
+    sqlx::query("UPDATE polls SET closes_at = NOW() - INTERVAL '1 hour' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let late_voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("late@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/api/vote/{}", late_voter.ballot_token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "POLL_CLOSED");
+}
+
+#[sqlx::test]
+async fn test_finalized_poll_refuses_ballots_before_close_time(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("closed@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    // Finalized, but with a close time still ahead
+    sqlx::query("UPDATE polls SET status = 'closed', closes_at = NOW() + INTERVAL '1 day' WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let ballot_data = json!({
+        "rankings": [
+            {"candidate_id": candidate_ids[0], "rank": 1}
+        ]
+    });
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/api/vote/{}", voter.ballot_token))
+        .header("content-type", "application/json")
+        .body(Body::from(ballot_data.to_string()))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "POLL_CLOSED");
+}
+
+#[sqlx::test]
+async fn test_concurrent_submissions_record_one_ballot(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    let voter = Voter::create(&pool, &test_signer(&pool), poll_id, Some("race@example.com".to_string()), None, None)
+        .await
+        .expect("Failed to create voter");
+
+    let submit = |candidate_id: Uuid| {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/api/vote/{}", voter.ballot_token))
+            .header("content-type", "application/json")
+            .body(Body::from(json!({"rankings": [{"candidate_id": candidate_id, "rank": 1}]}).to_string()))
+            .unwrap();
+        app.clone().oneshot(request)
+    };
+
+    // Both requests may pass the has-voted check; the database admits only one ballot
+    let (first, second) = tokio::join!(submit(candidate_ids[0]), submit(candidate_ids[1]));
+    let mut statuses = [first.unwrap().status(), second.unwrap().status()];
+    statuses.sort();
+    assert_eq!(statuses, [StatusCode::OK, StatusCode::CONFLICT]);
+
+    let ballots: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ballots WHERE voter_id = $1")
+        .bind(voter.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(ballots, 1);
+}
+
+#[sqlx::test]
+async fn test_anonymous_vote_cookie_blocks_repeat_votes(pool: PgPool) {
+    let app = create_test_app(pool.clone()).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET is_public = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let ballot_data = json!({
+        "rankings": [
+            {"candidate_id": candidate_ids[0], "rank": 1}
+        ]
+    });
+    let vote = |cookie: Option<String>| {
+        let mut request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/api/public/polls/{}/vote", poll_id))
+            .header("content-type", "application/json");
+        if let Some(cookie) = cookie {
+            request = request.header("cookie", cookie);
+        }
+        app.clone().oneshot(request.body(Body::from(ballot_data.to_string())).unwrap())
+    };
+
+    let response = vote(None).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let set_cookie = response.headers()["set-cookie"].to_str().unwrap().to_string();
+    assert!(set_cookie.contains("HttpOnly"));
+    let cookie = set_cookie.split(';').next().unwrap().to_string();
+
+    // The same browser is turned away
+    let response = vote(Some(format!("theme=dark; {}", cookie))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["error"]["code"], "ALREADY_VOTED");
+
+    // A cookie that wasn't signed by the server doesn't count
+    let (name, _) = cookie.split_once('=').unwrap();
+    let response = vote(Some(format!("{}={}", name, "00".repeat(32)))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let ballots: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ballots WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(ballots, 2);
+}
+
+// Stand-in for a CAPTCHA provider's siteverify endpoint that only accepts `good-token`
+async fn spawn_stub_captcha_provider() -> String {
+    let stub = axum::Router::new().route(
+        "/siteverify",
+        axum::routing::post(|axum::Form(form): axum::Form<std::collections::HashMap<String, String>>| async move {
+            let success = form.get("secret").map(String::as_str) == Some("test-secret")
+                && form.get("response").map(String::as_str) == Some("good-token");
+            axum::Json(json!({ "success": success }))
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, stub).await.unwrap();
+    });
+
+    format!("http://{}/siteverify", addr)
+}
+
+#[sqlx::test]
+async fn test_anonymous_vote_requires_captcha_when_enabled(pool: PgPool) {
+    let verifier = CaptchaVerifier::new(spawn_stub_captcha_provider().await, "test-secret");
+    let app = create_test_app_with_service(test_auth_service(&pool).with_captcha_verifier(verifier)).await;
+
+    let poll_id = create_test_poll(&pool).await;
+    let candidate_ids = create_test_candidates(&pool, poll_id).await;
+    sqlx::query("UPDATE polls SET is_public = true, require_captcha = true WHERE id = $1")
+        .bind(poll_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let vote = |captcha_token: Option<&str>| {
+        let ballot_data = json!({
+            "rankings": [{"candidate_id": candidate_ids[0], "rank": 1}],
+            "captcha_token": captcha_token
+        });
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("/api/public/polls/{}/vote", poll_id))
+            .header("content-type", "application/json")
+            .body(Body::from(ballot_data.to_string()))
+            .unwrap();
+        app.clone().oneshot(request)
+    };
+
+    for captcha_token in [None, Some("bad-token")] {
+        let response = vote(captcha_token).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let result: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["error"]["code"], "CAPTCHA_FAILED");
+    }
+
+    let response = vote(Some("good-token")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let result: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(result["success"], true);
+
+    let ballots: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ballots WHERE poll_id = $1")
+        .bind(poll_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(ballots, 1);
+}
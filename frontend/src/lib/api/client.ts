@@ -159,6 +159,7 @@ class APIClient {
 		status?: string;
 		sort?: string;
 		order?: string;
+		tag?: string;
 	}): Promise<{ polls: Poll[]; total: number; totalPages: number }> {
 		const searchParams = new URLSearchParams();
 		if (params) {
@@ -190,13 +191,44 @@ class APIClient {
 		return this.mapPollFromApi(response.data!);
 	}
 
+	async getPublicPolls(params?: {
+		page?: number;
+		limit?: number;
+		order?: 'asc' | 'desc';
+	}): Promise<{ polls: Poll[]; total: number; totalPages: number }> {
+		const searchParams = new URLSearchParams();
+		if (params) {
+			Object.entries(params).forEach(([key, value]) => {
+				if (value !== undefined) {
+					searchParams.append(key, value.toString());
+				}
+			});
+		}
+
+		const endpoint = `/public/polls${searchParams.toString() ? `?${searchParams.toString()}` : ''}`;
+		const response = await this.request<{ items: any[]; total: number; total_pages: number }>(endpoint);
+
+		return {
+			polls: response.data!.items.map((poll: any) => ({
+				...this.mapPollFromApi(poll),
+				candidateCount: poll.candidate_count
+			})),
+			total: response.data!.total,
+			totalPages: response.data!.total_pages
+		};
+	}
+
 	async getPublicPoll(id: string): Promise<Poll> {
 		const response = await this.request<any>(`/public/polls/${id}`);
 		
 		return this.mapPollFromApi(response.data!);
 	}
 
-	async submitAnonymousVote(pollId: string, rankings: Array<{ candidateId: string; rank: number }>): Promise<{
+	async submitAnonymousVote(
+		pollId: string,
+		rankings: Array<{ candidateId: string; rank: number }>,
+		captchaToken?: string
+	): Promise<{
 		ballot: {
 			id: string;
 			submitted_at: string;
@@ -210,7 +242,8 @@ class APIClient {
 			rankings: rankings.map(r => ({
 				candidate_id: r.candidateId,
 				rank: r.rank
-			}))
+			})),
+			captcha_token: captchaToken
 		};
 
 		const response = await this.request<{
@@ -243,8 +276,13 @@ class APIClient {
 			numWinners: poll.num_winners,
 			opensAt: poll.opens_at,
 			closesAt: poll.closes_at,
+			timezone: poll.timezone,
+			opensAtLocal: poll.opens_at_local,
+			closesAtLocal: poll.closes_at_local,
 			isPublic: poll.is_public,
 			registrationRequired: poll.registration_required,
+			requireCaptcha: poll.require_captcha,
+			tags: poll.tags,
 			createdAt: poll.created_at,
 			updatedAt: poll.updated_at,
 			candidates: poll.candidates?.map((candidate: any) => ({
@@ -252,6 +290,9 @@ class APIClient {
 				pollId: candidate.poll_id,
 				name: candidate.name,
 				description: candidate.description,
+				statement: candidate.statement,
+				statementHtml: candidate.statement_html,
+				affiliation: candidate.affiliation,
 				displayOrder: candidate.display_order,
 				createdAt: candidate.created_at
 			}))
@@ -307,6 +348,11 @@ class APIClient {
 		return response.data!;
 	}
 
+	async getCandidate(id: string): Promise<Candidate> {
+		const response = await this.request<Candidate>(`/candidates/${id}`);
+		return response.data!;
+	}
+
 	async addCandidate(pollId: string, candidateData: { name: string; description?: string }): Promise<Candidate> {
 		const response = await this.request<Candidate>(`/polls/${pollId}/candidates`, {
 			method: 'POST',
@@ -420,11 +466,13 @@ class APIClient {
 		rounds: RCVRound[];
 		total_ballots: number;
 		exhausted_ballots: number;
+		spoiled_ballots: number;
 	}> {
 		const response = await this.request<{
 			rounds: RCVRound[];
 			total_ballots: number;
 			exhausted_ballots: number;
+			spoiled_ballots: number;
 		}>(`/polls/${pollId}/results/rounds`);
 		return response.data!;
 	}
@@ -467,8 +515,9 @@ class APIClient {
 		return response.data!;
 	}
 
-	async getVoters(pollId: string): Promise<VotersListResponse> {
-		const response = await this.request<VotersListResponse>(`/polls/${pollId}/voters`);
+	async getVoters(pollId: string, q?: string): Promise<VotersListResponse> {
+		const query = q ? `?q=${encodeURIComponent(q)}` : '';
+		const response = await this.request<VotersListResponse>(`/polls/${pollId}/voters${query}`);
 		return response.data!;
 	}
 }
@@ -7,6 +7,7 @@ export interface User {
 	emailVerified: boolean;
 	createdAt: string;
 	updatedAt: string;
+	lastLoginAt?: string;
 }
 
 // Poll types
@@ -15,12 +16,17 @@ export interface Poll {
 	userId: string;
 	title: string;
 	description?: string;
-	pollType: 'single_winner' | 'multi_winner';
+	pollType: 'single_winner' | 'multi_winner' | 'approval' | 'borda' | 'condorcet';
 	numWinners: number;
 	opensAt?: string;
 	closesAt?: string;
+	timezone?: string; // IANA zone the poll is scheduled in
+	opensAtLocal?: string; // opensAt in that zone, with its offset
+	closesAtLocal?: string;
 	isPublic: boolean;
 	registrationRequired: boolean;
+	requireCaptcha?: boolean; // Anonymous votes must include a CAPTCHA token
+	tags?: string[];
 	createdAt: string;
 	updatedAt: string;
 	candidates?: Candidate[];
@@ -33,7 +39,11 @@ export interface Candidate {
 	id: string;
 	pollId?: string;
 	name: string;
+	slug?: string;
 	description?: string;
+	statement?: string; // Markdown source, for editing
+	statementHtml?: string; // Sanitized rendering of the statement, safe to display
+	affiliation?: string; // Party or group the candidate stands for
 	displayOrder: number;
 	rank?: number; // Added during voting
 }
@@ -61,6 +71,7 @@ export interface Voter {
 	invitedAt: string;
 	votedAt?: string;
 	votingUrl: string;
+	emailStatus?: 'sent' | 'failed' | 'skipped_anonymous' | 'no_email';
 }
 
 export interface CreateVoterRequest {
@@ -79,6 +90,7 @@ export interface PollResults {
 	pollId: string;
 	totalVotes: number;
 	status: 'in_progress' | 'completed';
+	outcome?: 'winner' | 'no_confidence' | 'undecided';
 	winner?: WinnerInfo;
 	finalRankings: FinalRanking[];
 	rounds?: RCVRound[];
@@ -126,6 +138,7 @@ export interface RCVRound {
 	total_votes: number;
 	majority_threshold: number;
 	tiebreak_reason?: string;
+	tied_candidates?: string[];
 }
 
 // API types
@@ -140,9 +153,15 @@ export interface ApiResponse<T> {
 	};
 }
 
+export interface FieldError {
+	field: string;
+	message: string;
+}
+
 export interface ApiError {
 	code: string;
 	message: string;
+	fields?: FieldError[];
 	details?: any;
 }
 
@@ -150,7 +169,7 @@ export interface ApiError {
 export interface CreatePollForm {
 	title: string;
 	description: string;
-	pollType: 'single_winner' | 'multi_winner';
+	pollType: 'single_winner' | 'multi_winner' | 'approval' | 'borda' | 'condorcet';
 	numWinners: number;
 	opensAt?: string;
 	closesAt?: string;
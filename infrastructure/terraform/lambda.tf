@@ -89,6 +89,7 @@ resource "aws_lambda_function" "api" {
       ENVIRONMENT      = var.environment
       JWT_SECRET       = random_password.jwt_secret.result
       FRONTEND_URL     = "https://${var.domain_name}"
+      ALLOWED_ORIGINS  = "https://${var.domain_name}"
       USE_SES          = "true"
       SES_FROM_ADDRESS = "noreply@${var.domain_name}"
     }
@@ -208,6 +208,55 @@ router.post('/voter-invitation', async (req: Request, res: Response) => {
   }
 });
 
+// POST /api/email/voter-reminder - Remind a voter who hasn't voted yet
+router.post('/voter-reminder', async (req: Request, res: Response) => {
+  try {
+    const validated = VoterInvitationSchema.parse(req.body);
+    
+    const { to, ...reminderData } = validated;
+    
+    const result = await emailService.sendVoterReminder(reminderData as VoterInvitationData, to);
+    
+    if (result.success) {
+      res.json({
+        success: true,
+        data: {
+          messageId: result.messageId,
+          recipient: to
+        }
+      });
+    } else {
+      res.status(500).json({
+        success: false,
+        error: {
+          code: 'EMAIL_SEND_FAILED',
+          message: result.error || 'Failed to send reminder email'
+        }
+      });
+    }
+  } catch (error) {
+    if (error instanceof z.ZodError) {
+      res.status(400).json({
+        success: false,
+        error: {
+          code: 'VALIDATION_ERROR',
+          message: 'Invalid request data',
+          details: error.errors
+        }
+      });
+    } else {
+      console.error('Error sending voter reminder:', error);
+      res.status(500).json({
+        success: false,
+        error: {
+          code: 'INTERNAL_ERROR',
+          message: 'Internal server error'
+        }
+      });
+    }
+  }
+});
+
 // POST /api/email/bulk-voter-invitations - Send bulk voter invitations
 router.post('/bulk-voter-invitations', async (req: Request, res: Response) => {
   try {
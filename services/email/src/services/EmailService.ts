@@ -1,5 +1,6 @@
 import { getEmailTransporter, EmailResult, createEmailConfig } from '../config/email';
 import { createVoterInvitationTemplate, VoterInvitationData } from '../templates/voterInvitation';
+import { createVoterReminderTemplate } from '../templates/voterReminder';
 import { createPollResultsTemplate, PollResultsData } from '../templates/pollResults';
 import { createEmailVerificationTemplate, EmailVerificationData } from '../templates/emailVerification';
 import { createPasswordResetTemplate, PasswordResetData } from '../templates/passwordReset';
@@ -25,6 +26,11 @@ export class EmailService {
     return this.sendEmail(to, template, 'voter_invitation');
   }
 
+  async sendVoterReminder(data: VoterInvitationData, to: string): Promise<EmailResult> {
+    const template = createVoterReminderTemplate(data);
+    return this.sendEmail(to, template, 'voter_reminder');
+  }
+
   async sendPollResults(data: PollResultsData, to: string): Promise<EmailResult> {
     const template = createPollResultsTemplate(data);
     return this.sendEmail(to, template, 'poll_results');
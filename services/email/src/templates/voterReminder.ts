@@ -0,0 +1,137 @@
+import { EmailTemplate } from '../config/email';
+import { VoterInvitationData } from './voterInvitation';
+
+export function createVoterReminderTemplate(data: VoterInvitationData): EmailTemplate {
+  const voterGreeting = data.voterName ? `Hi ${data.voterName}` : 'Hello';
+  const closingInfo = data.closesAt 
+    ? `This poll closes on ${new Date(data.closesAt).toLocaleString()}.`
+    : 'Please vote when you have a moment.';
+
+  const subject = `Reminder: your vote is still needed for ${data.pollTitle}`;
+
+  const text = `
+${voterGreeting},
+
+This is a friendly reminder from ${data.pollOwnerName} that you haven't voted yet.
+
+Poll: ${data.pollTitle}
+${data.pollDescription ? `Description: ${data.pollDescription}` : ''}
+
+To vote, click this link or copy it into your browser:
+${data.votingUrl}
+
+${closingInfo}
+
+If you have any questions, you can contact the poll organizer at ${data.pollOwnerEmail}.
+
+Happy voting!
+The RankedChoice.me Team
+  `.trim();
+
+  const html = `
+<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>Voting Reminder</title>
+    <style>
+        body {
+            font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, 'Helvetica Neue', Arial, sans-serif;
+            line-height: 1.6;
+            color: #333;
+            max-width: 600px;
+            margin: 0 auto;
+            padding: 20px;
+            background-color: #f8fafc;
+        }
+        .container {
+            background: white;
+            padding: 40px;
+            border-radius: 12px;
+            box-shadow: 0 4px 6px rgba(0, 0, 0, 0.07);
+        }
+        .header {
+            text-align: center;
+            margin-bottom: 30px;
+        }
+        .logo {
+            font-size: 24px;
+            font-weight: bold;
+            color: #dc2626;
+            margin-bottom: 10px;
+        }
+        .poll-title {
+            font-size: 20px;
+            font-weight: 600;
+            color: #1f2937;
+            margin: 20px 0 10px 0;
+        }
+        .vote-button {
+            display: inline-block;
+            background-color: #dc2626;
+            color: white;
+            padding: 14px 28px;
+            text-decoration: none;
+            border-radius: 8px;
+            font-weight: 600;
+            margin: 20px 0;
+            text-align: center;
+        }
+        .info-box {
+            background-color: #f3f4f6;
+            padding: 16px;
+            border-radius: 8px;
+            margin: 20px 0;
+        }
+        .closing-info {
+            color: #d97706;
+            font-weight: 500;
+        }
+        .footer {
+            text-align: center;
+            margin-top: 30px;
+            color: #6b7280;
+            font-size: 14px;
+            border-top: 1px solid #e5e7eb;
+            padding-top: 20px;
+        }
+    </style>
+</head>
+<body>
+    <div class="container">
+        <div class="header">
+            <div class="logo">🗳️ RankedChoice.me</div>
+            <h1>Your Vote Is Still Needed</h1>
+        </div>
+
+        <p>${voterGreeting},</p>
+        
+        <p>This is a friendly reminder from <strong>${data.pollOwnerName}</strong> that you haven't cast your ballot yet.</p>
+
+        <div class="poll-title">${data.pollTitle}</div>
+
+        <div style="text-align: center;">
+            <a href="${data.votingUrl}" class="vote-button">🗳️ Cast Your Vote</a>
+        </div>
+
+        ${data.closesAt ? `
+        <div class="info-box">
+            <div class="closing-info">⏰ Poll closes: ${new Date(data.closesAt).toLocaleString()}</div>
+        </div>
+        ` : ''}
+
+        <div class="footer">
+            <p>This reminder was sent via RankedChoice.me</p>
+        </div>
+    </div>
+</body>
+</html>
+  `.trim();
+
+  return {
+    subject,
+    text,
+    html
+  };
+}